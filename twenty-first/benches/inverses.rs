@@ -2,7 +2,9 @@ use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use itertools::Itertools;
 use twenty_first::math::b_field_element::BFieldElement;
 use twenty_first::math::other::random_elements;
+use twenty_first::math::traits::FiniteField;
 use twenty_first::math::traits::Inverse;
+use twenty_first::math::x_field_element::XFieldElement;
 
 /// Run with `cargo criterion --bench inverse`
 fn inverse(c: &mut Criterion) {
@@ -19,6 +21,20 @@ fn inverse(c: &mut Criterion) {
         });
     });
 
+    let rnd_xfes: Vec<XFieldElement> = random_elements(count);
+
+    let inverse = BenchmarkId::new("XFieldElement Inverse", 0);
+    group.bench_function(inverse, |bencher| {
+        bencher.iter(|| {
+            rnd_xfes.iter().map(|x| x.inverse()).collect_vec();
+        });
+    });
+
+    let batch_inverse = BenchmarkId::new("XFieldElement Batch Inverse", 0);
+    group.bench_function(batch_inverse, |bencher| {
+        bencher.iter(|| XFieldElement::batch_inversion(rnd_xfes.clone()));
+    });
+
     group.finish();
 }
 
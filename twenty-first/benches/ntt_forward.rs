@@ -4,6 +4,7 @@ use criterion::{
 };
 use twenty_first::math::b_field_element::BFieldElement;
 use twenty_first::math::ntt::ntt;
+use twenty_first::math::ntt::NttPlan;
 use twenty_first::math::other::random_elements;
 use twenty_first::math::traits::PrimitiveRootOfUnity;
 use twenty_first::math::x_field_element::XFieldElement;
@@ -31,6 +32,15 @@ fn chu_ntt_forward(c: &mut Criterion) {
         );
     }
 
+    // Benchmarking forward ntt on XFieldElements with precomputed twiddle factors
+    for &log2_of_size in log2_of_sizes.iter() {
+        xfield_with_plan_benchmark(
+            &mut group,
+            BenchmarkId::new("xfield_with_plan", log2_of_size),
+            log2_of_size,
+        );
+    }
+
     group.finish();
 }
 
@@ -67,5 +77,22 @@ fn xfield_benchmark(
     group.sample_size(10);
 }
 
+fn xfield_with_plan_benchmark(
+    group: &mut BenchmarkGroup<WallTime>,
+    bench_id: BenchmarkId,
+    log2_of_size: usize,
+) {
+    let size: usize = 1 << log2_of_size;
+
+    let mut xs: Vec<XFieldElement> = random_elements(size);
+    let plan = NttPlan::new(size).unwrap();
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(bench_id, &size, |b, _| {
+        b.iter(|| plan.ntt::<XFieldElement>(&mut xs))
+    });
+    group.sample_size(10);
+}
+
 criterion_group!(benches, chu_ntt_forward);
 criterion_main!(benches);
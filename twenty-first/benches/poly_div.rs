@@ -1,5 +1,6 @@
 use criterion::criterion_group;
 use criterion::criterion_main;
+use criterion::BatchSize;
 use criterion::BenchmarkId;
 use criterion::Criterion;
 
@@ -34,6 +35,15 @@ fn poly_div<const LOG2_NUM_DEG: usize, const LOG2_DEN_DEG: usize>(c: &mut Criter
     let id = BenchmarkId::new("Naïve", bench_param());
     group.bench_function(id, |b| b.iter(|| num.naive_divide(&den)));
 
+    let id = BenchmarkId::new("Naïve, in place", bench_param());
+    group.bench_function(id, |b| {
+        b.iter_batched(
+            || num.clone(),
+            |num| num.divide_in_place(&den),
+            BatchSize::SmallInput,
+        )
+    });
+
     let id = BenchmarkId::new("Fast", bench_param());
     group.bench_function(id, |b| b.iter(|| num.fast_divide(&den)));
 
@@ -4,6 +4,7 @@ use criterion::BenchmarkId;
 use criterion::Criterion;
 
 use twenty_first::math::other::random_elements;
+use twenty_first::math::scratch;
 use twenty_first::prelude::*;
 
 criterion_main!(benches);
@@ -13,6 +14,7 @@ criterion_group!(
     targets = poly_mul<7>,
               poly_mul<8>,
               poly_mul<9>,
+              poly_mul<14>,
 );
 
 fn poly_mul<const LOG2_SIZE: usize>(c: &mut Criterion) {
@@ -31,6 +33,14 @@ fn poly_mul<const LOG2_SIZE: usize>(c: &mut Criterion) {
     let id = BenchmarkId::new("Fast", product_degree);
     group.bench_function(id, |b| b.iter(|| poly_0.fast_multiply(&poly_1)));
 
+    scratch::set_enabled(true);
+    let id = BenchmarkId::new("Fast, pooled scratch", product_degree);
+    group.bench_function(id, |b| {
+        b.iter(|| scratch::recycle(poly_0.fast_multiply(&poly_1).coefficients))
+    });
+    scratch::set_enabled(false);
+    scratch::clear();
+
     let id = BenchmarkId::new("Faster of the two", product_degree);
     group.bench_function(id, |b| b.iter(|| poly_0.multiply(&poly_1)));
 
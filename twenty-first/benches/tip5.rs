@@ -64,5 +64,25 @@ fn bench_parallel(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_10, bench_pair, bench_varlen, bench_parallel);
+fn bench_chunks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tip5/hash_chunks");
+
+    let size = 1 << 20;
+    let chunk_length = 16;
+    group.sample_size(10);
+    let elements: Vec<BFieldElement> = random_elements(size);
+
+    group.bench_function(BenchmarkId::new("Tip5 / Hash Chunks", size), |bencher| {
+        bencher.iter(|| Tip5::hash_chunks(&elements, chunk_length));
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_10,
+    bench_pair,
+    bench_varlen,
+    bench_parallel,
+    bench_chunks
+);
 criterion_main!(benches);
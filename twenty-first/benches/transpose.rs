@@ -0,0 +1,52 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use itertools::Itertools;
+
+use twenty_first::math::matrix::transpose;
+use twenty_first::math::matrix::transpose_in_place;
+use twenty_first::math::matrix::transpose_rows;
+use twenty_first::math::other::random_elements;
+use twenty_first::prelude::*;
+
+criterion_main!(benches);
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = transpose_matrix<16, 300>,
+              transpose_matrix<20, 300>,
+);
+
+fn transpose_matrix<const LOG2_NUM_ROWS: usize, const NUM_COLUMNS: usize>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("Transpose 2^{LOG2_NUM_ROWS} × {NUM_COLUMNS}"));
+    let num_rows = 1 << LOG2_NUM_ROWS;
+    let matrix: Vec<BFieldElement> = random_elements(num_rows * NUM_COLUMNS);
+    let rows = matrix
+        .chunks(NUM_COLUMNS)
+        .map(|row| row.to_vec())
+        .collect_vec();
+
+    group.bench_function(BenchmarkId::new("Naïve", LOG2_NUM_ROWS), |b| {
+        b.iter(|| {
+            (0..NUM_COLUMNS)
+                .map(|column| rows.iter().map(|row| row[column]).collect_vec())
+                .collect_vec()
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("Blocked, rows", LOG2_NUM_ROWS), |b| {
+        b.iter(|| transpose_rows(&rows))
+    });
+
+    group.bench_function(BenchmarkId::new("Blocked, flat", LOG2_NUM_ROWS), |b| {
+        b.iter(|| transpose(&matrix, num_rows, NUM_COLUMNS))
+    });
+
+    group.bench_function(BenchmarkId::new("In place, flat", LOG2_NUM_ROWS), |b| {
+        let mut matrix = matrix.clone();
+        b.iter(|| transpose_in_place(&mut matrix, num_rows, NUM_COLUMNS))
+    });
+
+    group.finish();
+}
@@ -6,6 +6,7 @@ use criterion::Criterion;
 use twenty_first::math::b_field_element::BFieldElement;
 use twenty_first::math::other::random_elements;
 use twenty_first::math::polynomial::Polynomial;
+use twenty_first::math::traits::PrimitiveRootOfUnity;
 
 criterion_main!(benches);
 criterion_group!(
@@ -18,7 +19,10 @@ criterion_group!(
               zerofier<500>,
               zerofier<700>,
               zerofier<1_000>,
+              zerofier<2_000>,
+              zerofier<5_000>,
               zerofier<10_000>,
+              zerofier<30_000>,
 );
 
 fn zerofier<const SIZE: usize>(c: &mut Criterion) {
@@ -35,8 +39,30 @@ fn zerofier<const SIZE: usize>(c: &mut Criterion) {
     let id = BenchmarkId::new("Fast", SIZE);
     group.bench_function(id, |b| b.iter(|| Polynomial::fast_zerofier(&roots)));
 
+    let id = BenchmarkId::new("Parallel", SIZE);
+    group.bench_function(id, |b| b.iter(|| Polynomial::par_zerofier(&roots)));
+
     let id = BenchmarkId::new("Fastest of the three", SIZE);
     group.bench_function(id, |b| b.iter(|| Polynomial::zerofier(&roots)));
 
+    // evaluating on a coset just large enough, and on one of the size typical for a low-degree
+    // extension
+    let offset = BFieldElement::generator();
+    for (coset, order) in [
+        ("Tight Coset", (SIZE + 1).next_power_of_two()),
+        ("Extension Coset", (4 * SIZE).next_power_of_two()),
+    ] {
+        let generator = BFieldElement::primitive_root_of_unity(order as u64).unwrap();
+        let id = BenchmarkId::new(format!("Evaluated Fastest on {coset}"), SIZE);
+        group.bench_function(id, |b| {
+            b.iter(|| Polynomial::zerofier(&roots).fast_coset_evaluate(offset, generator, order))
+        });
+
+        let id = BenchmarkId::new(format!("Evaluation Form on {coset}"), SIZE);
+        group.bench_function(id, |b| {
+            b.iter(|| Polynomial::coset_zerofier_evaluations(&roots, offset, generator, order))
+        });
+    }
+
     group.finish();
 }
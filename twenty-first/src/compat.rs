@@ -0,0 +1,234 @@
+//! Stability guarantees for outputs that proofs and commitments depend on.
+//!
+//! Proof systems built on this crate hash data, commit to codewords, and derive challenges using
+//! the primitives in this crate. A proof or commitment produced with one version can only be
+//! verified with another version if those primitives produce bit-identical outputs. The following
+//! outputs are guaranteed to not change between minor versions:
+//!
+//! - the [`Tip5`] permutation, including its round constants, and
+//!   everything derived from it: [`hash_pair`][hash_pair], [`hash_varlen`][hash_varlen], the
+//!   sponge's [`pad_and_absorb_all`][absorb], and sampling of [indices][indices] and
+//!   [scalars][scalars],
+//! - the order of the outputs of the [NTT](crate::math::ntt::ntt), which is the natural order
+//!   `f(ω^0), f(ω^1), …`, as well as the [primitive roots of unity][root] used,
+//! - the order in which [Merkle tree][merkle_tree] nodes are hashed, the resulting roots, and the
+//!   order of the digests in [authentication structures][auth_structure],
+//! - the way [MMR peaks are bagged][bag_peaks], and
+//! - the [`BFieldCodec`] encoding of the built-in types.
+//!
+//! Each of these is pinned by a golden output in this module's tests, annotated with the version
+//! that introduced it. A test failing after a change means that the change breaks compatibility.
+//! Should such a change become necessary, it will only be made in a new major version, and the
+//! previous behavior will remain available behind a feature flag named `legacy-<version>`.
+//!
+//! # Determinism
+//!
+//! All of the above, as well as the results of all other computations in this crate, are
+//! independent of the number of threads they are computed with. Field arithmetic is exact, which
+//! makes parallel sums and products independent of the order of reduction, and parallel
+//! computations collect their results in a fixed order. Hence, there is no need for a dedicated
+//! deterministic mode; outputs computed on different machines are bit-for-bit identical. This,
+//! too, is checked by this module's tests.
+//!
+//! [hash_pair]: crate::util_types::algebraic_hasher::AlgebraicHasher::hash_pair
+//! [hash_varlen]: crate::util_types::algebraic_hasher::AlgebraicHasher::hash_varlen
+//! [absorb]: crate::util_types::algebraic_hasher::Sponge::pad_and_absorb_all
+//! [indices]: crate::util_types::algebraic_hasher::AlgebraicHasher::sample_indices
+//! [scalars]: crate::util_types::algebraic_hasher::AlgebraicHasher::sample_scalars
+//! [root]: crate::math::traits::PrimitiveRootOfUnity
+//! [auth_structure]: crate::util_types::merkle_tree::MerkleTree::authentication_structure
+//! [bag_peaks]: crate::util_types::mmr::mmr_trait::Mmr::bag_peaks
+//! [`Tip5`]: crate::math::tip5::Tip5
+//! [`BFieldCodec`]: crate::math::bfield_codec::BFieldCodec
+//! [merkle_tree]: crate::util_types::merkle_tree::MerkleTree
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::math::bfield_codec::BFieldCodec;
+    use crate::math::ntt::ntt;
+    use crate::math::traits::PrimitiveRootOfUnity;
+    use crate::prelude::*;
+    use crate::util_types::algebraic_hasher::Sponge;
+    use crate::util_types::mmr::mmr_accumulator::MmrAccumulator;
+    use crate::util_types::mmr::mmr_trait::Mmr;
+
+    fn digest(values: [u64; 5]) -> Digest {
+        Digest::new(values.map(BFieldElement::new))
+    }
+
+    fn leaves() -> Vec<Digest> {
+        (0..8)
+            .map(|i| Tip5::hash_varlen(&[BFieldElement::new(i)]))
+            .collect()
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn ntt_output_is_stable() {
+        let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+        let mut values = (0..8).map(BFieldElement::new).collect_vec();
+        ntt(&mut values, omega, 3);
+
+        let expected = [
+            28,
+            18445622567621360637,
+            18445618169507741693,
+            1130298020461564,
+            18446744069414584317,
+            18445613771394122749,
+            1125899906842620,
+            1121501793223676,
+        ]
+        .map(BFieldElement::new);
+        assert_eq!(expected.to_vec(), values);
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn tip5_hashing_is_stable() {
+        let left = digest([1, 2, 3, 4, 5]);
+        let right = digest([6, 7, 8, 9, 10]);
+        let expected_pair_digest = digest([
+            10818500669765797222,
+            7750847691288459381,
+            17271032843874487437,
+            1108553480921430050,
+            6029014391627118288,
+        ]);
+        assert_eq!(expected_pair_digest, Tip5::hash_pair(left, right));
+
+        let expected_varlen_digest = digest([
+            1037267703022364995,
+            3063942090192050073,
+            10598035914747203430,
+            12841041985295660792,
+            1267185559365897270,
+        ]);
+        assert_eq!(
+            expected_varlen_digest,
+            Tip5::hash_varlen(&bfe_array![1, 2, 3])
+        );
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn tip5_sampling_is_stable() {
+        let mut sponge = Tip5::init();
+        sponge.pad_and_absorb_all(&bfe_array![1, 2, 3]);
+
+        assert_eq!(
+            vec![323, 921, 870, 760, 54],
+            sponge.sample_indices(1 << 10, 5)
+        );
+
+        let expected_scalar = xfe!([
+            6184011226751154329_u64,
+            6396283975629064724_u64,
+            11471099342289750005_u64
+        ]);
+        assert_eq!(vec![expected_scalar], sponge.sample_scalars(1));
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn merkle_tree_root_and_authentication_structure_are_stable() {
+        let tree = MerkleTree::<Tip5>::new::<CpuParallel>(&leaves()).unwrap();
+        let expected_root = digest([
+            1931645890751727423,
+            9482358858435924248,
+            328939755342163670,
+            13684389089131870223,
+            858508923385259677,
+        ]);
+        assert_eq!(expected_root, tree.root());
+
+        let expected_authentication_structure = vec![
+            digest([
+                7843600472325899470,
+                4675088604585218768,
+                11079586537171200429,
+                16819127609711044941,
+                14091503999674757986,
+            ]),
+            digest([
+                14695687051846672791,
+                8498183173028182611,
+                12275420829827903905,
+                6435156683422063433,
+                3729705906224123462,
+            ]),
+            digest([
+                12193878995149321532,
+                9466682779448465582,
+                7551601024684626337,
+                8043756343095867192,
+                4734545858566422213,
+            ]),
+            digest([
+                18271436111856193975,
+                10201801780628363332,
+                10366041853272571552,
+                15442452142171230114,
+                15752105839343894597,
+            ]),
+        ];
+        let authentication_structure = tree.authentication_structure(&[2, 5]).unwrap();
+        assert_eq!(expected_authentication_structure, authentication_structure);
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn bagging_mmr_peaks_is_stable() {
+        let mmr = MmrAccumulator::<Tip5>::new(leaves()[..7].to_vec());
+        let expected_bagged_peaks = digest([
+            129000300589600824,
+            12132122377411345194,
+            4286710122457810707,
+            4856163716196701723,
+            18159060915562247937,
+        ]);
+        assert_eq!(expected_bagged_peaks, mmr.bag_peaks());
+    }
+
+    #[test]
+    fn outputs_are_independent_of_the_number_of_threads() {
+        let compute_outputs = || {
+            let roots = (0..1000).map(BFieldElement::new).collect_vec();
+            let zerofier = Polynomial::zerofier(&roots);
+            let product = zerofier.multiply(&zerofier);
+            let evaluations = product.batch_evaluate(&roots[..100]);
+            let merkle_root =
+                MerkleTree::<Tip5>::new::<CpuParallel>(&Tip5::hash_many(&roots[..512]))
+                    .unwrap()
+                    .root();
+            let expanded_seed = Tip5::expand_seed(merkle_root, 1000);
+            (zerofier, product, evaluations, merkle_root, expanded_seed)
+        };
+
+        let outputs_for_num_threads = |num_threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            pool.install(compute_outputs)
+        };
+
+        let single_threaded_outputs = outputs_for_num_threads(1);
+        for num_threads in [2, 3, 8] {
+            assert_eq!(
+                single_threaded_outputs,
+                outputs_for_num_threads(num_threads)
+            );
+        }
+    }
+
+    /// Since v0.40.
+    #[test]
+    fn bfield_codec_encoding_is_stable() {
+        let value = (vec![1_u64, 1 << 40], (bfe!(3), vec![bfe_vec![4]]));
+        let expected = bfe_vec![6, 4, 1, 2, 1, 4, 3, 5, 2, 1, 0, 0, 256];
+        assert_eq!(expected, value.encode());
+    }
+}
@@ -0,0 +1,264 @@
+//! Global configuration of the parallelism this crate uses.
+//!
+//! By default, parallel computations use rayon's global thread pool, and every workload that can
+//! be distributed across threads is. When embedding this crate, for example on a shared machine,
+//! use [`set_parallelism`] to keep tiny workloads sequential, avoiding the overhead of
+//! parallelization, and to cap the number of threads used by huge ones.
+//!
+//! The number of threads is capped for
+//! - [batch evaluation](Polynomial::batch_evaluate) and
+//!   [batch coset evaluation](Polynomial::batch_fast_coset_evaluate) of polynomials,
+//! - computing [zerofiers](Polynomial::zerofier),
+//! - [hashing many values](AlgebraicHasher::hash_many) or [chunks](Tip5::hash_chunks),
+//!   [expanding seeds](Tip5::expand_seed), and building
+//!   [Merkle trees](crate::util_types::merkle_tree::MerkleTree), and
+//! - [streamed NTTs](crate::math::ntt::streamed).
+//!
+//! Of these, only batch evaluation and Merkle tree building keep small workloads sequential. All
+//! other parallel computations, including parallel iterators handed out by methods like
+//! [`Polynomial::par_evaluate_iter`], run in rayon's current thread pool.
+//!
+//! ```
+//! # use twenty_first::config;
+//! # use twenty_first::config::Config;
+//! config::set_parallelism(Config {
+//!     min_parallel_len: 1 << 10,
+//!     max_threads: Some(4),
+//! });
+//! assert_eq!(Some(4), config::parallelism().max_threads);
+//! # config::set_parallelism(Config::default());
+//! ```
+
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use rayon::ThreadPool;
+
+#[cfg(doc)]
+use crate::math::polynomial::Polynomial;
+#[cfg(doc)]
+use crate::math::tip5::Tip5;
+#[cfg(doc)]
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+/// The parallelism used by this crate. See the [module-level documentation](self).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Config {
+    /// Workloads of fewer items are processed sequentially by the computations that support it;
+    /// see the [module-level documentation](self). Some algorithms use a higher threshold of
+    /// their own, which takes precedence.
+    pub min_parallel_len: usize,
+
+    /// The maximal number of threads used by the computations listed in the
+    /// [module-level documentation](self). If `None`, rayon's global thread pool is used. Must not
+    /// be `Some(0)`.
+    ///
+    /// The cap only applies to computations started outside of any rayon thread pool. Computations
+    /// started on a worker thread of some rayon thread pool keep using that pool. This includes
+    /// rayon's global pool, for example if this crate is called from within a `par_iter`, as well
+    /// as pools set up using [`ThreadPool::install`].
+    pub max_threads: Option<usize>,
+}
+
+struct Parallelism {
+    config: Config,
+    thread_pool: Option<Arc<ThreadPool>>,
+}
+
+static PARALLELISM: RwLock<Parallelism> = RwLock::new(Parallelism {
+    config: Config {
+        min_parallel_len: 0,
+        max_threads: None,
+    },
+    thread_pool: None,
+});
+
+/// Set the parallelism used by all subsequent computations of this crate that consult the
+/// configuration.
+///
+/// # Panics
+///
+/// Panics if `max_threads` is `Some(0)`, or if it is `Some` and the thread pool of that size
+/// cannot be created.
+pub fn set_parallelism(config: Config) {
+    assert_ne!(
+        Some(0),
+        config.max_threads,
+        "the maximal number of threads must be positive"
+    );
+    let thread_pool = config.max_threads.map(|num_threads| {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("thread pool with the configured number of threads should be creatable");
+        Arc::new(pool)
+    });
+
+    let mut parallelism = PARALLELISM.write().unwrap_or_else(PoisonError::into_inner);
+    *parallelism = Parallelism {
+        config,
+        thread_pool,
+    };
+}
+
+/// The parallelism currently in use.
+pub fn parallelism() -> Config {
+    PARALLELISM
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .config
+}
+
+/// Whether a workload of `len` items should be processed in parallel.
+pub(crate) fn should_parallelize(len: usize) -> bool {
+    len >= parallelism().min_parallel_len
+}
+
+/// Run `op` in the configured thread pool. Runs `op` directly if no thread pool is configured, or
+/// if the current thread belongs to some rayon thread pool already.
+pub(crate) fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    if rayon::current_thread_index().is_some() {
+        return op();
+    }
+
+    let thread_pool = PARALLELISM
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .thread_pool
+        .clone();
+
+    match thread_pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+/// The number of threads [`install`] runs its operation with.
+pub(crate) fn current_num_threads() -> usize {
+    if rayon::current_thread_index().is_some() {
+        return rayon::current_num_threads();
+    }
+
+    let parallelism = PARALLELISM.read().unwrap_or_else(PoisonError::into_inner);
+    match &parallelism.thread_pool {
+        Some(pool) => pool.current_num_threads(),
+        None => rayon::current_num_threads(),
+    }
+}
+
+/// Serializes the tests that depend on global state of this crate, like the configuration or
+/// whether the [scratch pool](crate::math::scratch) is enabled. Tests that change such state hold
+/// it for writing, see [`with_parallelism`]. Tests that rely on the default state, for example
+/// because they inspect thread-local state of the calling thread, hold it for reading.
+#[cfg(test)]
+pub(crate) static TEST_LOCK: RwLock<()> = RwLock::new(());
+
+/// Run `f` with the given parallelism, then restore the default one, even if `f` panics.
+#[cfg(test)]
+pub(crate) fn with_parallelism<R>(config: Config, f: impl FnOnce() -> R) -> R {
+    struct RestoreDefault;
+
+    impl Drop for RestoreDefault {
+        fn drop(&mut self) {
+            set_parallelism(Config::default());
+        }
+    }
+
+    let _guard = TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+    set_parallelism(config);
+    let _restore_default = RestoreDefault;
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::polynomial::Polynomial;
+    use crate::math::tip5::Tip5;
+    use crate::math::traits::PrimitiveRootOfUnity;
+    use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+    #[test]
+    fn configured_parallelism_is_used() {
+        let config = Config {
+            min_parallel_len: 100,
+            max_threads: Some(1),
+        };
+        with_parallelism(config, || {
+            assert_eq!(config, parallelism());
+            assert!(!should_parallelize(99));
+            assert!(should_parallelize(100));
+            assert_eq!(1, current_num_threads());
+            assert_eq!(1, install(rayon::current_num_threads));
+        });
+        assert_eq!(Config::default(), parallelism());
+    }
+
+    #[test]
+    fn configured_thread_pool_is_not_used_from_within_other_thread_pool() {
+        let config = Config {
+            max_threads: Some(1),
+            ..Config::default()
+        };
+        with_parallelism(config, || {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap();
+            assert_eq!(2, pool.install(current_num_threads));
+            assert_eq!(2, pool.install(|| install(rayon::current_num_threads)));
+        });
+    }
+
+    #[test]
+    fn default_parallelism_uses_current_thread_pool() {
+        let _guard = TEST_LOCK.read().unwrap_or_else(PoisonError::into_inner);
+        assert!(should_parallelize(0));
+        assert_eq!(rayon::current_num_threads(), current_num_threads());
+        assert_eq!(
+            rayon::current_num_threads(),
+            install(rayon::current_num_threads)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the maximal number of threads must be positive")]
+    fn zero_threads_are_rejected() {
+        let config = Config {
+            max_threads: Some(0),
+            ..Config::default()
+        };
+        with_parallelism(config, || ());
+    }
+
+    #[test]
+    fn capped_computations_give_same_results() {
+        let roots = (0..1000).map(BFieldElement::new).collect::<Vec<_>>();
+        let generator = BFieldElement::primitive_root_of_unity(1 << 11).unwrap();
+        let compute_outputs = || {
+            let zerofier = Polynomial::zerofier(&roots);
+            let par_zerofier = Polynomial::par_zerofier(&roots);
+            let codewords = Polynomial::batch_fast_coset_evaluate(
+                &[zerofier.clone(), par_zerofier.clone()],
+                BFieldElement::generator(),
+                generator,
+                1 << 11,
+            );
+            let digests = Tip5::hash_many(&roots);
+            let expanded_seed = Tip5::expand_seed(digests[0], 1000);
+            (zerofier, par_zerofier, codewords, digests, expanded_seed)
+        };
+
+        let default_outputs = {
+            let _guard = TEST_LOCK.read().unwrap_or_else(PoisonError::into_inner);
+            compute_outputs()
+        };
+        let config = Config {
+            max_threads: Some(2),
+            ..Config::default()
+        };
+        assert_eq!(default_outputs, with_parallelism(config, compute_outputs));
+    }
+}
@@ -1,7 +1,10 @@
 use std::str::FromStr;
 use thiserror::Error;
 
+use crate::math::b_field_element::BFieldElement;
 pub use crate::math::bfield_codec::BFieldCodecError;
+use crate::math::bfield_codec::BYTES_PER_BFIELD_ELEMENT;
+use crate::math::ntt::MAX_NTT_LENGTH;
 use crate::prelude::tip5::DIGEST_LENGTH;
 use crate::prelude::x_field_element::EXTENSION_DEGREE;
 pub use crate::util_types::merkle_tree::MerkleTreeError;
@@ -30,6 +33,70 @@ pub enum TryFromXFieldElementError {
     InvalidDigest,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Error)]
+#[non_exhaustive]
+pub enum NttError {
+    #[error("length must be a power of two, but got {0}")]
+    LengthNotPowerOfTwo(usize),
+
+    #[error("length must not exceed {MAX_NTT_LENGTH}, but got {0}")]
+    DomainTooLarge(usize),
+
+    #[error("length must be of the form 2^k, 3·2^k, or 5·2^k, but got {0}")]
+    LengthNotSmooth(usize),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Error)]
+#[non_exhaustive]
+pub enum DecodeBytesError {
+    #[error("empty sequence")]
+    EmptySequence,
+
+    #[error("element {0} does not encode {BYTES_PER_BFIELD_ELEMENT} bytes")]
+    ElementOutOfRange(BFieldElement),
+
+    #[error("invalid padding")]
+    InvalidPadding,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Error)]
+#[non_exhaustive]
+pub enum TryFromPolynomialError {
+    #[error("polynomial of degree {0} has too many coefficients")]
+    DegreeTooLarge(isize),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Error)]
+#[non_exhaustive]
+pub enum PolynomialError {
+    #[error("domain and values must be of equal length, but are of length {0} and {1}")]
+    LengthMismatch(usize, usize),
+
+    #[error("interpolation must happen through more than zero points")]
+    EmptyDomain,
+
+    #[error("domain contains a duplicate at index {0}")]
+    DuplicateDomainPoint(usize),
+
+    #[error("polynomial of degree {0} cannot be evaluated on a domain of order {1}")]
+    DomainTooSmall(isize, usize),
+
+    #[error("the generator does not generate a subgroup of order {0}")]
+    InvalidGenerator(usize),
+
+    #[error("the zero polynomial has no leading coefficient")]
+    ZeroPolynomial,
+
+    #[error("cannot divide by the zero polynomial")]
+    DivisionByZero,
+
+    #[error("polynomial of degree {0} exceeds the claimed degree bound {1}")]
+    DegreeBoundExceeded(isize, isize),
+
+    #[error(transparent)]
+    Ntt(#[from] NttError),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Error)]
 #[non_exhaustive]
 pub enum TryFromDigestError {
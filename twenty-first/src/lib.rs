@@ -1,5 +1,7 @@
 #![deny(clippy::shadow_unrelated)]
 pub mod amount;
+pub mod compat;
+pub mod config;
 pub mod error;
 pub mod math;
 pub mod prelude;
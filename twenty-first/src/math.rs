@@ -1,11 +1,31 @@
+/// Count one field operation of the given kind. Does nothing unless feature `op-count` is enabled.
+macro_rules! count_op {
+    ($kind:ident) => {
+        #[cfg(feature = "op-count")]
+        crate::math::op_count::increment(|counts| &mut counts.$kind);
+    };
+}
+
 pub mod b_field_element;
 pub mod bfield_codec;
+pub mod codeword;
 pub mod digest;
+pub mod evaluation_cache;
+pub mod expression;
+pub mod fixed_polynomial;
+pub mod folding;
+#[cfg(any(test, feature = "instrumentation"))]
+pub mod instrumentation;
 pub mod lattice;
+pub mod matrix;
 pub mod mds;
 pub mod ntt;
+#[cfg(feature = "op-count")]
+pub mod op_count;
 pub mod other;
+pub mod padding;
 pub mod polynomial;
+pub mod scratch;
 pub mod tip5;
 pub mod traits;
 pub mod x_field_element;
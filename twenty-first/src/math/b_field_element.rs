@@ -6,6 +6,7 @@ use std::num::TryFromIntError;
 use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Div;
+use std::ops::DivAssign;
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Neg;
@@ -74,6 +75,11 @@ const PRIMITIVE_ROOTS: phf::Map<u64, u64> = phf_map! {
     4294967296u64 => 1753635133440165772,
 };
 
+/// The prime factorization of the order of the base field's multiplicative group, _i.e._, of
+/// p - 1 = 2^32 · 3 · 5 · 17 · 257 · 65537, as pairs of (prime, multiplicity).
+const MULTIPLICATIVE_GROUP_ORDER_FACTORIZATION: [(u64, u32); 6] =
+    [(2, 32), (3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)];
+
 /// Base field element ∈ ℤ_{2^64 - 2^32 + 1}.
 ///
 /// In Montgomery representation. This implementation follows <https://eprint.iacr.org/2022/274.pdf>
@@ -366,6 +372,142 @@ impl BFieldElement {
     pub fn raw_u64(&self) -> u64 {
         self.0
     }
+
+    /// The smallest positive integer `n` such that `self^n == 1`.
+    ///
+    /// Always divides `P - 1`. Cheap to compute, since the factorization of `P - 1` is smooth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// assert_eq!(1, bfe!(1).multiplicative_order());
+    /// assert_eq!(2, bfe!(-1).multiplicative_order());
+    /// assert_eq!(BFieldElement::P - 1, BFieldElement::generator().multiplicative_order());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, which is not an element of the multiplicative group.
+    pub fn multiplicative_order(&self) -> u64 {
+        assert!(!self.is_zero(), "zero has no multiplicative order");
+
+        let mut order = Self::P - 1;
+        for (prime, _) in MULTIPLICATIVE_GROUP_ORDER_FACTORIZATION {
+            while order.is_multiple_of(prime) && self.mod_pow(order / prime).is_one() {
+                order /= prime;
+            }
+        }
+
+        order
+    }
+
+    /// The discrete logarithm of `self` to the given `base`, _i.e._, the smallest non-negative
+    /// integer `x` such that `base^x == self`, or `None` if no such `x` exists.
+    ///
+    /// Uses the Pohlig–Hellman algorithm, which is efficient because the multiplicative group's
+    /// order `P - 1` has only small prime factors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let generator = BFieldElement::generator();
+    /// let element = generator.mod_pow(1337);
+    /// assert_eq!(Some(1337), element.discrete_log(generator));
+    ///
+    /// // 7 is not a power of -1
+    /// assert_eq!(None, bfe!(7).discrete_log(bfe!(-1)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `base` is zero.
+    pub fn discrete_log(&self, base: Self) -> Option<u64> {
+        let base_order = base.multiplicative_order();
+        if self.is_zero() || !self.mod_pow(base_order).is_one() {
+            return None;
+        }
+
+        // Solve the discrete logarithm in every prime-power order subgroup, then recombine.
+        let mut log = 0;
+        let mut modulus = 1;
+        for (prime, multiplicity) in MULTIPLICATIVE_GROUP_ORDER_FACTORIZATION {
+            let mut prime_power = 1;
+            for _ in 0..multiplicity {
+                if !base_order.is_multiple_of(prime_power * prime) {
+                    break;
+                }
+                prime_power *= prime;
+            }
+            if prime_power == 1 {
+                continue;
+            }
+
+            let cofactor = base_order / prime_power;
+            let sub_log = Self::prime_power_order_discrete_log(
+                self.mod_pow(cofactor),
+                base.mod_pow(cofactor),
+                prime,
+                prime_power,
+            )?;
+            log = Self::chinese_remainder(log, modulus, sub_log, prime_power);
+            modulus *= prime_power;
+        }
+
+        Some(log)
+    }
+
+    /// The discrete logarithm of `element` to the `base`, where `base` has order `prime_power`,
+    /// computed one base-`prime` digit at a time.
+    fn prime_power_order_discrete_log(
+        element: Self,
+        base: Self,
+        prime: u64,
+        prime_power: u64,
+    ) -> Option<u64> {
+        // generates the subgroup of order `prime`
+        let gamma = base.mod_pow(prime_power / prime);
+        let base_inverse = base.inverse();
+
+        let mut log = 0;
+        let mut digit_weight = 1;
+        while digit_weight < prime_power {
+            let remaining = element * base_inverse.mod_pow(log);
+            let target = remaining.mod_pow(prime_power / (digit_weight * prime));
+            let mut gamma_power = Self::one();
+            let digit = (0..prime).find(|_| {
+                let is_digit = gamma_power == target;
+                gamma_power *= gamma;
+                is_digit
+            })?;
+            log += digit * digit_weight;
+            digit_weight *= prime;
+        }
+
+        Some(log)
+    }
+
+    /// The unique `x` in `[0, modulus_a · modulus_b)` with `x ≡ a mod modulus_a` and
+    /// `x ≡ b mod modulus_b`. The moduli must be coprime.
+    fn chinese_remainder(a: u64, modulus_a: u64, b: u64, modulus_b: u64) -> u64 {
+        // extended Euclidean algorithm for the inverse of modulus_a modulo modulus_b
+        let (mut old_r, mut r) = (i128::from(modulus_a), i128::from(modulus_b));
+        let (mut old_s, mut s) = (1_i128, 0_i128);
+        while r != 0 {
+            let quotient = old_r / r;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+        }
+        debug_assert_eq!(1, old_r, "moduli must be coprime");
+
+        let modulus_b = i128::from(modulus_b);
+        let modulus_a_inverse = old_s.rem_euclid(modulus_b);
+        let difference = (i128::from(b) - i128::from(a)).rem_euclid(modulus_b);
+        let t = (difference * modulus_a_inverse) % modulus_b;
+        let x = i128::from(a) + t * i128::from(modulus_a);
+        x as u64
+    }
 }
 
 impl fmt::Display for BFieldElement {
@@ -462,9 +604,9 @@ impl From<[u8; BFieldElement::BYTES]> for BFieldElement {
 }
 
 impl Inverse for BFieldElement {
-    #[must_use]
     #[inline]
     fn inverse(&self) -> Self {
+        count_op!(bfe_inversions);
         let x = *self;
         assert_ne!(
             x,
@@ -558,6 +700,7 @@ impl Add for BFieldElement {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline(always)]
     fn add(self, rhs: Self) -> Self {
+        count_op!(bfe_additions);
         // Compute a + b = a - (p - b).
         let (x1, c1) = self.0.overflowing_sub(Self::P - rhs.0);
 
@@ -601,6 +744,7 @@ impl Mul for BFieldElement {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self {
+        count_op!(bfe_multiplications);
         Self(Self::montyred((self.0 as u128) * (rhs.0 as u128)))
     }
 }
@@ -620,6 +764,7 @@ impl Sub for BFieldElement {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn sub(self, rhs: Self) -> Self {
+        count_op!(bfe_additions);
         let (x1, c1) = self.0.overflowing_sub(rhs.0);
 
         // The following code is equivalent to the commented-out code below
@@ -648,6 +793,70 @@ impl Div for BFieldElement {
     }
 }
 
+/// A non-zero [`BFieldElement`] together with its precomputed inverse.
+///
+/// Dividing by an `InvertedConstant` is a multiplication with the precomputed inverse. This makes
+/// repeated divisions by the same constant, like halving in every step of folding or unscaling by
+/// the domain length after an inverse NTT, both cheap and explicit.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::b_field_element::InvertedConstant;
+/// let two = InvertedConstant::new(bfe!(2));
+/// assert_eq!(bfe!(21), bfe!(42) / two);
+/// assert_eq!(xfe!(21), xfe!(42) / two);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InvertedConstant {
+    constant: BFieldElement,
+    inverse: BFieldElement,
+}
+
+impl InvertedConstant {
+    /// # Panics
+    ///
+    /// Panics if the `constant` is zero.
+    pub fn new(constant: BFieldElement) -> Self {
+        let inverse = constant.inverse();
+        Self { constant, inverse }
+    }
+
+    pub const fn constant(self) -> BFieldElement {
+        self.constant
+    }
+
+    pub const fn inverse(self) -> BFieldElement {
+        self.inverse
+    }
+}
+
+impl Mul for InvertedConstant {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            constant: self.constant * rhs.constant,
+            inverse: self.inverse * rhs.inverse,
+        }
+    }
+}
+
+impl Div<InvertedConstant> for BFieldElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, divisor: InvertedConstant) -> Self {
+        self * divisor.inverse
+    }
+}
+
+impl DivAssign<InvertedConstant> for BFieldElement {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, divisor: InvertedConstant) {
+        *self *= divisor.inverse;
+    }
+}
+
 // TODO: We probably wanna make use of Rust's Pow, but for now we copy from ...big:
 impl ModPowU64 for BFieldElement {
     #[inline]
@@ -672,6 +881,7 @@ mod b_prime_field_element_test {
     use proptest_arbitrary_interop::arb;
     use rand::random;
     use rand::thread_rng;
+    use rand::Rng;
     use test_strategy::proptest;
 
     use crate::math::b_field_element::*;
@@ -776,6 +986,35 @@ mod b_prime_field_element_test {
         prop_assert!((bfe / bfe).is_one());
     }
 
+    #[proptest]
+    fn division_by_inverted_constant_is_division_by_constant(
+        dividend: BFieldElement,
+        #[filter(!#divisor.is_zero())] divisor: BFieldElement,
+    ) {
+        let inverted_divisor = InvertedConstant::new(divisor);
+        prop_assert_eq!(divisor, inverted_divisor.constant());
+        prop_assert_eq!(dividend / divisor, dividend / inverted_divisor);
+
+        let mut quotient = dividend;
+        quotient /= inverted_divisor;
+        prop_assert_eq!(dividend / divisor, quotient);
+    }
+
+    #[proptest]
+    fn product_of_inverted_constants_is_inverted_product(
+        #[filter(!#a.is_zero())] a: BFieldElement,
+        #[filter(!#b.is_zero())] b: BFieldElement,
+    ) {
+        let product = InvertedConstant::new(a) * InvertedConstant::new(b);
+        prop_assert_eq!(InvertedConstant::new(a * b), product);
+    }
+
+    #[test]
+    #[should_panic(expected = "inverse of zero")]
+    fn zero_cannot_be_inverted_constant() {
+        let _ = InvertedConstant::new(BFieldElement::zero());
+    }
+
     #[proptest]
     fn values_larger_than_modulus_are_handled_correctly(
         #[strategy(BFieldElement::P..)] large_value: u64,
@@ -1144,6 +1383,69 @@ mod b_prime_field_element_test {
         }
     }
 
+    #[test]
+    fn multiplicative_order_of_known_elements() {
+        assert_eq!(1, BFieldElement::one().multiplicative_order());
+        assert_eq!(2, (-BFieldElement::one()).multiplicative_order());
+        assert_eq!(
+            BFieldElement::P - 1,
+            BFieldElement::generator().multiplicative_order()
+        );
+
+        for log_order in 0..=32 {
+            let order = 1 << log_order;
+            let root = BFieldElement::primitive_root_of_unity(order).unwrap();
+            assert_eq!(order, root.multiplicative_order());
+        }
+    }
+
+    #[proptest]
+    fn multiplicative_order_is_minimal_exponent_dividing_group_order(
+        #[filter(!#element.is_zero())] element: BFieldElement,
+    ) {
+        let order = element.multiplicative_order();
+        prop_assert_eq!(0, (BFieldElement::P - 1) % order);
+        prop_assert!(element.mod_pow(order).is_one());
+        for (prime, _) in MULTIPLICATIVE_GROUP_ORDER_FACTORIZATION {
+            if order.is_multiple_of(prime) {
+                prop_assert!(!element.mod_pow(order / prime).is_one());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no multiplicative order")]
+    fn multiplicative_order_of_zero() {
+        let _ = BFieldElement::zero().multiplicative_order();
+    }
+
+    #[proptest]
+    fn discrete_log_inverts_exponentiation(
+        #[filter(!#base.is_zero())] base: BFieldElement,
+        exponent: u64,
+    ) {
+        let element = base.mod_pow(exponent);
+        let log = element.discrete_log(base).unwrap();
+        prop_assert_eq!(exponent % base.multiplicative_order(), log);
+        prop_assert_eq!(element, base.mod_pow(log));
+    }
+
+    #[proptest]
+    fn discrete_log_to_generator_always_exists(
+        #[filter(!#element.is_zero())] element: BFieldElement,
+    ) {
+        let generator = BFieldElement::generator();
+        let log = element.discrete_log(generator).unwrap();
+        prop_assert_eq!(element, generator.mod_pow(log));
+    }
+
+    #[test]
+    fn discrete_log_of_element_outside_of_subgroup_is_none() {
+        assert_eq!(None, bfe!(7).discrete_log(bfe!(-1)));
+        assert_eq!(None, BFieldElement::zero().discrete_log(bfe!(2)));
+        assert_eq!(Some(0), BFieldElement::one().discrete_log(bfe!(2)));
+    }
+
     #[test]
     #[should_panic(expected = "Attempted to find the multiplicative inverse of zero.")]
     fn multiplicative_inverse_of_zero() {
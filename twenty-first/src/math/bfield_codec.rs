@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -13,6 +14,7 @@ use num_traits::Zero;
 use thiserror::Error;
 
 use super::b_field_element::BFieldElement;
+use crate::error::DecodeBytesError;
 
 /// This trait provides functions for encoding to and decoding from a Vec of [BFieldElement]s.
 /// This encoding does not record the size of objects nor their type information; this is
@@ -48,10 +50,77 @@ pub enum BFieldCodecError {
     #[error("invalid length indicator")]
     InvalidLengthIndicator,
 
+    #[error("list of length {0} exceeds the decoding limit of {1}")]
+    ListTooLong(usize, usize),
+
     #[error("inner decoding error: {0}")]
     InnerDecodingFailure(#[from] Box<dyn Error + Send + Sync>),
 }
 
+/// Limits enforced while [decoding](decode_with_limits), protecting verifiers against length
+/// indicators chosen to make them allocate or iterate excessively.
+///
+/// A list can never be longer than the sequence it is decoded from, unless its items have an empty
+/// encoding. Use these limits to reject inputs that are well-formed but larger than any honest
+/// input can be, like codewords longer than the largest supported domain, or authentication paths
+/// longer than the height of the tree.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DecodingLimits {
+    /// The maximal length of every decoded [`Vec`], including nested ones.
+    pub max_list_length: usize,
+}
+
+impl DecodingLimits {
+    /// No limits beyond those implied by the length of the decoded sequence.
+    pub const UNLIMITED: Self = Self {
+        max_list_length: usize::MAX,
+    };
+}
+
+impl Default for DecodingLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+thread_local! {
+    static DECODING_LIMITS: Cell<DecodingLimits> = const { Cell::new(DecodingLimits::UNLIMITED) };
+}
+
+/// Like [`BFieldCodec::decode`], but fails if the decoded object, or any object nested in it,
+/// exceeds the given `limits`.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::bfield_codec::decode_with_limits;
+/// # use twenty_first::math::bfield_codec::DecodingLimits;
+/// let encoding = vec![bfe_vec![1, 2, 3], bfe_vec![4]].encode();
+/// let limits = DecodingLimits { max_list_length: 3 };
+/// assert!(decode_with_limits::<Vec<Vec<BFieldElement>>>(&encoding, limits).is_ok());
+///
+/// // the first inner list is too long
+/// let limits = DecodingLimits { max_list_length: 2 };
+/// assert!(decode_with_limits::<Vec<Vec<BFieldElement>>>(&encoding, limits).is_err());
+/// ```
+pub fn decode_with_limits<T: BFieldCodec>(
+    sequence: &[BFieldElement],
+    limits: DecodingLimits,
+) -> Result<Box<T>, T::Error> {
+    /// Restores the previous limits, even if decoding panics.
+    struct Restore(DecodingLimits);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DECODING_LIMITS.set(self.0);
+        }
+    }
+
+    let _restore = Restore(DECODING_LIMITS.replace(limits));
+    T::decode(sequence)
+}
+
 // The type underlying BFieldElement is u64. A single u64 does not fit in one BFieldElement.
 // Therefore, deriving the BFieldCodec for BFieldElement using the derive macro will result in a
 // BFieldCodec implementation that encodes a single BFieldElement as two BFieldElements.
@@ -231,7 +300,7 @@ impl<T: BFieldCodec, S: BFieldCodec> BFieldCodec for (T, S) {
 
     fn decode(sequence: &[BFieldElement]) -> Result<Box<Self>, Self::Error> {
         // decode S
-        if S::static_length().is_none() && sequence.first().is_none() {
+        if S::static_length().is_none() && sequence.is_empty() {
             return Err(Self::Error::MissingLengthIndicator);
         }
         let (length_of_s, sequence) = match S::static_length() {
@@ -245,7 +314,7 @@ impl<T: BFieldCodec, S: BFieldCodec> BFieldCodec for (T, S) {
         let s = *S::decode(sequence_for_s).map_err(|err| err.into())?;
 
         // decode T
-        if T::static_length().is_none() && sequence.first().is_none() {
+        if T::static_length().is_none() && sequence.is_empty() {
             return Err(Self::Error::MissingLengthIndicator);
         }
         let (length_of_t, sequence) = match T::static_length() {
@@ -327,7 +396,7 @@ impl<T: BFieldCodec, const N: usize> BFieldCodec for [T; N] {
     type Error = BFieldCodecError;
 
     fn decode(sequence: &[BFieldElement]) -> Result<Box<Self>, Self::Error> {
-        if N > 0 && sequence.is_empty() {
+        if N > 0 && sequence.is_empty() && T::static_length() != Some(0) {
             return Err(Self::Error::EmptySequence);
         }
 
@@ -356,11 +425,31 @@ impl<T: BFieldCodec> BFieldCodec for Vec<T> {
         }
 
         let vec_length = sequence[0].value() as usize;
+        let max_list_length = DECODING_LIMITS.get().max_list_length;
+        if vec_length > max_list_length {
+            return Err(Self::Error::ListTooLong(vec_length, max_list_length));
+        }
+
+        // For items with an empty encoding, the length indicator is the only thing determining the
+        // time it takes to decode. To prevent a denial of service, only empty lists of such items
+        // can be decoded. Consistently, only empty lists of such items can be encoded.
+        if T::static_length() == Some(0) && vec_length != 0 {
+            return Err(Self::Error::InvalidLengthIndicator);
+        }
+
         let vec = bfield_codec_decode_list(vec_length, &sequence[1..])?;
         Ok(Box::new(vec))
     }
 
+    /// # Panics
+    ///
+    /// Panics if `self` is not empty but its items have an empty encoding, like [`PhantomData`].
+    /// Such lists could not be decoded.
     fn encode(&self) -> Vec<BFieldElement> {
+        assert!(
+            T::static_length() != Some(0) || self.is_empty(),
+            "Only empty lists of items with an empty encoding can be encoded."
+        );
         let num_elements = (self.len() as u64).into();
         let mut encoding = vec![num_elements];
         let encoded_items = bfield_codec_encode_list(self.iter());
@@ -406,6 +495,14 @@ fn bfield_codec_decode_list_with_statically_sized_items<T: BFieldCodec>(
         return Err(BFieldCodecError::SequenceTooLong);
     }
 
+    if item_length == 0 {
+        for _ in 0..num_items {
+            let item = *T::decode(&[]).map_err(|e| e.into())?;
+            vec.push(item);
+        }
+        return Ok(vec);
+    }
+
     for raw_item in sequence.chunks_exact(item_length) {
         let item = *T::decode(raw_item).map_err(|e| e.into())?;
         vec.push(item);
@@ -476,6 +573,83 @@ impl<T> BFieldCodec for PhantomData<T> {
     }
 }
 
+/// The number of bytes packed into one [`BFieldElement`] by [`encode_bytes_to_bfes`].
+///
+/// Seven bytes always fit into a `BFieldElement`, since 2^56 is less than the field's modulus.
+pub const BYTES_PER_BFIELD_ELEMENT: usize = 7;
+
+/// Pack a byte string into [`BFieldElement`]s, injectively.
+///
+/// The bytes are padded with a single `1` followed by as many `0`s as needed to get a multiple of
+/// [`BYTES_PER_BFIELD_ELEMENT`]; the padding is at least one byte long. Every chunk of the padded
+/// bytes is then interpreted as a little-endian integer. Because of the padding, byte strings with
+/// trailing zeros are encoded differently from those without, and the empty byte string is
+/// encoded as one element.
+///
+/// The inverse is [`decode_bytes_from_bfes`].
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::bfield_codec::decode_bytes_from_bfes;
+/// # use twenty_first::math::bfield_codec::encode_bytes_to_bfes;
+/// assert_eq!(bfe_vec![1], encode_bytes_to_bfes(&[]));
+/// assert_eq!(bfe_vec![0x01_2a], encode_bytes_to_bfes(&[42]));
+/// assert_eq!(bfe_vec![0x01_00], encode_bytes_to_bfes(&[0]));
+///
+/// let bytes = b"twenty-first";
+/// let encoding = encode_bytes_to_bfes(bytes);
+/// assert_eq!(2, encoding.len());
+/// assert_eq!(bytes.to_vec(), decode_bytes_from_bfes(&encoding).unwrap());
+/// ```
+pub fn encode_bytes_to_bfes(bytes: &[u8]) -> Vec<BFieldElement> {
+    let padded_length = (bytes.len() + 1).next_multiple_of(BYTES_PER_BFIELD_ELEMENT);
+    let mut padded_bytes = bytes.to_vec();
+    padded_bytes.push(1);
+    padded_bytes.resize(padded_length, 0);
+
+    padded_bytes
+        .chunks(BYTES_PER_BFIELD_ELEMENT)
+        .map(|chunk| {
+            let mut le_bytes = [0; 8];
+            le_bytes[..BYTES_PER_BFIELD_ELEMENT].copy_from_slice(chunk);
+            BFieldElement::new(u64::from_le_bytes(le_bytes))
+        })
+        .collect()
+}
+
+/// The inverse of [`encode_bytes_to_bfes`].
+///
+/// Only accepts canonical encodings: every element must be less than 2^56, and the padding must
+/// consist of a single `1` followed by fewer than [`BYTES_PER_BFIELD_ELEMENT`] many `0`s.
+pub fn decode_bytes_from_bfes(sequence: &[BFieldElement]) -> Result<Vec<u8>, DecodeBytesError> {
+    if sequence.is_empty() {
+        return Err(DecodeBytesError::EmptySequence);
+    }
+
+    let mut bytes = Vec::with_capacity(sequence.len() * BYTES_PER_BFIELD_ELEMENT);
+    for &element in sequence {
+        let le_bytes = element.value().to_le_bytes();
+        let (packed_bytes, unused_bytes) = le_bytes.split_at(BYTES_PER_BFIELD_ELEMENT);
+        if unused_bytes.iter().any(|&byte| byte != 0) {
+            return Err(DecodeBytesError::ElementOutOfRange(element));
+        }
+        bytes.extend_from_slice(packed_bytes);
+    }
+
+    let Some(padding_start) = bytes.iter().rposition(|&byte| byte != 0) else {
+        return Err(DecodeBytesError::InvalidPadding);
+    };
+    let padding_is_in_last_element = bytes.len() - padding_start <= BYTES_PER_BFIELD_ELEMENT;
+    if bytes[padding_start] != 1 || !padding_is_in_last_element {
+        return Err(DecodeBytesError::InvalidPadding);
+    }
+
+    bytes.truncate(padding_start);
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::collection::size_range;
@@ -484,6 +658,7 @@ mod tests {
     use proptest_arbitrary_interop::arb;
     use test_strategy::proptest;
 
+    use crate::bfe_vec;
     use crate::prelude::Digest;
     use crate::prelude::Tip5;
     use crate::prelude::XFieldElement;
@@ -634,6 +809,68 @@ mod tests {
     test_case! { fn tuples_dynamic_static_size for (Vec<XFieldElement>, Digest): None }
     test_case! { fn tuples_dynamic_dynamic_size for (Vec<XFieldElement>, Vec<Digest>): None }
     test_case! { fn phantom_data for PhantomData<Tip5>: Some(0) }
+    test_case! { fn array_of_phantom_data for [PhantomData<Tip5>; 3]: Some(0) }
+
+    #[proptest]
+    fn decoding_non_empty_vec_of_items_with_empty_encoding_fails(
+        #[strategy(1..BFieldElement::P)] indicated_length: u64,
+    ) {
+        let encoding = [BFieldElement::new(indicated_length)];
+        let err = Vec::<PhantomData<Tip5>>::decode(&encoding).unwrap_err();
+        prop_assert!(matches!(err, BFieldCodecError::InvalidLengthIndicator));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only empty lists")]
+    fn encoding_non_empty_vec_of_items_with_empty_encoding_panics() {
+        let _ = vec![PhantomData::<Tip5>].encode();
+    }
+
+    #[proptest]
+    fn decoding_with_sufficient_limits_is_decoding(
+        list: Vec<Vec<BFieldElement>>,
+        #[strategy(0_usize..10)] slack: usize,
+    ) {
+        let longest_list = list
+            .iter()
+            .map(|l| l.len())
+            .chain([list.len()])
+            .max()
+            .unwrap();
+        let limits = DecodingLimits {
+            max_list_length: longest_list + slack,
+        };
+        let decoded = decode_with_limits::<Vec<Vec<BFieldElement>>>(&list.encode(), limits)?;
+        prop_assert_eq!(list, *decoded);
+    }
+
+    #[proptest]
+    fn decoding_nested_list_exceeding_limits_fails(
+        #[filter(!#list.is_empty())] list: Vec<Vec<BFieldElement>>,
+    ) {
+        let longest_list = list
+            .iter()
+            .map(|l| l.len())
+            .chain([list.len()])
+            .max()
+            .unwrap();
+        let limits = DecodingLimits {
+            max_list_length: longest_list - 1,
+        };
+        let err = decode_with_limits::<Vec<Vec<BFieldElement>>>(&list.encode(), limits);
+        prop_assert!(err.is_err());
+
+        // the limits only apply to the one decoding
+        prop_assert!(Vec::<Vec<BFieldElement>>::decode(&list.encode()).is_ok());
+    }
+
+    #[test]
+    fn empty_vec_of_items_with_empty_encoding_can_be_decoded() {
+        let vec = Vec::<PhantomData<Tip5>>::new();
+        let decoded = *Vec::<PhantomData<Tip5>>::decode(&vec.encode()).unwrap();
+        assert_eq!(vec, decoded);
+    }
+
     test_case! { fn boxed_u32s for Box<u32>: Some(1) }
     test_case! { fn tuple_with_boxed_bfe for (u64, Box<BFieldElement>): Some(3) }
     test_case! { fn tuple_with_boxed_digest for (u128, Box<Digest>): Some(9) }
@@ -648,6 +885,66 @@ mod tests {
     neg_test_case! { fn vec_of_vec_of_bfield_elements_neg for Vec<Vec<BFieldElement>> }
     neg_test_case! { fn vec_of_vec_of_xfield_elements_neg for Vec<Vec<XFieldElement>> }
 
+    #[proptest]
+    fn decoding_encoded_bytes_is_identity(bytes: Vec<u8>) {
+        let encoding = encode_bytes_to_bfes(&bytes);
+        prop_assert_eq!(bytes.len() / BYTES_PER_BFIELD_ELEMENT + 1, encoding.len());
+        prop_assert_eq!(bytes, decode_bytes_from_bfes(&encoding)?);
+    }
+
+    #[proptest]
+    fn encoding_bytes_is_injective(
+        bytes: Vec<u8>,
+        #[filter(#bytes != #other_bytes)] other_bytes: Vec<u8>,
+    ) {
+        prop_assert_ne!(
+            encode_bytes_to_bfes(&bytes),
+            encode_bytes_to_bfes(&other_bytes)
+        );
+    }
+
+    #[proptest]
+    fn appending_zero_bytes_changes_encoding(
+        bytes: Vec<u8>,
+        #[strategy(1_usize..20)] num_zeros: usize,
+    ) {
+        let mut padded_bytes = bytes.clone();
+        padded_bytes.resize(bytes.len() + num_zeros, 0);
+        prop_assert_ne!(
+            encode_bytes_to_bfes(&bytes),
+            encode_bytes_to_bfes(&padded_bytes)
+        );
+    }
+
+    #[proptest]
+    fn decoding_element_out_of_range_fails(
+        bytes: Vec<u8>,
+        #[strategy(0..=#bytes.len() / BYTES_PER_BFIELD_ELEMENT)] index: usize,
+        #[strategy(1_u64 << 56..BFieldElement::P)] value: u64,
+    ) {
+        let mut encoding = encode_bytes_to_bfes(&bytes);
+        encoding[index] = BFieldElement::new(value);
+        let err = decode_bytes_from_bfes(&encoding).unwrap_err();
+        prop_assert_eq!(DecodeBytesError::ElementOutOfRange(encoding[index]), err);
+    }
+
+    #[test]
+    fn decoding_bytes_with_invalid_padding_fails() {
+        let invalid_encodings = [
+            bfe_vec![0],
+            bfe_vec![2],
+            bfe_vec![0x01_00_00_00_00_00_00_u64, 0],
+            bfe_vec![0x02_2a],
+        ];
+        for encoding in &invalid_encodings {
+            let err = decode_bytes_from_bfes(encoding).unwrap_err();
+            assert_eq!(DecodeBytesError::InvalidPadding, err);
+        }
+
+        let err = decode_bytes_from_bfes(&[]).unwrap_err();
+        assert_eq!(DecodeBytesError::EmptySequence, err);
+    }
+
     /// Depending on the test helper [`BFieldCodecPropertyTestData`] in the bfieldcodec_derive crate
     /// would introduce an almost-cyclic dependency.[^1] This would make publishing to crates.io
     /// quite difficult. Hence, integration tests in the bfieldcodec_derive crate are also off the
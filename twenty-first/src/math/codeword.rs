@@ -0,0 +1,930 @@
+use std::iter::StepBy;
+use std::ops::MulAssign;
+use std::slice::Iter;
+
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::error::NttError;
+use crate::math::b_field_element::BFieldElement;
+use crate::math::ntt::intt_owned;
+use crate::math::ntt::ntt_owned;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+use crate::math::x_field_element::XFieldElement;
+
+/// Fold a codeword in half using the given `challenge`, as done in every round of FRI.
+///
+/// The `codeword` holds the evaluations of some polynomial `f(x) = f_E(x²) + x·f_O(x²)` on the
+/// coset `{offset·ω^i}` of the subgroup generated by `ω`. The folded codeword holds the evaluations
+/// of the polynomial `f_E(x) + challenge·f_O(x)` on the coset `{offset²·ω^(2i)}`, which is half the
+/// size. Concretely, with `x_i = offset·ω^i` and `n` the length of the codeword, the `i`th folded
+/// value is
+///
+/// ```markdown
+/// ((1 + challenge / x_i)·codeword[i] + (1 - challenge / x_i)·codeword[n/2 + i]) / 2
+/// ```
+///
+/// Since the arguments `omega_inv` and `offset_inv` are the inverses of `ω` and the `offset`,
+/// respectively, no inversions are required other than that of 2.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::fold_codeword;
+/// # use twenty_first::math::traits::FiniteField;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3, 4]); // f_E(x) = 1 + 3x, f_O(x) = 2 + 4x
+/// let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+/// let offset = BFieldElement::generator();
+/// let codeword = polynomial.fast_coset_evaluate(offset, omega, 8);
+///
+/// let challenge = bfe!(10);
+/// let folded_codeword = fold_codeword(&codeword, challenge, omega.inverse(), offset.inverse());
+///
+/// let folded_polynomial = Polynomial::new(bfe_vec![21, 43]); // f_E(x) + 10·f_O(x)
+/// let expected = folded_polynomial.fast_coset_evaluate(offset.square(), omega.square(), 4);
+/// assert_eq!(expected, folded_codeword);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of the `codeword` is not a power of two, or if it is less than 2.
+pub fn fold_codeword<FF>(
+    codeword: &[FF],
+    challenge: FF,
+    omega_inv: BFieldElement,
+    offset_inv: BFieldElement,
+) -> Vec<FF>
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+{
+    let codeword_length = codeword.len();
+    assert!(
+        codeword_length >= 2 && codeword_length.is_power_of_two(),
+        "The length of the codeword must be a power of two greater than 1, but is {codeword_length}."
+    );
+
+    let (left_half, right_half) = codeword.split_at(codeword_length / 2);
+    let two_inv = FF::from(2).inverse();
+
+    let mut domain_point_inv = offset_inv;
+    let mut folded_codeword = Vec::with_capacity(codeword_length / 2);
+    for (&left, &right) in left_half.iter().zip(right_half) {
+        let mut scaled_challenge = challenge;
+        scaled_challenge *= domain_point_inv;
+        let folded_value =
+            (FF::one() + scaled_challenge) * left + (FF::one() - scaled_challenge) * right;
+        folded_codeword.push(folded_value * two_inv);
+        domain_point_inv *= omega_inv;
+    }
+
+    folded_codeword
+}
+
+/// Rotate the `codeword` by `k` positions, corresponding to multiplying the argument of the
+/// underlying polynomial by `ω^k`.
+///
+/// Let the `codeword` contain the evaluations of some polynomial `f(x)` on the coset
+/// `{offset·ω^i}`, where `ω` generates a subgroup of order `n`, the length of the `codeword`. Then
+/// the rotated codeword contains the evaluations of `f(ω^k·x)` on the same coset. In particular,
+/// its `i`th element is the `codeword`'s element at index `(i + k) mod n`. This is the “next row”
+/// relation commonly used when evaluating transition constraints.
+///
+/// See also [`Polynomial::scale`](crate::math::polynomial::Polynomial::scale).
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::rotate;
+/// let codeword = bfe_vec![1, 2, 3, 4];
+/// assert_eq!(bfe_vec![2, 3, 4, 1], rotate(&codeword, 1));
+/// assert_eq!(bfe_vec![4, 1, 2, 3], rotate(&codeword, 7));
+/// ```
+pub fn rotate<FF: Copy>(codeword: &[FF], k: usize) -> Vec<FF> {
+    let mut rotated_codeword = codeword.to_vec();
+    if !codeword.is_empty() {
+        rotated_codeword.rotate_left(k % codeword.len());
+    }
+    rotated_codeword
+}
+
+/// Move the `codeword` from one coset domain to another, possibly of different size.
+///
+/// The `codeword` holds the evaluations of some polynomial `f(x)` on the coset
+/// `{from_offset·ω^i}`, where `ω` generates the subgroup of order `codeword.len()`. The returned
+/// codeword holds the evaluations of the same polynomial on the coset `{to_offset·η^i}`, where
+/// `η` generates the subgroup of order `to_length`. This is the low-degree extension used to move
+/// between trace and quotient domains; the domains may also be of the same size and only differ
+/// in their offset.
+///
+/// Internally, this is an [inverse NTT](intt_owned) on the `from` domain followed by an
+/// [NTT](ntt_owned) on the `to` domain. The offsets are accounted for with one combined scaling
+/// of the coefficients by powers of `to_offset / from_offset`.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::change_domain;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let from_offset = bfe!(1);
+/// let to_offset = BFieldElement::generator();
+/// let omega = BFieldElement::primitive_root_of_unity(4).unwrap();
+/// let codeword = polynomial.fast_coset_evaluate(from_offset, omega, 4);
+///
+/// let extended_codeword = change_domain(&codeword, from_offset, to_offset, 16).unwrap();
+/// let eta = BFieldElement::primitive_root_of_unity(16).unwrap();
+/// assert_eq!(polynomial.fast_coset_evaluate(to_offset, eta, 16), extended_codeword);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of the `codeword` or the `to_length` is neither zero nor a power
+/// of two, or if either is too large for an NTT.
+///
+/// # Panics
+///
+/// Panics if the `from_offset` is zero, or if the interpolated polynomial does not fit into the
+/// `to` domain, _i.e._, if its degree is at least `to_length`.
+pub fn change_domain<FF>(
+    codeword: &[FF],
+    from_offset: BFieldElement,
+    to_offset: BFieldElement,
+    to_length: usize,
+) -> Result<Vec<FF>, NttError>
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+{
+    // Ensure the error is independent of whether the `to` domain is bigger or smaller.
+    if !to_length.is_power_of_two() && to_length != 0 {
+        return Err(NttError::LengthNotPowerOfTwo(to_length));
+    }
+    let mut coefficients = intt_owned(codeword.to_vec())?;
+
+    let scale_factor = to_offset * from_offset.inverse();
+    let mut power_of_scale_factor = BFieldElement::new(1);
+    for coefficient in &mut coefficients {
+        *coefficient *= power_of_scale_factor;
+        power_of_scale_factor *= scale_factor;
+    }
+
+    if to_length < coefficients.len() {
+        assert!(
+            coefficients[to_length..].iter().all(|c| c.is_zero()),
+            "The interpolated polynomial must fit into the target domain of length {to_length}."
+        );
+    }
+    coefficients.resize(to_length, FF::zero());
+
+    ntt_owned(coefficients)
+}
+
+/// The part of the `codeword` living on a coset of a subgroup of the `codeword`'s domain, without
+/// copying.
+///
+/// Let the `codeword` hold the evaluations of some polynomial on the coset `{offset·ω^i}`. The
+/// returned iterator yields the evaluations on the coset `{offset·ω^shift·(ω^stride)^j}`, _i.e._,
+/// every `stride`th element starting at index `shift`. Use [`sub_coset`] to get the offset and
+/// generator of that coset. For example, the two sub-codewords of stride 2 hold the evaluations on
+/// the points `offset·ω^i` with even and odd `i`, respectively.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::sub_codeword;
+/// let codeword = bfe_vec![0, 1, 2, 3, 4, 5];
+/// let sub_codeword = sub_codeword(&codeword, 3, 1).copied().collect::<Vec<_>>();
+/// assert_eq!(bfe_vec![1, 4], sub_codeword);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the `stride` does not divide the length of the `codeword`, or if the `shift` is not
+/// smaller than the `stride`.
+pub fn sub_codeword<FF>(codeword: &[FF], stride: usize, shift: usize) -> StepBy<Iter<'_, FF>> {
+    assert!(
+        stride > 0 && codeword.len().is_multiple_of(stride),
+        "The stride {stride} must divide the codeword length {}.",
+        codeword.len()
+    );
+    assert!(
+        shift < stride,
+        "The shift {shift} must be smaller than the stride {stride}."
+    );
+
+    let shifted_codeword = codeword.get(shift..).unwrap_or_default();
+    shifted_codeword.iter().step_by(stride)
+}
+
+/// The offset and generator of the coset that the [sub-codeword](sub_codeword) of the given
+/// `stride` and `shift` lives on, given the `offset` and generator `omega` of the original
+/// codeword's domain.
+///
+/// Concretely, the sub-coset is `{offset·ω^shift·(ω^stride)^j}`, with offset `offset·ω^shift` and
+/// generator `ω^stride`.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::sub_codeword;
+/// # use twenty_first::math::codeword::sub_coset;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let offset = BFieldElement::generator();
+/// let omega = BFieldElement::primitive_root_of_unity(16).unwrap();
+/// let codeword = polynomial.fast_coset_evaluate(offset, omega, 16);
+///
+/// let (sub_offset, sub_omega) = sub_coset(offset, omega, 4, 3);
+/// let sub_codeword = sub_codeword(&codeword, 4, 3).copied().collect::<Vec<_>>();
+/// assert_eq!(polynomial.fast_coset_evaluate(sub_offset, sub_omega, 4), sub_codeword);
+/// ```
+pub fn sub_coset(
+    offset: BFieldElement,
+    omega: BFieldElement,
+    stride: usize,
+    shift: usize,
+) -> (BFieldElement, BFieldElement) {
+    let sub_offset = offset * omega.mod_pow(shift as u64);
+    let sub_omega = omega.mod_pow(stride as u64);
+    (sub_offset, sub_omega)
+}
+
+/// The codewords of the quotients `(f_i(x) - y_i) / (x - z)`, where the `codewords` hold the
+/// evaluations of the polynomials `f_i` on the coset `{offset·ω^i}`, and `y_i` are the `values`.
+///
+/// If the `values` are `y_i = f_i(z)`, the results are the codewords of the
+/// [polynomial quotients](Polynomial::batch_deep_quotients). The inverses of `x - z` on the domain
+/// are computed only once, using a single [batch inversion](FiniteField::batch_inversion), and
+/// shared among all codewords, which are then processed in parallel.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::batch_deep_quotients;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomials = [
+///     Polynomial::new(bfe_vec![1, 2, 3]),
+///     Polynomial::new(bfe_vec![4, 5]),
+/// ];
+/// let offset = BFieldElement::generator();
+/// let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+/// let codewords = Polynomial::batch_fast_coset_evaluate(&polynomials, offset, omega, 8);
+///
+/// let z = bfe!(5);
+/// let values = polynomials.iter().map(|p| p.evaluate(z)).collect::<Vec<_>>();
+/// let quotient_codewords = batch_deep_quotients(&codewords, offset, omega, z, &values);
+///
+/// let quotients = Polynomial::batch_deep_quotients(&polynomials, z);
+/// assert_eq!(quotients[0].fast_coset_evaluate(offset, omega, 8), quotient_codewords[0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of `codewords` and `values` differ, if the `codewords` are not all of the
+/// same length, or if `z` is an element of the domain.
+pub fn batch_deep_quotients<FF>(
+    codewords: &[Vec<FF>],
+    offset: BFieldElement,
+    omega: BFieldElement,
+    z: FF,
+    values: &[FF],
+) -> Vec<Vec<FF>>
+where
+    FF: FiniteField + From<BFieldElement>,
+{
+    assert_eq!(
+        codewords.len(),
+        values.len(),
+        "The number of codewords and values must be equal."
+    );
+    let Some(codeword_length) = codewords.first().map(Vec::len) else {
+        return vec![];
+    };
+    assert!(
+        codewords.iter().all(|c| c.len() == codeword_length),
+        "All codewords must be of equal length."
+    );
+
+    let domain_minus_z = std::iter::successors(Some(offset), |&x| Some(x * omega))
+        .take(codeword_length)
+        .map(|x| FF::from(x) - z)
+        .collect();
+    let inverses = FF::batch_inversion(domain_minus_z);
+
+    codewords
+        .par_iter()
+        .zip(values)
+        .map(|(codeword, &value)| {
+            codeword
+                .iter()
+                .zip(&inverses)
+                .map(|(&evaluation, &inverse)| (evaluation - value) * inverse)
+                .collect()
+        })
+        .collect()
+}
+
+/// The degree of the polynomial of lowest degree that agrees with the `codeword`.
+///
+/// The `codeword` is assumed to hold the evaluations of some polynomial on a coset of the subgroup
+/// of order `codeword.len()`. The degree is independent of the coset's offset, which is why it
+/// is not required. If the polynomial's degree is smaller than the length of the `codeword`, the
+/// returned degree is exact. Otherwise, it is usually `codeword.len() - 1`. The zero codeword,
+/// including the empty one, has degree -1, like the [zero polynomial][degree].
+///
+/// Internally, this is an [inverse NTT](intt_owned) followed by counting the trailing zeros of the
+/// resulting coefficients.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::estimate_degree;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+/// let codeword = polynomial.fast_coset_evaluate(BFieldElement::generator(), omega, 8);
+/// assert_eq!(2, estimate_degree(&codeword).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of the `codeword` is neither zero nor a power of two, or if it
+/// is too large for an NTT.
+///
+/// [degree]: crate::math::polynomial::Polynomial::degree
+pub fn estimate_degree<FF>(codeword: &[FF]) -> Result<isize, NttError>
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+{
+    let coefficients = intt_owned(codeword.to_vec())?;
+    let num_trailing_zeros = coefficients
+        .iter()
+        .rev()
+        .take_while(|c| c.is_zero())
+        .count();
+    let degree = coefficients.len() as isize - num_trailing_zeros as isize - 1;
+
+    Ok(degree)
+}
+
+/// The fraction of positions in which the two codewords differ.
+///
+/// The distance between two empty codewords is 0.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::relative_hamming_distance;
+/// let codeword_a = bfe_vec![1, 2, 3, 4];
+/// let codeword_b = bfe_vec![1, 2, 0, 4];
+/// assert_eq!(0.25, relative_hamming_distance(&codeword_a, &codeword_b));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the codewords are not of equal length.
+pub fn relative_hamming_distance<FF: PartialEq>(codeword_a: &[FF], codeword_b: &[FF]) -> f64 {
+    assert_eq!(
+        codeword_a.len(),
+        codeword_b.len(),
+        "The codewords must be of equal length."
+    );
+    if codeword_a.is_empty() {
+        return 0.0;
+    }
+
+    let num_differences = codeword_a
+        .iter()
+        .zip(codeword_b)
+        .filter(|(a, b)| a != b)
+        .count();
+    num_differences as f64 / codeword_a.len() as f64
+}
+
+/// An upper bound on the [relative Hamming distance](relative_hamming_distance) of the `codeword`
+/// to the closest codeword of a polynomial of degree at most `degree_bound`.
+///
+/// Like for [`estimate_degree`], the `codeword` is assumed to hold the evaluations of some
+/// polynomial on a coset of the subgroup of order `codeword.len()`. If the codeword's degree is
+/// within the `degree_bound`, the distance is 0. Otherwise, each of the `num_trials` trials
+/// interpolates the codeword on `degree_bound + 1` random positions and measures the distance to
+/// the resulting low-degree codeword. The smallest distance found is returned.
+///
+/// Finding the exact distance is hard in general. However, the closer the `codeword` is to some
+/// low-degree codeword, the more likely it is that a trial only hits positions where the two
+/// agree, in which case the exact distance is found. This makes the estimate useful for
+/// experimenting with proximity parameters, but not as a soundness guarantee.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::distance_to_low_degree;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let omega = BFieldElement::primitive_root_of_unity(16).unwrap();
+/// let mut codeword = polynomial.fast_coset_evaluate(bfe!(1), omega, 16);
+/// codeword[5] += bfe!(1);
+///
+/// let mut rng = rand::thread_rng();
+/// assert_eq!(0.0, distance_to_low_degree(&codeword, 15, 10, &mut rng).unwrap());
+/// assert!(distance_to_low_degree(&codeword, 2, 100, &mut rng).unwrap() >= 1.0 / 16.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of the `codeword` is neither zero nor a power of two, or if it
+/// is too large for an NTT.
+pub fn distance_to_low_degree<FF, R>(
+    codeword: &[FF],
+    degree_bound: isize,
+    num_trials: usize,
+    rng: &mut R,
+) -> Result<f64, NttError>
+where
+    FF: FiniteField + MulAssign<BFieldElement> + 'static,
+    R: Rng + ?Sized,
+{
+    if estimate_degree(codeword)? <= degree_bound {
+        return Ok(0.0);
+    }
+
+    // The codeword is non-zero and the degree bound is smaller than its length.
+    let codeword_length = codeword.len();
+    if degree_bound < 0 {
+        let zero_codeword = vec![FF::zero(); codeword_length];
+        return Ok(relative_hamming_distance(codeword, &zero_codeword));
+    }
+
+    let num_points = (degree_bound + 1) as usize;
+    let omega = FF::primitive_root_of_unity(codeword_length as u64).unwrap();
+    let domain = (0..codeword_length)
+        .scan(FF::one(), |power, _| {
+            let current_power = *power;
+            *power *= omega;
+            Some(current_power)
+        })
+        .collect::<Vec<_>>();
+
+    // Any `num_points` positions can be matched by interpolation.
+    let mut distance = 1.0 - num_points as f64 / codeword_length as f64;
+    for _ in 0..num_trials {
+        let (points, values): (Vec<_>, Vec<_>) =
+            rand::seq::index::sample(rng, codeword_length, num_points)
+                .into_iter()
+                .map(|i| (domain[i], codeword[i]))
+                .unzip();
+        let mut coefficients = Polynomial::interpolate(&points, &values).coefficients;
+        coefficients.resize(codeword_length, FF::zero());
+        let low_degree_codeword = ntt_owned(coefficients)?;
+        distance = distance.min(relative_hamming_distance(codeword, &low_degree_codeword));
+    }
+
+    Ok(distance)
+}
+
+/// Whether all elements of the `codeword` are elements of the [base field](BFieldElement).
+pub fn is_in_base_field(codeword: &[XFieldElement]) -> bool {
+    codeword.iter().all(|c| c.unlift().is_some())
+}
+
+/// The codeword over the [base field](BFieldElement) with the same elements as the given
+/// `codeword`, if all of them are elements of the base field. Otherwise, `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::codeword::try_unlift;
+/// assert_eq!(Some(bfe_vec![1, 2]), try_unlift(&xfe_vec![1, 2]));
+/// assert_eq!(None, try_unlift(&[xfe!(1), xfe!([0, 1, 0])]));
+/// ```
+pub fn try_unlift(codeword: &[XFieldElement]) -> Option<Vec<BFieldElement>> {
+    codeword.iter().map(|c| c.unlift()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use num_traits::One;
+    use num_traits::Zero;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use test_strategy::proptest;
+
+    use crate::bfe;
+    use crate::bfe_array;
+    use crate::bfe_vec;
+    use crate::math::traits::PrimitiveRootOfUnity;
+
+    use super::*;
+
+    /// Split `f(x)` into `f_E(x)` and `f_O(x)` such that `f(x) = f_E(x²) + x·f_O(x²)`.
+    fn split_polynomial<FF: FiniteField>(
+        polynomial: &Polynomial<FF>,
+    ) -> (Polynomial<FF>, Polynomial<FF>) {
+        let (even, odd): (Vec<_>, Vec<_>) = polynomial
+            .coefficients
+            .iter()
+            .enumerate()
+            .partition_map(|(i, &c)| match i % 2 {
+                0 => itertools::Either::Left(c),
+                _ => itertools::Either::Right(c),
+            });
+        (Polynomial::new(even), Polynomial::new(odd))
+    }
+
+    #[proptest(cases = 50)]
+    fn folding_codeword_corresponds_to_folding_polynomial(
+        #[strategy(1_u32..=8)] log_2_codeword_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_codeword_length))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())] challenge: XFieldElement,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+    ) {
+        let codeword_length = 1 << log_2_codeword_length;
+        let omega = BFieldElement::primitive_root_of_unity(codeword_length).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(offset, omega, codeword_length as usize);
+
+        let folded_codeword =
+            fold_codeword(&codeword, challenge, omega.inverse(), offset.inverse());
+
+        let (even, odd) = split_polynomial(&polynomial);
+        let folded_polynomial = even + odd.scalar_mul(challenge);
+        let folded_domain = (0..codeword_length / 2)
+            .map(|i| offset.square() * omega.mod_pow(2 * i))
+            .map(XFieldElement::new_const)
+            .collect_vec();
+        let expected_folded_codeword = folded_polynomial.batch_evaluate(&folded_domain);
+        prop_assert_eq!(expected_folded_codeword, folded_codeword);
+    }
+
+    #[proptest(cases = 20)]
+    fn folding_constant_codeword_with_zero_challenge_gives_constant_codeword(
+        #[strategy(arb())] constant: BFieldElement,
+        #[strategy(1_u32..=8)] log_2_codeword_length: u32,
+    ) {
+        let codeword_length = 1 << log_2_codeword_length;
+        let codeword = vec![constant; codeword_length];
+        let omega_inv = BFieldElement::primitive_root_of_unity(codeword_length as u64)
+            .unwrap()
+            .inverse();
+        let offset_inv = BFieldElement::generator().inverse();
+
+        let folded_codeword =
+            fold_codeword(&codeword, BFieldElement::zero(), omega_inv, offset_inv);
+        prop_assert_eq!(vec![constant; codeword_length / 2], folded_codeword);
+    }
+
+    #[proptest(cases = 50)]
+    fn rotating_codeword_corresponds_to_scaling_polynomial(
+        #[strategy(0_u32..=8)] log_2_codeword_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_codeword_length))] coefficients: Vec<BFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+        k: usize,
+    ) {
+        let codeword_length = 1_usize << log_2_codeword_length;
+        let omega = BFieldElement::primitive_root_of_unity(codeword_length as u64).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(offset, omega, codeword_length);
+
+        let scaled_polynomial = polynomial.scale(omega.mod_pow(k as u64));
+        let expected = scaled_polynomial.fast_coset_evaluate(offset, omega, codeword_length);
+        prop_assert_eq!(expected, rotate(&codeword, k));
+    }
+
+    #[proptest]
+    fn rotating_codeword_by_its_length_is_identity(
+        #[strategy(arb())] codeword: Vec<BFieldElement>,
+    ) {
+        prop_assert_eq!(&codeword, &rotate(&codeword, 0));
+        prop_assert_eq!(&codeword, &rotate(&codeword, codeword.len()));
+    }
+
+    #[proptest(cases = 50)]
+    fn changing_domain_corresponds_to_evaluating_polynomial_on_new_domain(
+        #[strategy(0_u32..=6)] log_2_from_length: u32,
+        #[strategy(#log_2_from_length..=8)] log_2_to_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_from_length))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#from_offset.is_zero())]
+        from_offset: BFieldElement,
+        #[strategy(arb())] to_offset: BFieldElement,
+    ) {
+        let from_length = 1_usize << log_2_from_length;
+        let to_length = 1_usize << log_2_to_length;
+        let omega = BFieldElement::primitive_root_of_unity(from_length as u64).unwrap();
+        let eta = BFieldElement::primitive_root_of_unity(to_length as u64).unwrap();
+
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(from_offset, omega, from_length);
+        let expected = polynomial.fast_coset_evaluate(to_offset, eta, to_length);
+        let new_codeword = change_domain(&codeword, from_offset, to_offset, to_length)?;
+        prop_assert_eq!(expected, new_codeword);
+    }
+
+    #[proptest(cases = 20)]
+    fn changing_domain_to_smaller_domain_works_for_low_degree_polynomials(
+        #[strategy(vec(arb(), 4))] coefficients: Vec<BFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#from_offset.is_zero())]
+        from_offset: BFieldElement,
+        #[strategy(arb())] to_offset: BFieldElement,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(16).unwrap();
+        let eta = BFieldElement::primitive_root_of_unity(4).unwrap();
+
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(from_offset, omega, 16);
+        let expected = polynomial.fast_coset_evaluate(to_offset, eta, 4);
+        prop_assert_eq!(
+            expected,
+            change_domain(&codeword, from_offset, to_offset, 4)?
+        );
+    }
+
+    #[test]
+    fn changing_domain_to_length_not_power_of_two_fails() {
+        let codeword = vec![BFieldElement::one(); 4];
+        let offset = BFieldElement::one();
+        let to_length_err = change_domain(&codeword, offset, offset, 6).unwrap_err();
+        assert_eq!(NttError::LengthNotPowerOfTwo(6), to_length_err);
+
+        let from_length_err = change_domain(&codeword[..3], offset, offset, 8).unwrap_err();
+        assert_eq!(NttError::LengthNotPowerOfTwo(3), from_length_err);
+    }
+
+    #[test]
+    #[should_panic(expected = "must fit into the target domain")]
+    fn changing_domain_to_too_small_domain_panics() {
+        let codeword = bfe_vec![1, 2, 3, 4];
+        let offset = BFieldElement::one();
+        let _ = change_domain(&codeword, offset, offset, 2);
+    }
+
+    #[proptest(cases = 50)]
+    fn sub_codeword_corresponds_to_evaluation_on_sub_coset(
+        #[strategy(0_u32..=8)] log_2_codeword_length: u32,
+        #[strategy(0..=#log_2_codeword_length)] log_2_stride: u32,
+        #[strategy(0_usize..1 << #log_2_stride)] shift: usize,
+        #[strategy(vec(arb(), 1 << #log_2_codeword_length))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+    ) {
+        let codeword_length = 1_usize << log_2_codeword_length;
+        let stride = 1_usize << log_2_stride;
+        let omega = BFieldElement::primitive_root_of_unity(codeword_length as u64).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(offset, omega, codeword_length);
+
+        let (sub_offset, sub_omega) = sub_coset(offset, omega, stride, shift);
+        let sub_domain = (0..codeword_length / stride)
+            .map(|j| sub_offset * sub_omega.mod_pow(j as u64))
+            .map(XFieldElement::new_const)
+            .collect_vec();
+        let expected = polynomial.batch_evaluate(&sub_domain);
+        let sub_codeword = sub_codeword(&codeword, stride, shift)
+            .copied()
+            .collect_vec();
+        prop_assert_eq!(expected, sub_codeword);
+    }
+
+    #[proptest]
+    fn sub_codewords_of_same_stride_partition_codeword(
+        #[strategy(1_usize..10)] stride: usize,
+        #[strategy(0_usize..10)] _sub_codeword_length: usize,
+        #[strategy(vec(arb(), #stride * #_sub_codeword_length))] codeword: Vec<BFieldElement>,
+    ) {
+        let mut sub_codewords = (0..stride)
+            .map(|shift| sub_codeword(&codeword, stride, shift))
+            .collect_vec();
+        let interleaved_sub_codewords = (0..codeword.len())
+            .map(|i| *sub_codewords[i % stride].next().unwrap())
+            .collect_vec();
+        prop_assert_eq!(codeword, interleaved_sub_codewords);
+    }
+
+    #[test]
+    fn sub_codewords_of_empty_codeword_are_empty() {
+        let codeword: Vec<BFieldElement> = vec![];
+        for shift in 0..2 {
+            assert_eq!(0, sub_codeword(&codeword, 2, shift).count());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "must divide the codeword length")]
+    fn sub_codeword_with_stride_not_dividing_codeword_length_panics() {
+        let codeword = bfe_vec![1, 2, 3, 4];
+        let _ = sub_codeword(&codeword, 3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be smaller than the stride")]
+    fn sub_codeword_with_too_large_shift_panics() {
+        let codeword = bfe_vec![1, 2, 3, 4];
+        let _ = sub_codeword(&codeword, 2, 2);
+    }
+
+    #[proptest(cases = 50)]
+    fn batch_deep_quotient_codewords_are_codewords_of_polynomial_quotients(
+        #[strategy(0_u32..=8)] log_2_codeword_length: u32,
+        #[strategy(vec(vec(arb(), 0..=1 << #log_2_codeword_length), 0..5))] coefficients: Vec<
+            Vec<XFieldElement>,
+        >,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+        #[filter(!is_in_base_field(&[#z]))] z: XFieldElement,
+    ) {
+        let codeword_length = 1_usize << log_2_codeword_length;
+        let omega = BFieldElement::primitive_root_of_unity(codeword_length as u64).unwrap();
+        let polynomials = coefficients.into_iter().map(Polynomial::new).collect_vec();
+        let codewords =
+            Polynomial::batch_fast_coset_evaluate(&polynomials, offset, omega, codeword_length);
+        let values = polynomials.iter().map(|p| p.evaluate(z)).collect_vec();
+
+        let quotients = Polynomial::batch_deep_quotients(&polynomials, z);
+        let expected =
+            Polynomial::batch_fast_coset_evaluate(&quotients, offset, omega, codeword_length);
+        let quotient_codewords = batch_deep_quotients(&codewords, offset, omega, z, &values);
+        prop_assert_eq!(expected, quotient_codewords);
+    }
+
+    #[test]
+    fn batch_deep_quotients_of_no_codewords_are_empty() {
+        let quotient_codewords = batch_deep_quotients(&[], bfe!(1), bfe!(1), bfe!(1), &[]);
+        assert!(quotient_codewords.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot do batch inversion on zero")]
+    fn batch_deep_quotients_with_point_in_domain_panics() {
+        let omega = BFieldElement::primitive_root_of_unity(4).unwrap();
+        let codewords = [bfe_vec![1, 2, 3, 4]];
+        let _ = batch_deep_quotients(&codewords, bfe!(1), omega, omega, &bfe_array![0]);
+    }
+
+    #[proptest(cases = 50)]
+    fn estimated_degree_of_low_degree_codeword_is_polynomial_degree(
+        #[strategy(0_u32..=8)] log_2_codeword_length: u32,
+        #[strategy(vec(arb(), 0..=1 << #log_2_codeword_length))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+    ) {
+        let codeword_length = 1_usize << log_2_codeword_length;
+        let omega = BFieldElement::primitive_root_of_unity(codeword_length as u64).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(offset, omega, codeword_length);
+        prop_assert_eq!(polynomial.degree(), estimate_degree(&codeword)?);
+    }
+
+    #[test]
+    fn estimated_degree_of_zero_codeword_is_minus_one() {
+        assert_eq!(Ok(-1), estimate_degree::<BFieldElement>(&[]));
+        assert_eq!(Ok(-1), estimate_degree(&[BFieldElement::zero(); 4]));
+    }
+
+    #[test]
+    fn estimating_degree_of_codeword_with_length_not_power_of_two_fails() {
+        let codeword = bfe_vec![1, 2, 3];
+        let err = estimate_degree(&codeword).unwrap_err();
+        assert_eq!(NttError::LengthNotPowerOfTwo(3), err);
+    }
+
+    #[proptest]
+    fn relative_hamming_distance_is_fraction_of_changed_positions(
+        #[strategy(vec(arb(), 1..100))] codeword: Vec<BFieldElement>,
+        #[strategy(vec(0..#codeword.len(), 0..#codeword.len()))] positions: Vec<usize>,
+    ) {
+        let mut changed_codeword = codeword.clone();
+        for &position in &positions {
+            changed_codeword[position] = codeword[position] + BFieldElement::one();
+        }
+        let num_changed_positions = positions.iter().unique().count();
+        let expected = num_changed_positions as f64 / codeword.len() as f64;
+        prop_assert_eq!(
+            expected,
+            relative_hamming_distance(&codeword, &changed_codeword)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be of equal length")]
+    fn relative_hamming_distance_of_codewords_with_different_lengths_panics() {
+        let _ = relative_hamming_distance(&bfe_array![1, 2], &bfe_array![1]);
+    }
+
+    #[proptest(cases = 20)]
+    fn low_degree_codeword_has_distance_zero(
+        #[strategy(vec(arb(), 0..=32))] coefficients: Vec<XFieldElement>,
+        seed: u64,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(32).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = polynomial.fast_coset_evaluate(BFieldElement::generator(), omega, 32);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let distance = distance_to_low_degree(&codeword, polynomial.degree(), 0, &mut rng)?;
+        prop_assert_eq!(0.0, distance);
+    }
+
+    #[proptest(cases = 20)]
+    fn distance_of_slightly_corrupted_codeword_is_found(
+        #[strategy(vec(arb(), 4))] coefficients: Vec<BFieldElement>,
+        #[strategy(0_usize..32)] corrupted_position_0: usize,
+        #[strategy(0_usize..32)]
+        #[filter(#corrupted_position_0 != #corrupted_position_1)]
+        corrupted_position_1: usize,
+        seed: u64,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(32).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let mut codeword = polynomial.fast_coset_evaluate(BFieldElement::generator(), omega, 32);
+        codeword[corrupted_position_0] += BFieldElement::one();
+        codeword[corrupted_position_1] += BFieldElement::one();
+
+        // Each trial avoids both corrupted positions with probability > 3/4.
+        let mut rng = StdRng::seed_from_u64(seed);
+        let distance = distance_to_low_degree(&codeword, 3, 100, &mut rng)?;
+        prop_assert_eq!(2.0 / 32.0, distance);
+    }
+
+    #[test]
+    fn distance_to_zero_codeword_is_fraction_of_non_zero_positions() {
+        let codeword = bfe_vec![0, 1, 0, 0, 2, 0, 0, 0];
+        let distance = distance_to_low_degree(&codeword, -1, 10, &mut rand::thread_rng());
+        assert_eq!(Ok(0.25), distance);
+    }
+
+    #[test]
+    fn distance_without_trials_is_trivial_bound() {
+        let codeword = bfe_vec![0, 1, 0, 0, 2, 0, 0, 0];
+        let distance = distance_to_low_degree(&codeword, 1, 0, &mut rand::thread_rng());
+        assert_eq!(Ok(0.75), distance);
+    }
+
+    #[proptest]
+    fn lifting_then_unlifting_codeword_is_identity(
+        #[strategy(arb())] codeword: Vec<BFieldElement>,
+    ) {
+        let lifted_codeword = codeword.iter().map(|c| c.lift()).collect_vec();
+        prop_assert!(is_in_base_field(&lifted_codeword));
+        prop_assert_eq!(Some(codeword), try_unlift(&lifted_codeword));
+    }
+
+    #[proptest]
+    fn codeword_with_element_outside_base_field_cannot_be_unlifted(
+        #[strategy(arb())] mut codeword: Vec<XFieldElement>,
+        #[strategy(arb())]
+        #[filter(#element.unlift().is_none())]
+        element: XFieldElement,
+        #[strategy(0..=#codeword.len())] index: usize,
+    ) {
+        codeword.insert(index, element);
+        prop_assert!(!is_in_base_field(&codeword));
+        prop_assert_eq!(None, try_unlift(&codeword));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a power of two")]
+    fn folding_codeword_of_length_not_power_of_two_panics() {
+        let codeword = vec![BFieldElement::one(); 6];
+        fold_codeword(
+            &codeword,
+            BFieldElement::one(),
+            BFieldElement::one(),
+            BFieldElement::one(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a power of two")]
+    fn folding_codeword_of_length_one_panics() {
+        let codeword = vec![BFieldElement::one()];
+        fold_codeword(
+            &codeword,
+            BFieldElement::one(),
+            BFieldElement::one(),
+            BFieldElement::one(),
+        );
+    }
+}
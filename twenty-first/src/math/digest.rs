@@ -10,6 +10,7 @@ use num_traits::Zero;
 use rand::Rng;
 use rand_distr::Distribution;
 use rand_distr::Standard;
+use rayon::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -17,6 +18,8 @@ use crate::error::TryFromDigestError;
 use crate::math::b_field_element::BFieldElement;
 use crate::math::b_field_element::BFIELD_ZERO;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::algebraic_hasher::Domain;
+use crate::util_types::algebraic_hasher::RATE;
 
 pub const DIGEST_LENGTH: usize = 5;
 
@@ -206,6 +209,94 @@ impl Digest {
     pub fn hash<H: AlgebraicHasher>(self) -> Digest {
         H::hash_pair(self, Digest::new([BFieldElement::zero(); DIGEST_LENGTH]))
     }
+
+    /// Hash `self` `length` times in a row, using [`hash`](Self::hash) for every step. A hash chain
+    /// of length 0 is `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let seed = Digest::default();
+    /// assert_eq!(seed, seed.hash_chain::<Tip5>(0));
+    /// assert_eq!(seed.hash::<Tip5>().hash::<Tip5>(), seed.hash_chain::<Tip5>(2));
+    /// ```
+    pub fn hash_chain<H: AlgebraicHasher>(self, length: usize) -> Digest {
+        (0..length).fold(self, |digest, _| digest.hash::<H>())
+    }
+
+    /// All links of the [hash chain](Self::hash_chain) of the given `length`, starting with `self`.
+    /// The returned vector contains `length + 1` digests; the `i`th one is `self.hash_chain(i)`.
+    pub fn hash_chain_links<H: AlgebraicHasher>(self, length: usize) -> Vec<Digest> {
+        let mut links = Vec::with_capacity(length + 1);
+        links.push(self);
+        for i in 0..length {
+            links.push(links[i].hash::<H>());
+        }
+        links
+    }
+
+    /// The [hash chains](Self::hash_chain) of the given `length` for all `seeds`, computed in
+    /// parallel.
+    pub fn par_hash_chains<H: AlgebraicHasher>(seeds: &[Digest], length: usize) -> Vec<Digest> {
+        seeds
+            .par_iter()
+            .map(|&seed| seed.hash_chain::<H>(length))
+            .collect()
+    }
+
+    /// Compress `self` under the given `key`, for example, to derive nonces from a secret seed.
+    ///
+    /// The `key` and `self` are absorbed in a single permutation of a sponge initialized in the
+    /// [keyed-hash domain](Domain::KeyedHash). Hence, keyed hashes never coincide with
+    /// [`hash`](Self::hash) or [`hash_pair`](AlgebraicHasher::hash_pair), except by chance.
+    pub fn keyed_hash<H: AlgebraicHasher>(self, key: Digest) -> Digest {
+        let mut input = [BFIELD_ZERO; RATE];
+        input[..DIGEST_LENGTH].copy_from_slice(&key.values());
+        input[DIGEST_LENGTH..2 * DIGEST_LENGTH].copy_from_slice(&self.values());
+
+        let mut sponge = H::init_in_domain(Domain::KeyedHash);
+        sponge.absorb(input);
+        let produce = sponge.squeeze();
+
+        Digest::new((&produce[..DIGEST_LENGTH]).try_into().unwrap())
+    }
+
+    /// Chain the `blocks` onto the initial value `self` in Merkle–Damgård fashion, using the
+    /// [keyed hash](Self::keyed_hash) as compression function: every block is compressed under the
+    /// previous chaining value. A final block encoding the number of blocks is appended, so that the
+    /// chain of a message is not an intermediate chaining value of any longer message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let initial_value = Digest::default();
+    /// let block = Tip5::hash_varlen(&bfe_vec![42]);
+    /// let length_block = Digest::new(bfe_array![1, 0, 0, 0, 0]);
+    /// let chained = length_block.keyed_hash::<Tip5>(block.keyed_hash::<Tip5>(initial_value));
+    /// assert_eq!(chained, initial_value.merkle_damgard::<Tip5>(&[block]));
+    /// ```
+    pub fn merkle_damgard<H: AlgebraicHasher>(self, blocks: &[Digest]) -> Digest {
+        let mut length_block = [BFIELD_ZERO; DIGEST_LENGTH];
+        length_block[0] = BFieldElement::new(blocks.len() as u64);
+
+        blocks
+            .iter()
+            .chain([&Digest::new(length_block)])
+            .fold(self, |chaining_value, block| {
+                block.keyed_hash::<H>(chaining_value)
+            })
+    }
+
+    /// The [Merkle–Damgård chains](Self::merkle_damgard) of all `messages` for the initial value
+    /// `self`, computed in parallel.
+    pub fn par_merkle_damgard<H: AlgebraicHasher>(self, messages: &[Vec<Digest>]) -> Vec<Digest> {
+        messages
+            .par_iter()
+            .map(|blocks| self.merkle_damgard::<H>(blocks))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -410,6 +501,87 @@ pub(crate) mod digest_tests {
         assert_eq!(TryFromDigestError::Overflow, err);
     }
 
+    #[proptest(cases = 20)]
+    fn hash_chains_compose(
+        seed: Digest,
+        #[strategy(0_usize..10)] length_0: usize,
+        #[strategy(0_usize..10)] length_1: usize,
+    ) {
+        let composed_chain = seed
+            .hash_chain::<Tip5>(length_0)
+            .hash_chain::<Tip5>(length_1);
+        prop_assert_eq!(seed.hash_chain::<Tip5>(length_0 + length_1), composed_chain);
+    }
+
+    #[proptest(cases = 20)]
+    fn hash_chain_links_agree_with_hash_chains(
+        seed: Digest,
+        #[strategy(0_usize..10)] length: usize,
+    ) {
+        let links = seed.hash_chain_links::<Tip5>(length);
+        prop_assert_eq!(length + 1, links.len());
+        for (i, link) in links.into_iter().enumerate() {
+            prop_assert_eq!(seed.hash_chain::<Tip5>(i), link);
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn parallel_hash_chains_agree_with_sequential_hash_chains(
+        #[strategy(vec(arb(), 0..20))] seeds: Vec<Digest>,
+        #[strategy(0_usize..10)] length: usize,
+    ) {
+        let hash_chains = seeds.iter().map(|seed| seed.hash_chain::<Tip5>(length));
+        let par_hash_chains = Digest::par_hash_chains::<Tip5>(&seeds, length);
+        prop_assert_eq!(hash_chains.collect_vec(), par_hash_chains);
+    }
+
+    #[proptest]
+    fn keyed_hash_depends_on_key(
+        digest: Digest,
+        key_0: Digest,
+        #[filter(#key_0 != #key_1)] key_1: Digest,
+    ) {
+        let keyed_hash_0 = digest.keyed_hash::<Tip5>(key_0);
+        let keyed_hash_1 = digest.keyed_hash::<Tip5>(key_1);
+        prop_assert_ne!(keyed_hash_0, keyed_hash_1);
+    }
+
+    #[proptest]
+    fn keyed_hash_is_domain_separated_from_hashing(digest: Digest, key: Digest) {
+        let keyed_hash = digest.keyed_hash::<Tip5>(key);
+        prop_assert_ne!(Tip5::hash_pair(key, digest), keyed_hash);
+        prop_assert_ne!(
+            key.hash::<Tip5>(),
+            Digest::default().keyed_hash::<Tip5>(key)
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn merkle_damgard_chains_depend_on_message_length(
+        initial_value: Digest,
+        #[strategy(vec(arb(), 0..10))] blocks: Vec<Digest>,
+    ) {
+        let chain = initial_value.merkle_damgard::<Tip5>(&blocks);
+        let extended_blocks = [blocks.clone(), vec![Digest::default()]].concat();
+        prop_assert_ne!(
+            chain,
+            initial_value.merkle_damgard::<Tip5>(&extended_blocks)
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn parallel_merkle_damgard_chains_agree_with_sequential_chains(
+        initial_value: Digest,
+        #[strategy(vec(vec(arb(), 0..5), 0..10))] messages: Vec<Vec<Digest>>,
+    ) {
+        let chains = messages
+            .iter()
+            .map(|blocks| initial_value.merkle_damgard::<Tip5>(blocks))
+            .collect_vec();
+        let par_chains = initial_value.par_merkle_damgard::<Tip5>(&messages);
+        prop_assert_eq!(chains, par_chains);
+    }
+
     #[proptest]
     fn forty_bytes_can_be_converted_to_digest(bytes: [u8; Digest::BYTES]) {
         let digest = Digest::from(bytes);
@@ -0,0 +1,267 @@
+//! Memoization of [batch evaluations](Polynomial::batch_evaluate) of polynomials on fixed domains.
+//!
+//! Verifiers often evaluate the same public polynomials, like zerofiers or selectors, on the same
+//! domains again and again, for example once for every proof they check. An [`EvaluationCache`]
+//! evaluates every combination of polynomial and [registered](EvaluationCache::register_domain)
+//! domain only once. Polynomials are identified by a [digest](Digest) of their coefficients, not
+//! by their memory location, so the cache can be used with polynomials that are re-created for
+//! every proof.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::evaluation_cache::EvaluationCache;
+//! let mut cache = EvaluationCache::new();
+//! let domain = cache.register_domain(bfe_vec![1, 2, 3]);
+//!
+//! let zerofier = Polynomial::zerofier(&bfe_array![1, 2]);
+//! assert_eq!(bfe_vec![0, 0, 2], cache.evaluate(&zerofier, domain));
+//!
+//! // evaluated only once
+//! let same_zerofier = Polynomial::zerofier(&bfe_array![2, 1]);
+//! assert_eq!(bfe_vec![0, 0, 2], cache.evaluate(&same_zerofier, domain));
+//! assert_eq!(1, cache.len());
+//! ```
+
+use std::collections::HashMap;
+use std::ops::MulAssign;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::bfield_codec::BFieldCodec;
+use crate::math::digest::Digest;
+use crate::math::polynomial::Polynomial;
+use crate::math::tip5::Tip5;
+use crate::math::traits::FiniteField;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+/// The identifier of the next [`EvaluationCache`] to be created.
+static NEXT_CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Identifies a domain [registered](EvaluationCache::register_domain) with an [`EvaluationCache`].
+///
+/// Every id remembers the cache that handed it out. Using it with any other cache, including
+/// clones of the original cache, is detected.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DomainId {
+    cache_id: usize,
+    index: usize,
+}
+
+/// Memoizes batch evaluations of polynomials on registered domains. See the
+/// [module-level documentation](self).
+#[derive(Debug)]
+pub struct EvaluationCache<FF: FiniteField> {
+    id: usize,
+    domains: Vec<Vec<FF>>,
+    evaluations: HashMap<(Digest, DomainId), Vec<FF>>,
+}
+
+/// The clone is a new cache with the same domains and evaluations. The domains are re-registered
+/// under new [`DomainId`]s, which are available through [`domain_ids`](EvaluationCache::domain_ids).
+impl<FF: FiniteField> Clone for EvaluationCache<FF> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        clone.domains.clone_from(&self.domains);
+        clone.evaluations = self
+            .evaluations
+            .iter()
+            .map(|(&(digest, domain_id), evaluations)| {
+                let clone_domain_id = clone.domain_id(domain_id.index);
+                ((digest, clone_domain_id), evaluations.clone())
+            })
+            .collect();
+        clone
+    }
+}
+
+impl<FF: FiniteField> Default for EvaluationCache<FF> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<FF: FiniteField> EvaluationCache<FF> {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_CACHE_ID.fetch_add(1, Ordering::Relaxed),
+            domains: vec![],
+            evaluations: HashMap::new(),
+        }
+    }
+
+    /// Register a domain to evaluate polynomials on. Registering the same domain multiple times
+    /// gives different [`DomainId`]s, which do not share cached evaluations.
+    pub fn register_domain(&mut self, domain: Vec<FF>) -> DomainId {
+        self.domains.push(domain);
+        self.domain_id(self.domains.len() - 1)
+    }
+
+    /// The [`DomainId`]s of all registered domains, in order of registration.
+    pub fn domain_ids(&self) -> impl ExactSizeIterator<Item = DomainId> + '_ {
+        (0..self.domains.len()).map(|index| self.domain_id(index))
+    }
+
+    /// The domain registered under the given `id`, or `None` if the `id` was not handed out by
+    /// this cache.
+    pub fn domain(&self, id: DomainId) -> Option<&[FF]> {
+        if id.cache_id != self.id {
+            return None;
+        }
+        self.domains.get(id.index).map(|domain| domain.as_slice())
+    }
+
+    fn domain_id(&self, index: usize) -> DomainId {
+        DomainId {
+            cache_id: self.id,
+            index,
+        }
+    }
+
+    /// The number of cached batch evaluations.
+    pub fn len(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.evaluations.is_empty()
+    }
+
+    /// Forget all cached evaluations. The registered domains remain registered.
+    pub fn clear(&mut self) {
+        self.evaluations.clear();
+    }
+}
+
+impl<FF> EvaluationCache<FF>
+where
+    FF: FiniteField + BFieldCodec + MulAssign<BFieldElement> + 'static,
+{
+    /// The evaluations of the `polynomial` on the domain registered under `domain_id`. Unless
+    /// cached, the evaluations are computed using [`Polynomial::batch_evaluate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `domain_id` was not handed out by this cache.
+    pub fn evaluate(&mut self, polynomial: &Polynomial<FF>, domain_id: DomainId) -> &[FF] {
+        assert_eq!(
+            self.id, domain_id.cache_id,
+            "domain should be registered with this cache"
+        );
+        let domain = &self.domains[domain_id.index];
+
+        self.evaluations
+            .entry((Self::digest(polynomial), domain_id))
+            .or_insert_with(|| polynomial.batch_evaluate(domain))
+    }
+
+    /// Whether the evaluations of the `polynomial` on the domain registered under `domain_id` are
+    /// cached.
+    pub fn contains(&self, polynomial: &Polynomial<FF>, domain_id: DomainId) -> bool {
+        self.evaluations
+            .contains_key(&(Self::digest(polynomial), domain_id))
+    }
+
+    /// Identifies the polynomial independent of trailing zero coefficients.
+    fn digest(polynomial: &Polynomial<FF>) -> Digest {
+        let num_coefficients = usize::try_from(polynomial.degree() + 1).unwrap();
+        Tip5::hash(&polynomial.coefficients[..num_coefficients].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::prelude::XFieldElement;
+
+    #[proptest]
+    fn cached_evaluations_are_batch_evaluations(
+        polynomial: Polynomial<XFieldElement>,
+        domain: Vec<XFieldElement>,
+    ) {
+        let mut cache = EvaluationCache::new();
+        let domain_id = cache.register_domain(domain.clone());
+        prop_assert!(!cache.contains(&polynomial, domain_id));
+
+        let evaluations = polynomial.batch_evaluate(&domain);
+        prop_assert_eq!(&evaluations, cache.evaluate(&polynomial, domain_id));
+        prop_assert!(cache.contains(&polynomial, domain_id));
+        prop_assert_eq!(&evaluations, cache.evaluate(&polynomial, domain_id));
+        prop_assert_eq!(1, cache.len());
+    }
+
+    #[proptest]
+    fn trailing_zeros_do_not_affect_the_cache_key(
+        polynomial: Polynomial<BFieldElement>,
+        #[strategy(0_usize..10)] num_trailing_zeros: usize,
+    ) {
+        let mut padded_polynomial = polynomial.clone();
+        padded_polynomial
+            .coefficients
+            .extend(vec![BFieldElement::new(0); num_trailing_zeros]);
+
+        let mut cache = EvaluationCache::new();
+        let domain_id = cache.register_domain(vec![BFieldElement::new(42)]);
+        cache.evaluate(&polynomial, domain_id);
+        prop_assert!(cache.contains(&padded_polynomial, domain_id));
+    }
+
+    #[test]
+    fn domains_registered_separately_do_not_share_evaluations() {
+        let mut cache = EvaluationCache::new();
+        let domain = vec![BFieldElement::new(1)];
+        let domain_id_0 = cache.register_domain(domain.clone());
+        let domain_id_1 = cache.register_domain(domain);
+        assert_ne!(domain_id_0, domain_id_1);
+
+        let polynomial = Polynomial::new(vec![BFieldElement::new(1)]);
+        cache.evaluate(&polynomial, domain_id_0);
+        assert!(!cache.contains(&polynomial, domain_id_1));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(cache.domain(domain_id_1).is_some());
+    }
+
+    #[test]
+    fn domain_ids_are_tied_to_the_cache_that_handed_them_out() {
+        let mut cache = EvaluationCache::new();
+        let mut other_cache = EvaluationCache::new();
+        let domain_id = cache.register_domain(vec![BFieldElement::new(1)]);
+        let other_domain_id = other_cache.register_domain(vec![BFieldElement::new(2)]);
+        assert_ne!(domain_id, other_domain_id);
+        assert!(other_cache.domain(domain_id).is_none());
+
+        let polynomial = Polynomial::new(vec![BFieldElement::new(1)]);
+        cache.evaluate(&polynomial, domain_id);
+        assert!(!other_cache.contains(&polynomial, domain_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "domain should be registered with this cache")]
+    fn evaluating_on_domain_of_other_cache_panics() {
+        let mut cache = EvaluationCache::new();
+        let domain_id = EvaluationCache::new().register_domain(vec![BFieldElement::new(1)]);
+        cache.register_domain(vec![BFieldElement::new(2)]);
+        cache.evaluate(&Polynomial::new(vec![BFieldElement::new(1)]), domain_id);
+    }
+
+    #[test]
+    fn cloned_cache_re_registers_domains_and_evaluations() {
+        let mut cache = EvaluationCache::new();
+        let domain_id = cache.register_domain(vec![BFieldElement::new(1)]);
+        let polynomial = Polynomial::new(vec![BFieldElement::new(1)]);
+        cache.evaluate(&polynomial, domain_id);
+
+        let clone = cache.clone();
+        assert!(clone.domain(domain_id).is_none());
+        let clone_domain_id = clone.domain_ids().next().unwrap();
+        assert_eq!(cache.domain(domain_id), clone.domain(clone_domain_id));
+        assert!(clone.contains(&polynomial, clone_domain_id));
+    }
+}
@@ -0,0 +1,641 @@
+//! Compiled evaluation of arithmetic expressions over codewords.
+//!
+//! Evaluating many arithmetic expressions – for example, thousands of AIR constraints – over large
+//! codewords by composing whole-codeword operations allocates one temporary vector per
+//! operation. Instead, expressions can be built into a directed acyclic graph using an
+//! [`ExpressionBuilder`], which de-duplicates common sub-expressions, and then be
+//! [compiled](ExpressionBuilder::compile) into a [`CompiledExpressions`] program. The program
+//!
+//! - computes all sub-expressions that only depend on challenges and constants once,
+//! - evaluates everything else chunk by chunk, in parallel, using a small number of re-used
+//!   registers, and
+//! - writes the results directly into the output codewords.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::expression::ExpressionBuilder;
+//! let mut builder = ExpressionBuilder::new();
+//! let (x, y) = (builder.input(0), builder.input(1));
+//! let alpha = builder.challenge(0);
+//!
+//! // x·y - α  and  (x·y)²
+//! let x_times_y = builder.mul(x, y);
+//! let constraint_0 = builder.sub(x_times_y, alpha);
+//! let constraint_1 = builder.mul(x_times_y, x_times_y);
+//! let program = builder.compile(&[constraint_0, constraint_1]);
+//!
+//! let xs = bfe_vec![1, 2, 3];
+//! let ys = bfe_vec![4, 5, 6];
+//! let evaluations = program.evaluate(&[&xs, &ys], &bfe_vec![10]);
+//! assert_eq!(bfe_vec![-6, 0, 8], evaluations[0]);
+//! assert_eq!(bfe_vec![16, 100, 324], evaluations[1]);
+//! ```
+
+use std::collections::HashMap;
+use std::iter;
+
+use rayon::prelude::*;
+
+use crate::math::traits::FiniteField;
+
+/// The number of rows evaluated in one go by a [`CompiledExpressions`] program.
+const CHUNK_LENGTH: usize = 1 << 10;
+
+/// A handle to a node in the expression graph of some [`ExpressionBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExpressionId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node<FF> {
+    Input(usize),
+    Challenge(usize),
+    Constant(FF),
+    Add(ExpressionId, ExpressionId),
+    Sub(ExpressionId, ExpressionId),
+    Mul(ExpressionId, ExpressionId),
+    Neg(ExpressionId),
+}
+
+impl<FF> Node<FF> {
+    fn operands(&self) -> Vec<ExpressionId> {
+        match *self {
+            Node::Input(_) | Node::Challenge(_) | Node::Constant(_) => vec![],
+            Node::Add(l, r) | Node::Sub(l, r) | Node::Mul(l, r) => vec![l, r],
+            Node::Neg(e) => vec![e],
+        }
+    }
+}
+
+/// Builds a directed acyclic graph of arithmetic expressions over input codewords, challenges,
+/// and constants. Structurally identical sub-expressions are represented by the same node.
+#[derive(Debug, Clone)]
+pub struct ExpressionBuilder<FF: FiniteField> {
+    nodes: Vec<Node<FF>>,
+    node_ids: HashMap<Node<FF>, ExpressionId>,
+}
+
+impl<FF: FiniteField> Default for ExpressionBuilder<FF> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<FF: FiniteField> ExpressionBuilder<FF> {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![],
+            node_ids: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, node: Node<FF>) -> ExpressionId {
+        for operand in node.operands() {
+            assert!(
+                operand.0 < self.nodes.len(),
+                "expression {operand:?} does not belong to this builder"
+            );
+        }
+
+        if let Some(&id) = self.node_ids.get(&node) {
+            return id;
+        }
+        let id = ExpressionId(self.nodes.len());
+        self.nodes.push(node);
+        self.node_ids.insert(node, id);
+        id
+    }
+
+    /// The `index`th input codeword.
+    pub fn input(&mut self, index: usize) -> ExpressionId {
+        self.insert(Node::Input(index))
+    }
+
+    /// The `index`th challenge, a scalar that is constant across all rows.
+    pub fn challenge(&mut self, index: usize) -> ExpressionId {
+        self.insert(Node::Challenge(index))
+    }
+
+    pub fn constant(&mut self, constant: FF) -> ExpressionId {
+        self.insert(Node::Constant(constant))
+    }
+
+    pub fn add(&mut self, lhs: ExpressionId, rhs: ExpressionId) -> ExpressionId {
+        self.insert(Node::Add(lhs, rhs))
+    }
+
+    pub fn sub(&mut self, lhs: ExpressionId, rhs: ExpressionId) -> ExpressionId {
+        self.insert(Node::Sub(lhs, rhs))
+    }
+
+    pub fn mul(&mut self, lhs: ExpressionId, rhs: ExpressionId) -> ExpressionId {
+        self.insert(Node::Mul(lhs, rhs))
+    }
+
+    pub fn neg(&mut self, expression: ExpressionId) -> ExpressionId {
+        self.insert(Node::Neg(expression))
+    }
+
+    /// Compile the given expressions into a program that evaluates all of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `outputs` does not belong to this builder.
+    pub fn compile(&self, outputs: &[ExpressionId]) -> CompiledExpressions<FF> {
+        Compiler::new(self, outputs).compile()
+    }
+}
+
+/// Where an instruction's operand comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Input(usize),
+    Scalar(usize),
+    Register(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Neg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Compute {
+        operation: Operation,
+        destination: usize,
+        lhs: Operand,
+        rhs: Operand,
+    },
+    Emit {
+        output: usize,
+        source: Operand,
+    },
+}
+
+/// A node that does not depend on any input, evaluated once per call to
+/// [`evaluate`](CompiledExpressions::evaluate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScalarNode<FF> {
+    Challenge(usize),
+    Constant(FF),
+    Compute(Operation, usize, usize),
+}
+
+/// A program evaluating a fixed set of expressions over codewords. See the
+/// [module-level documentation](self) for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledExpressions<FF: FiniteField> {
+    scalar_program: Vec<ScalarNode<FF>>,
+    instructions: Vec<Instruction>,
+    num_registers: usize,
+    num_inputs: usize,
+    num_challenges: usize,
+    num_outputs: usize,
+}
+
+struct Compiler<'a, FF: FiniteField> {
+    nodes: &'a [Node<FF>],
+    outputs: &'a [ExpressionId],
+}
+
+impl<'a, FF: FiniteField> Compiler<'a, FF> {
+    fn new(builder: &'a ExpressionBuilder<FF>, outputs: &'a [ExpressionId]) -> Self {
+        for output in outputs {
+            assert!(
+                output.0 < builder.nodes.len(),
+                "expression {output:?} does not belong to this builder"
+            );
+        }
+
+        Self {
+            nodes: &builder.nodes,
+            outputs,
+        }
+    }
+
+    fn compile(self) -> CompiledExpressions<FF> {
+        let num_nodes = self.nodes.len();
+
+        // Only compile nodes that contribute to some output. Since operands are always inserted
+        // before the nodes using them, iterating in reverse visits consumers before producers.
+        let mut is_needed = vec![false; num_nodes];
+        for output in self.outputs {
+            is_needed[output.0] = true;
+        }
+        let mut last_use = vec![None; num_nodes];
+        for (index, node) in self.nodes.iter().enumerate().rev() {
+            if !is_needed[index] {
+                continue;
+            }
+            for operand in node.operands() {
+                is_needed[operand.0] = true;
+                last_use[operand.0] = last_use[operand.0].max(Some(index));
+            }
+        }
+
+        let mut scalar_program = vec![];
+        let mut instructions = vec![];
+        let mut operands = vec![None; num_nodes];
+        let mut free_registers = vec![];
+        let mut num_registers = 0;
+        let mut num_inputs = 0;
+        let mut num_challenges = 0;
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            if !is_needed[index] {
+                continue;
+            }
+            let operand_of = |id: ExpressionId| operands[id.0].unwrap();
+
+            let binary_operation = match *node {
+                Node::Input(i) => {
+                    num_inputs = num_inputs.max(i + 1);
+                    operands[index] = Some(Operand::Input(i));
+                    None
+                }
+                Node::Challenge(i) => {
+                    num_challenges = num_challenges.max(i + 1);
+                    operands[index] = Some(Operand::Scalar(scalar_program.len()));
+                    scalar_program.push(ScalarNode::Challenge(i));
+                    None
+                }
+                Node::Constant(c) => {
+                    operands[index] = Some(Operand::Scalar(scalar_program.len()));
+                    scalar_program.push(ScalarNode::Constant(c));
+                    None
+                }
+                Node::Add(l, r) => Some((Operation::Add, operand_of(l), operand_of(r))),
+                Node::Sub(l, r) => Some((Operation::Sub, operand_of(l), operand_of(r))),
+                Node::Mul(l, r) => Some((Operation::Mul, operand_of(l), operand_of(r))),
+                Node::Neg(e) => Some((Operation::Neg, operand_of(e), operand_of(e))),
+            };
+
+            if let Some((operation, lhs, rhs)) = binary_operation {
+                if let (Operand::Scalar(l), Operand::Scalar(r)) = (lhs, rhs) {
+                    operands[index] = Some(Operand::Scalar(scalar_program.len()));
+                    scalar_program.push(ScalarNode::Compute(operation, l, r));
+                } else {
+                    let destination = free_registers.pop().unwrap_or_else(|| {
+                        num_registers += 1;
+                        num_registers - 1
+                    });
+                    operands[index] = Some(Operand::Register(destination));
+                    instructions.push(Instruction::Compute {
+                        operation,
+                        destination,
+                        lhs,
+                        rhs,
+                    });
+
+                    // registers of operands that are not used anymore can be re-used
+                    for operand in node.operands() {
+                        if last_use[operand.0] == Some(index) {
+                            if let Some(Operand::Register(r)) = operands[operand.0] {
+                                if !free_registers.contains(&r) {
+                                    free_registers.push(r);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            let source = operands[index].unwrap();
+            for (output, _) in self
+                .outputs
+                .iter()
+                .enumerate()
+                .filter(|(_, o)| o.0 == index)
+            {
+                instructions.push(Instruction::Emit { output, source });
+            }
+            if let (None, Operand::Register(r)) = (last_use[index], source) {
+                free_registers.push(r);
+            }
+        }
+
+        CompiledExpressions {
+            scalar_program,
+            instructions,
+            num_registers,
+            num_inputs,
+            num_challenges,
+            num_outputs: self.outputs.len(),
+        }
+    }
+}
+
+impl Operation {
+    fn apply<FF: FiniteField>(self, lhs: FF, rhs: FF) -> FF {
+        match self {
+            Operation::Add => lhs + rhs,
+            Operation::Sub => lhs - rhs,
+            Operation::Mul => lhs * rhs,
+            Operation::Neg => -lhs,
+        }
+    }
+}
+
+/// An operand, resolved for one chunk of rows.
+#[derive(Clone, Copy)]
+enum Source<'a, FF> {
+    Slice(&'a [FF]),
+    Scalar(FF),
+}
+
+impl<FF: FiniteField> CompiledExpressions<FF> {
+    /// The number of registers, _i.e._, chunk-sized scratch buffers, the program uses.
+    pub fn num_registers(&self) -> usize {
+        self.num_registers
+    }
+
+    /// Evaluate all expressions on all rows of the `inputs`, given the `challenges`. Returns one
+    /// codeword per compiled expression, in the order they were passed to
+    /// [`compile`](ExpressionBuilder::compile).
+    ///
+    /// # Panics
+    ///
+    /// - Panics if fewer input codewords or challenges are given than the expressions use.
+    /// - Panics if the input codewords are not all of the same length.
+    pub fn evaluate(&self, inputs: &[&[FF]], challenges: &[FF]) -> Vec<Vec<FF>> {
+        assert!(
+            inputs.len() >= self.num_inputs,
+            "expected at least {} input codewords, but got {}",
+            self.num_inputs,
+            inputs.len()
+        );
+        assert!(
+            challenges.len() >= self.num_challenges,
+            "expected at least {} challenges, but got {}",
+            self.num_challenges,
+            challenges.len()
+        );
+        let num_rows = inputs.first().map_or(0, |input| input.len());
+        assert!(
+            inputs.iter().all(|input| input.len() == num_rows),
+            "all input codewords must be of the same length"
+        );
+
+        let scalars = self.evaluate_scalars(challenges);
+        let mut evaluations = vec![vec![FF::zero(); num_rows]; self.num_outputs];
+
+        // For every chunk of rows, the corresponding chunk of every output codeword.
+        let num_chunks = num_rows.div_ceil(CHUNK_LENGTH);
+        let mut output_chunks = iter::repeat_with(Vec::new)
+            .take(num_chunks)
+            .collect::<Vec<_>>();
+        for evaluation in &mut evaluations {
+            let chunks = evaluation.chunks_mut(CHUNK_LENGTH);
+            for (chunks_of_outputs, chunk) in output_chunks.iter_mut().zip(chunks) {
+                chunks_of_outputs.push(chunk);
+            }
+        }
+
+        output_chunks.into_par_iter().enumerate().for_each_init(
+            || vec![vec![]; self.num_registers],
+            |registers, (chunk_index, mut outputs)| {
+                let start = chunk_index * CHUNK_LENGTH;
+                let end = num_rows.min(start + CHUNK_LENGTH);
+                let input_chunks = inputs.iter().map(|input| &input[start..end]);
+                let input_chunks = input_chunks.collect::<Vec<_>>();
+                self.evaluate_chunk(
+                    &input_chunks,
+                    &scalars,
+                    registers,
+                    &mut outputs,
+                    end - start,
+                );
+            },
+        );
+        evaluations
+    }
+
+    fn evaluate_scalars(&self, challenges: &[FF]) -> Vec<FF> {
+        let mut scalars = Vec::with_capacity(self.scalar_program.len());
+        for &scalar_node in &self.scalar_program {
+            let scalar = match scalar_node {
+                ScalarNode::Challenge(i) => challenges[i],
+                ScalarNode::Constant(c) => c,
+                ScalarNode::Compute(operation, l, r) => operation.apply(scalars[l], scalars[r]),
+            };
+            scalars.push(scalar);
+        }
+        scalars
+    }
+
+    /// Evaluate the program on one chunk of rows, writing the results into the `outputs`, which
+    /// are of length `chunk_length`.
+    fn evaluate_chunk(
+        &self,
+        inputs: &[&[FF]],
+        scalars: &[FF],
+        registers: &mut [Vec<FF>],
+        outputs: &mut [&mut [FF]],
+        chunk_length: usize,
+    ) {
+        for &instruction in &self.instructions {
+            match instruction {
+                Instruction::Compute {
+                    operation,
+                    destination,
+                    lhs,
+                    rhs,
+                } => {
+                    let mut result = std::mem::take(&mut registers[destination]);
+                    result.clear();
+                    let lhs = Self::resolve(lhs, inputs, scalars, registers);
+                    let rhs = Self::resolve(rhs, inputs, scalars, registers);
+                    match (lhs, rhs) {
+                        (Source::Slice(l), Source::Slice(r)) => {
+                            result.extend(l.iter().zip(r).map(|(&l, &r)| operation.apply(l, r)))
+                        }
+                        (Source::Slice(l), Source::Scalar(r)) => {
+                            result.extend(l.iter().map(|&l| operation.apply(l, r)))
+                        }
+                        (Source::Scalar(l), Source::Slice(r)) => {
+                            result.extend(r.iter().map(|&r| operation.apply(l, r)))
+                        }
+                        (Source::Scalar(l), Source::Scalar(r)) => {
+                            result.resize(chunk_length, operation.apply(l, r))
+                        }
+                    }
+                    registers[destination] = result;
+                }
+                Instruction::Emit { output, source } => {
+                    match Self::resolve(source, inputs, scalars, registers) {
+                        Source::Slice(slice) => outputs[output].copy_from_slice(slice),
+                        Source::Scalar(scalar) => outputs[output].fill(scalar),
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve<'b>(
+        operand: Operand,
+        inputs: &[&'b [FF]],
+        scalars: &[FF],
+        registers: &'b [Vec<FF>],
+    ) -> Source<'b, FF> {
+        match operand {
+            Operand::Input(i) => Source::Slice(inputs[i]),
+            Operand::Scalar(i) => Source::Scalar(scalars[i]),
+            Operand::Register(i) => Source::Slice(&registers[i]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::x_field_element::XFieldElement;
+
+    use super::*;
+
+    impl<FF: FiniteField> ExpressionBuilder<FF> {
+        /// Reference implementation: evaluate one expression on one row.
+        fn evaluate_row(&self, id: ExpressionId, row: &[FF], challenges: &[FF]) -> FF {
+            let eval = |operand| self.evaluate_row(operand, row, challenges);
+            match self.nodes[id.0] {
+                Node::Input(i) => row[i],
+                Node::Challenge(i) => challenges[i],
+                Node::Constant(c) => c,
+                Node::Add(l, r) => eval(l) + eval(r),
+                Node::Sub(l, r) => eval(l) - eval(r),
+                Node::Mul(l, r) => eval(l) * eval(r),
+                Node::Neg(e) => -eval(e),
+            }
+        }
+    }
+
+    const NUM_INPUTS: usize = 3;
+    const NUM_CHALLENGES: usize = 2;
+
+    /// Build a random expression graph from a list of `(operation, lhs, rhs)` descriptions, where
+    /// the operands are indices into the list of previously built expressions.
+    fn random_builder(
+        descriptions: &[(u8, usize, usize)],
+        constants: &[XFieldElement],
+    ) -> (ExpressionBuilder<XFieldElement>, Vec<ExpressionId>) {
+        let mut builder = ExpressionBuilder::new();
+        let mut ids = (0..NUM_INPUTS).map(|i| builder.input(i)).collect_vec();
+        ids.extend((0..NUM_CHALLENGES).map(|i| builder.challenge(i)));
+        ids.extend(constants.iter().map(|&c| builder.constant(c)));
+
+        for &(operation, lhs, rhs) in descriptions {
+            let lhs = ids[lhs % ids.len()];
+            let rhs = ids[rhs % ids.len()];
+            let id = match operation % 4 {
+                0 => builder.add(lhs, rhs),
+                1 => builder.sub(lhs, rhs),
+                2 => builder.mul(lhs, rhs),
+                _ => builder.neg(lhs),
+            };
+            ids.push(id);
+        }
+        (builder, ids)
+    }
+
+    #[proptest(cases = 50)]
+    fn compiled_expressions_agree_with_reference_evaluation(
+        #[strategy(vec(arb(), 0..40))] descriptions: Vec<(u8, usize, usize)>,
+        #[strategy(vec(arb(), 0..3))] constants: Vec<XFieldElement>,
+        #[strategy(vec(arb(), 1..10))] output_indices: Vec<usize>,
+        #[strategy(0_usize..2 * CHUNK_LENGTH + 7)] _num_rows: usize,
+        #[strategy(vec(vec(arb(), #_num_rows), NUM_INPUTS))] inputs: Vec<Vec<XFieldElement>>,
+        #[strategy(vec(arb(), NUM_CHALLENGES))] challenges: Vec<XFieldElement>,
+    ) {
+        let (builder, ids) = random_builder(&descriptions, &constants);
+        let outputs = output_indices
+            .iter()
+            .map(|&i| ids[i % ids.len()])
+            .collect_vec();
+        let program = builder.compile(&outputs);
+
+        let input_slices = inputs.iter().map(|input| input.as_slice()).collect_vec();
+        let evaluations = program.evaluate(&input_slices, &challenges);
+        prop_assert_eq!(outputs.len(), evaluations.len());
+
+        for (&output, evaluation) in outputs.iter().zip(evaluations) {
+            prop_assert_eq!(_num_rows, evaluation.len());
+            for (row_index, value) in evaluation.into_iter().enumerate() {
+                let row = inputs.iter().map(|input| input[row_index]).collect_vec();
+                let expected = builder.evaluate_row(output, &row, &challenges);
+                prop_assert_eq!(expected, value);
+            }
+        }
+    }
+
+    #[test]
+    fn common_sub_expressions_are_de_duplicated() {
+        let mut builder = ExpressionBuilder::<BFieldElement>::new();
+        let x = builder.input(0);
+        let y = builder.input(1);
+        let x_times_y = builder.mul(x, y);
+        assert_eq!(x_times_y, builder.mul(x, y));
+        assert_eq!(x, builder.input(0));
+    }
+
+    #[test]
+    fn registers_are_reused() {
+        let mut builder = ExpressionBuilder::<BFieldElement>::new();
+        let x = builder.input(0);
+        let mut accumulator = x;
+        for _ in 0..100 {
+            accumulator = builder.mul(accumulator, x);
+        }
+        let program = builder.compile(&[accumulator]);
+        assert!(program.num_registers() <= 2);
+
+        let evaluations = program.evaluate(&[&[BFieldElement::new(2)]], &[]);
+        assert_eq!(BFieldElement::new(2).mod_pow(101), evaluations[0][0]);
+    }
+
+    #[test]
+    fn expressions_only_depending_on_challenges_need_no_registers() {
+        let mut builder = ExpressionBuilder::<BFieldElement>::new();
+        let alpha = builder.challenge(0);
+        let two = builder.constant(BFieldElement::new(2));
+        let alpha_squared = builder.mul(alpha, alpha);
+        let output = builder.add(alpha_squared, two);
+        let program = builder.compile(&[output]);
+        assert_eq!(0, program.num_registers());
+
+        let xs = vec![BFieldElement::new(0); 5];
+        let evaluations = program.evaluate(&[&xs], &[BFieldElement::new(3)]);
+        assert_eq!(vec![BFieldElement::new(11); 5], evaluations[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected at least 2 input codewords")]
+    fn evaluating_with_too_few_inputs_panics() {
+        let mut builder = ExpressionBuilder::<BFieldElement>::new();
+        let y = builder.input(1);
+        let program = builder.compile(&[y]);
+        program.evaluate(&[&[BFieldElement::new(1)]], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be of the same length")]
+    fn evaluating_inputs_of_different_lengths_panics() {
+        let mut builder = ExpressionBuilder::<BFieldElement>::new();
+        let x = builder.input(0);
+        let y = builder.input(1);
+        let sum = builder.add(x, y);
+        let program = builder.compile(&[sum]);
+        let xs = vec![BFieldElement::new(1); 3];
+        let ys = vec![BFieldElement::new(1); 4];
+        program.evaluate(&[&xs, &ys], &[]);
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+use arbitrary::Arbitrary;
+use num_traits::Zero;
+
+use crate::error::TryFromPolynomialError;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+
+/// A polynomial with at most `N` coefficients, _i.e._, of degree less than `N`, stored in an
+/// array.
+///
+/// Meant for hot paths involving polynomials of small degree, like the lines of colinearity checks
+/// or linear combinations when folding, where the heap allocation of a [`Polynomial`] dominates
+/// the cost of the arithmetic. Since the number of coefficients is known at compile time, the
+/// loops in all operations can be unrolled.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::fixed_polynomial::FixedPolynomial;
+/// let line = FixedPolynomial::new(bfe_array![1, 2]); // 2x + 1
+/// let parabola: FixedPolynomial<_, 3> = line.multiply(&line);
+/// assert_eq!(bfe!(25), parabola.evaluate(bfe!(2)));
+/// assert_eq!(Polynomial::new(bfe_vec![1, 4, 4]), Polynomial::from(parabola));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Arbitrary)]
+pub struct FixedPolynomial<FF: FiniteField, const N: usize> {
+    /// The coefficients in order of increasing degree.
+    pub coefficients: [FF; N],
+}
+
+impl<FF: FiniteField, const N: usize> FixedPolynomial<FF, N> {
+    pub const fn new(coefficients: [FF; N]) -> Self {
+        Self { coefficients }
+    }
+
+    /// The degree of the polynomial, where the zero polynomial has degree -1.
+    pub fn degree(&self) -> isize {
+        self.coefficients
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(-1, |index| index as isize)
+    }
+
+    pub fn evaluate(&self, x: FF) -> FF {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(FF::zero(), |acc, &c| acc * x + c)
+    }
+
+    pub fn scalar_mul(&self, scalar: FF) -> Self {
+        Self::new(self.coefficients.map(|c| c * scalar))
+    }
+
+    /// The product of `self` and `other`.
+    ///
+    /// The number of coefficients of the product, `R`, must be large enough to hold the product of
+    /// any two polynomials with `N` and `M` coefficients, respectively. This is checked at compile
+    /// time.
+    pub fn multiply<const M: usize, const R: usize>(
+        &self,
+        other: &FixedPolynomial<FF, M>,
+    ) -> FixedPolynomial<FF, R> {
+        const {
+            assert!(
+                N + M <= R + 1,
+                "the product does not fit into the requested number of coefficients"
+            )
+        };
+
+        let mut product = [FF::zero(); R];
+        for (i, &lhs) in self.coefficients.iter().enumerate() {
+            for (j, &rhs) in other.coefficients.iter().enumerate() {
+                product[i + j] += lhs * rhs;
+            }
+        }
+        FixedPolynomial::new(product)
+    }
+}
+
+impl<FF: FiniteField, const N: usize> Zero for FixedPolynomial<FF, N> {
+    fn zero() -> Self {
+        Self::new([FF::zero(); N])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<FF: FiniteField, const N: usize> Add for FixedPolynomial<FF, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut coefficients = self.coefficients;
+        for (c, &r) in coefficients.iter_mut().zip(&rhs.coefficients) {
+            *c += r;
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<FF: FiniteField, const N: usize> Sub for FixedPolynomial<FF, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut coefficients = self.coefficients;
+        for (c, &r) in coefficients.iter_mut().zip(&rhs.coefficients) {
+            *c -= r;
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<FF: FiniteField, const N: usize> Neg for FixedPolynomial<FF, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coefficients.map(|c| -c))
+    }
+}
+
+impl<FF: FiniteField, const N: usize> Mul<FF> for FixedPolynomial<FF, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: FF) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<FF: FiniteField, const N: usize> From<FixedPolynomial<FF, N>> for Polynomial<FF> {
+    fn from(polynomial: FixedPolynomial<FF, N>) -> Self {
+        let mut polynomial = Polynomial::new(polynomial.coefficients.to_vec());
+        polynomial.normalize();
+        polynomial
+    }
+}
+
+impl<FF: FiniteField, const N: usize> TryFrom<&Polynomial<FF>> for FixedPolynomial<FF, N> {
+    type Error = TryFromPolynomialError;
+
+    fn try_from(polynomial: &Polynomial<FF>) -> Result<Self, Self::Error> {
+        let degree = polynomial.degree();
+        if degree >= N as isize {
+            return Err(TryFromPolynomialError::DegreeTooLarge(degree));
+        }
+
+        let mut coefficients = [FF::zero(); N];
+        let num_coefficients = (degree + 1) as usize;
+        coefficients[..num_coefficients]
+            .copy_from_slice(&polynomial.coefficients[..num_coefficients]);
+        Ok(Self::new(coefficients))
+    }
+}
+
+impl<FF: FiniteField, const N: usize> TryFrom<Polynomial<FF>> for FixedPolynomial<FF, N> {
+    type Error = TryFromPolynomialError;
+
+    fn try_from(polynomial: Polynomial<FF>) -> Result<Self, Self::Error> {
+        Self::try_from(&polynomial)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::bfe_vec;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::x_field_element::XFieldElement;
+
+    use super::*;
+
+    type Poly<const N: usize> = FixedPolynomial<XFieldElement, N>;
+
+    #[proptest]
+    fn converting_to_polynomial_and_back_is_identity(#[strategy(arb())] poly: Poly<5>) {
+        let polynomial = Polynomial::from(poly);
+        prop_assert_eq!(poly.degree(), polynomial.degree());
+        prop_assert_eq!(poly, Poly::<5>::try_from(polynomial)?);
+    }
+
+    #[proptest]
+    fn evaluation_agrees_with_polynomial(
+        #[strategy(arb())] poly: Poly<8>,
+        #[strategy(arb())] x: XFieldElement,
+    ) {
+        prop_assert_eq!(Polynomial::from(poly).evaluate(x), poly.evaluate(x));
+    }
+
+    #[proptest]
+    fn arithmetic_agrees_with_polynomial(
+        #[strategy(arb())] lhs: Poly<4>,
+        #[strategy(arb())] rhs: Poly<4>,
+        #[strategy(arb())] scalar: XFieldElement,
+    ) {
+        let lhs_polynomial = Polynomial::from(lhs);
+        let rhs_polynomial = Polynomial::from(rhs);
+
+        let sum = lhs_polynomial.clone() + rhs_polynomial.clone();
+        prop_assert_eq!(sum, Polynomial::from(lhs + rhs));
+
+        let difference = lhs_polynomial.clone() - rhs_polynomial.clone();
+        prop_assert_eq!(difference, Polynomial::from(lhs - rhs));
+
+        prop_assert_eq!(-lhs_polynomial.clone(), Polynomial::from(-lhs));
+
+        let scaled = lhs_polynomial.scalar_mul(scalar);
+        prop_assert_eq!(scaled, Polynomial::from(lhs * scalar));
+
+        let product = lhs_polynomial * rhs_polynomial;
+        let fixed_product: Poly<7> = lhs.multiply(&rhs);
+        prop_assert_eq!(product, Polynomial::from(fixed_product));
+    }
+
+    #[proptest]
+    fn product_may_have_more_coefficients_than_necessary(
+        #[strategy(arb())] lhs: Poly<2>,
+        #[strategy(arb())] rhs: Poly<3>,
+    ) {
+        let tight_product: Poly<4> = lhs.multiply(&rhs);
+        let loose_product: Poly<10> = lhs.multiply(&rhs);
+        prop_assert_eq!(
+            Polynomial::from(tight_product),
+            Polynomial::from(loose_product)
+        );
+    }
+
+    #[test]
+    fn zero_polynomial_has_degree_minus_one() {
+        let zero = Poly::<3>::zero();
+        assert!(zero.is_zero());
+        assert_eq!(-1, zero.degree());
+        assert_eq!(Polynomial::zero(), Polynomial::from(zero));
+        assert_eq!(-1, FixedPolynomial::<BFieldElement, 0>::zero().degree());
+    }
+
+    #[test]
+    fn polynomial_of_too_large_degree_cannot_be_converted() {
+        let polynomial = Polynomial::new(bfe_vec![1, 2, 3, 0]);
+        let err = FixedPolynomial::<_, 2>::try_from(&polynomial).unwrap_err();
+        assert_eq!(TryFromPolynomialError::DegreeTooLarge(2), err);
+
+        let fixed_polynomial = FixedPolynomial::<_, 3>::try_from(&polynomial).unwrap();
+        assert_eq!(2, fixed_polynomial.degree());
+    }
+}
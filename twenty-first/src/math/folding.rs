@@ -0,0 +1,389 @@
+//! Nova-style folding of [rank-1 constraint systems](R1cs).
+//!
+//! Folding combines two instances of a constraint system into a single instance with a random
+//! linear combination. The folded instance is satisfied if both original instances are, and, with
+//! high probability over the choice of the challenge, not satisfied if either of them is not.
+//! Plain R1CS instances are not closed under linear combinations, which is why folding works on
+//! [relaxed instances](RelaxedR1csInstance): the constraint `Az ∘ Bz = Cz` is relaxed to
+//! `Az ∘ Bz = u·Cz + E`, where `u` absorbs the scalars of the linear combinations, and the error
+//! vector `E` absorbs the cross terms.
+//!
+//! This module provides the algebra of folding, _i.e._, the [cross term](R1cs::cross_term) and the
+//! [folded instance](RelaxedR1csInstance::fold). Committing to witnesses, error vectors and cross
+//! terms, as well as deriving the challenge, is up to the protocol built on top.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::folding::R1cs;
+//! # use twenty_first::math::matrix::FieldMatrix;
+//! // The variables are (1, x, y). The single constraint is x·x = y.
+//! let a = FieldMatrix::from_rows(&[bfe_vec![0, 1, 0]]);
+//! let b = FieldMatrix::from_rows(&[bfe_vec![0, 1, 0]]);
+//! let c = FieldMatrix::from_rows(&[bfe_vec![0, 0, 1]]);
+//! let r1cs = R1cs::new(a, b, c);
+//!
+//! let instance_0 = r1cs.relax(bfe_vec![1, 3, 9]);
+//! let instance_1 = r1cs.relax(bfe_vec![1, 5, 25]);
+//! let folded_instance = r1cs.fold(&instance_0, &instance_1, bfe!(42));
+//! assert!(r1cs.is_satisfied(&folded_instance));
+//! ```
+
+use rayon::prelude::*;
+
+use crate::math::matrix::FieldMatrix;
+use crate::math::traits::FiniteField;
+
+/// A rank-1 constraint system, consisting of matrices `A`, `B`, and `C` of identical dimensions.
+///
+/// An assignment `z` satisfies the system if `Az ∘ Bz = Cz`, where `∘` is the element-wise
+/// product. Every row of the matrices corresponds to one constraint, every column to one variable.
+/// By convention, the first variable is the constant 1. In a [relaxed
+/// instance](RelaxedR1csInstance), it becomes the scalar `u`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct R1cs<FF: FiniteField> {
+    a: FieldMatrix<FF>,
+    b: FieldMatrix<FF>,
+    c: FieldMatrix<FF>,
+}
+
+/// An assignment to the variables of a [relaxed R1CS](R1cs), together with an error vector.
+///
+/// The instance is satisfied if `Az ∘ Bz = u·Cz + E`, where `z` is the `assignment`, `u` is its
+/// first element (see [`scalar`](Self::scalar)), and `E` is the `error`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelaxedR1csInstance<FF: FiniteField> {
+    pub assignment: Vec<FF>,
+    pub error: Vec<FF>,
+}
+
+impl<FF: FiniteField> R1cs<FF> {
+    /// # Panics
+    ///
+    /// Panics if the matrices are not of identical dimensions, or if they have no columns.
+    pub fn new(a: FieldMatrix<FF>, b: FieldMatrix<FF>, c: FieldMatrix<FF>) -> Self {
+        let dimensions = |m: &FieldMatrix<FF>| (m.num_rows(), m.num_columns());
+        assert_eq!(
+            dimensions(&a),
+            dimensions(&b),
+            "The matrices must be of identical dimensions."
+        );
+        assert_eq!(
+            dimensions(&a),
+            dimensions(&c),
+            "The matrices must be of identical dimensions."
+        );
+        assert!(
+            a.num_columns() > 0,
+            "The constraint system must have at least one variable."
+        );
+
+        Self { a, b, c }
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.a.num_rows()
+    }
+
+    /// The number of variables, including the constant 1.
+    pub fn num_variables(&self) -> usize {
+        self.a.num_columns()
+    }
+
+    /// The relaxed instance of the given `assignment` with `u = 1` and an error of zero. It is
+    /// satisfied if and only if the `assignment` satisfies the (non-relaxed) constraint system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the `assignment` is not the [number of
+    /// variables](Self::num_variables), or if its first element is not 1.
+    pub fn relax(&self, assignment: Vec<FF>) -> RelaxedR1csInstance<FF> {
+        self.assert_assignment_length(&assignment);
+        assert!(
+            assignment[0].is_one(),
+            "The first variable of a non-relaxed assignment must be 1."
+        );
+
+        let error = vec![FF::zero(); self.num_constraints()];
+        RelaxedR1csInstance { assignment, error }
+    }
+
+    /// Whether the relaxed `instance` satisfies the constraint system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `instance` does not fit the dimensions of the constraint system.
+    pub fn is_satisfied(&self, instance: &RelaxedR1csInstance<FF>) -> bool {
+        self.assert_instance_dimensions(instance);
+        let z = &instance.assignment;
+        let u = instance.scalar();
+        let (a_z, b_z, c_z) = self.products(z);
+
+        (a_z, b_z, c_z, &instance.error)
+            .into_par_iter()
+            .all(|(a, b, c, &e)| a * b == u * c + e)
+    }
+
+    /// The cross term `T = Az₀ ∘ Bz₁ + Az₁ ∘ Bz₀ - u₀·Cz₁ - u₁·Cz₀` that arises when folding the
+    /// two relaxed instances.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either instance does not fit the dimensions of the constraint system.
+    pub fn cross_term(
+        &self,
+        instance_0: &RelaxedR1csInstance<FF>,
+        instance_1: &RelaxedR1csInstance<FF>,
+    ) -> Vec<FF> {
+        self.assert_instance_dimensions(instance_0);
+        self.assert_instance_dimensions(instance_1);
+        let u_0 = instance_0.scalar();
+        let u_1 = instance_1.scalar();
+        let (a_z_0, b_z_0, c_z_0) = self.products(&instance_0.assignment);
+        let (a_z_1, b_z_1, c_z_1) = self.products(&instance_1.assignment);
+
+        (a_z_0, b_z_0, c_z_0, a_z_1, b_z_1, c_z_1)
+            .into_par_iter()
+            .map(|(a_0, b_0, c_0, a_1, b_1, c_1)| a_0 * b_1 + a_1 * b_0 - u_0 * c_1 - u_1 * c_0)
+            .collect()
+    }
+
+    /// Fold the two relaxed instances using the given `challenge` `r`.
+    ///
+    /// This is a shorthand for computing the [cross term](Self::cross_term) and
+    /// [folding](RelaxedR1csInstance::fold) with it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either instance does not fit the dimensions of the constraint system.
+    pub fn fold(
+        &self,
+        instance_0: &RelaxedR1csInstance<FF>,
+        instance_1: &RelaxedR1csInstance<FF>,
+        challenge: FF,
+    ) -> RelaxedR1csInstance<FF> {
+        let cross_term = self.cross_term(instance_0, instance_1);
+        instance_0.fold(instance_1, &cross_term, challenge)
+    }
+
+    /// The products `Az`, `Bz`, and `Cz`.
+    fn products(&self, z: &[FF]) -> (Vec<FF>, Vec<FF>, Vec<FF>) {
+        let product = |matrix: &FieldMatrix<FF>| {
+            matrix
+                .as_slice()
+                .par_chunks(matrix.num_columns())
+                .map(|row| {
+                    row.iter()
+                        .zip(z)
+                        .fold(FF::zero(), |acc, (&m, &v)| acc + m * v)
+                })
+                .collect()
+        };
+
+        (product(&self.a), product(&self.b), product(&self.c))
+    }
+
+    fn assert_assignment_length(&self, assignment: &[FF]) {
+        assert_eq!(
+            self.num_variables(),
+            assignment.len(),
+            "The assignment must have one element per variable."
+        );
+    }
+
+    fn assert_instance_dimensions(&self, instance: &RelaxedR1csInstance<FF>) {
+        self.assert_assignment_length(&instance.assignment);
+        assert_eq!(
+            self.num_constraints(),
+            instance.error.len(),
+            "The error must have one element per constraint."
+        );
+    }
+}
+
+impl<FF: FiniteField> RelaxedR1csInstance<FF> {
+    /// The scalar `u` of the relaxed constraint `Az ∘ Bz = u·Cz + E`, which is stored as the first
+    /// element of the assignment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the assignment is empty.
+    pub fn scalar(&self) -> FF {
+        self.assignment[0]
+    }
+
+    /// Fold `self` and the `other` instance using the `cross_term` and the `challenge` `r`.
+    ///
+    /// The folded assignment is `z₀ + r·z₁`, and the folded error is `E₀ + r·T + r²·E₁`. Since this
+    /// only depends on the [cross term](R1cs::cross_term) but not the constraint system itself, it
+    /// can also be computed by a party that only knows commitments to the cross term.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instances and the cross term are not of matching dimensions.
+    pub fn fold(&self, other: &Self, cross_term: &[FF], challenge: FF) -> Self {
+        assert_eq!(
+            self.assignment.len(),
+            other.assignment.len(),
+            "The assignments must be of equal length."
+        );
+        assert_eq!(
+            self.error.len(),
+            other.error.len(),
+            "The errors must be of equal length."
+        );
+        assert_eq!(
+            self.error.len(),
+            cross_term.len(),
+            "The cross term must be as long as the errors."
+        );
+
+        let assignment = self
+            .assignment
+            .par_iter()
+            .zip(&other.assignment)
+            .map(|(&z_0, &z_1)| z_0 + challenge * z_1)
+            .collect();
+        let challenge_squared = challenge * challenge;
+        let error = (&self.error, cross_term, &other.error)
+            .into_par_iter()
+            .map(|(&e_0, &t, &e_1)| e_0 + challenge * t + challenge_squared * e_1)
+            .collect();
+
+        Self { assignment, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Zero;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::bfe_vec;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::x_field_element::XFieldElement;
+
+    use super::*;
+
+    /// The variables are `(1, x, y, w)`, the constraints are `x·x = y` and `y·x = w + 5`.
+    fn cube_plus_five<FF: FiniteField>() -> R1cs<FF> {
+        let rows = |rows: [[u64; 4]; 2]| {
+            let rows = rows.map(|row| row.map(FF::from).to_vec());
+            FieldMatrix::from_rows(&rows)
+        };
+        let a = rows([[0, 1, 0, 0], [0, 0, 1, 0]]);
+        let b = rows([[0, 1, 0, 0], [0, 1, 0, 0]]);
+        let c = rows([[0, 0, 1, 0], [5, 0, 0, 1]]);
+        R1cs::new(a, b, c)
+    }
+
+    fn satisfying_assignment<FF: FiniteField>(x: FF) -> Vec<FF> {
+        let y = x * x;
+        let w = y * x - FF::from(5);
+        vec![FF::one(), x, y, w]
+    }
+
+    #[proptest]
+    fn relaxed_satisfying_assignment_is_satisfied(#[strategy(arb())] x: BFieldElement) {
+        let r1cs = cube_plus_five();
+        let instance = r1cs.relax(satisfying_assignment(x));
+        prop_assert!(r1cs.is_satisfied(&instance));
+    }
+
+    #[proptest]
+    fn relaxed_unsatisfying_assignment_is_not_satisfied(
+        #[strategy(arb())] x: BFieldElement,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+    ) {
+        let r1cs = cube_plus_five();
+        let mut assignment = satisfying_assignment(x);
+        assignment[3] += offset;
+        let instance = r1cs.relax(assignment);
+        prop_assert!(!r1cs.is_satisfied(&instance));
+    }
+
+    #[proptest]
+    fn folding_satisfied_instances_gives_satisfied_instance(
+        #[strategy(arb())] x_0: XFieldElement,
+        #[strategy(arb())] x_1: XFieldElement,
+        #[strategy(arb())] challenge: XFieldElement,
+    ) {
+        let r1cs = cube_plus_five();
+        let instance_0 = r1cs.relax(satisfying_assignment(x_0));
+        let instance_1 = r1cs.relax(satisfying_assignment(x_1));
+        let folded_instance = r1cs.fold(&instance_0, &instance_1, challenge);
+        prop_assert!(r1cs.is_satisfied(&folded_instance));
+    }
+
+    #[proptest(cases = 20)]
+    fn repeatedly_folding_satisfied_instances_gives_satisfied_instance(
+        #[strategy(arb())] xs: [BFieldElement; 5],
+        #[strategy(arb())] challenges: [BFieldElement; 4],
+    ) {
+        let r1cs = cube_plus_five();
+        let mut accumulator = r1cs.relax(satisfying_assignment(xs[0]));
+        for (&x, &challenge) in xs[1..].iter().zip(&challenges) {
+            let instance = r1cs.relax(satisfying_assignment(x));
+            accumulator = r1cs.fold(&accumulator, &instance, challenge);
+            prop_assert!(r1cs.is_satisfied(&accumulator));
+        }
+    }
+
+    #[proptest]
+    fn folding_with_unsatisfied_instance_gives_unsatisfied_instance(
+        #[strategy(arb())] x_0: XFieldElement,
+        #[strategy(arb())] x_1: XFieldElement,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: XFieldElement,
+        #[strategy(arb())]
+        #[filter(!#challenge.is_zero())]
+        challenge: XFieldElement,
+    ) {
+        let r1cs = cube_plus_five();
+        let instance_0 = r1cs.relax(satisfying_assignment(x_0));
+        let mut assignment_1 = satisfying_assignment(x_1);
+        assignment_1[3] += offset;
+        let instance_1 = r1cs.relax(assignment_1);
+
+        // A non-zero challenge always detects an error in a linearly constrained variable.
+        let folded_instance = r1cs.fold(&instance_0, &instance_1, challenge);
+        prop_assert!(!r1cs.is_satisfied(&folded_instance));
+    }
+
+    #[test]
+    fn folding_with_challenge_zero_gives_first_instance() {
+        let r1cs = cube_plus_five();
+        let instance_0 = r1cs.relax(satisfying_assignment(BFieldElement::new(3)));
+        let instance_1 = r1cs.relax(bfe_vec![1, 2, 3, 4]);
+        let folded_instance = r1cs.fold(&instance_0, &instance_1, BFieldElement::zero());
+        assert_eq!(instance_0, folded_instance);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 1")]
+    fn relaxing_assignment_without_leading_one_panics() {
+        let r1cs = cube_plus_five();
+        let _ = r1cs.relax(bfe_vec![2, 3, 9, 22]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one element per variable")]
+    fn relaxing_assignment_of_wrong_length_panics() {
+        let r1cs = cube_plus_five::<BFieldElement>();
+        let _ = r1cs.relax(bfe_vec![1, 3, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "identical dimensions")]
+    fn constraint_system_with_mismatched_matrices_cannot_be_constructed() {
+        let a = FieldMatrix::<BFieldElement>::zero(2, 3);
+        let c = FieldMatrix::zero(3, 3);
+        let _ = R1cs::new(a.clone(), a, c);
+    }
+}
@@ -0,0 +1,221 @@
+//! Record which strategy the dispatching methods of [`Polynomial`] choose.
+//!
+//! Methods like [`Polynomial::multiply`] pick one of several internal algorithms depending on the
+//! size of their input. Which one runs is invisible from the outside, which makes it hard to
+//! notice when a refactor or a changed threshold sends some input size down the slow path. With
+//! feature `instrumentation` enabled, the dispatching methods report their choice, which can be
+//! inspected using [`record_strategies`].
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::instrumentation::*;
+//! let polynomial = Polynomial::new(bfe_vec![1; 1000]);
+//! let (_, strategies) = record_strategies(|| polynomial.multiply(&polynomial));
+//! let expected = StrategySelection::new(Method::Multiply, Strategy::Fast);
+//! assert_eq!(Some(&expected), strategies.first());
+//! ```
+//!
+//! [`Polynomial`]: crate::math::polynomial::Polynomial
+//! [`Polynomial::multiply`]: crate::math::polynomial::Polynomial::multiply
+
+use std::cell::RefCell;
+
+/// A dispatching method of [`Polynomial`](crate::math::polynomial::Polynomial).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Method {
+    Multiply,
+    Square,
+    Zerofier,
+    Interpolate,
+    CleanDivide,
+}
+
+/// The internal algorithm a [`Method`] dispatched to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Strategy {
+    /// Schoolbook arithmetic, quadratic in the input size.
+    Naive,
+
+    /// An improved quadratic algorithm, like the iterative [zerofier] computation.
+    ///
+    /// [zerofier]: crate::math::polynomial::Polynomial::smart_zerofier
+    Smart,
+
+    /// Lagrange interpolation.
+    Lagrange,
+
+    /// An NTT-based algorithm, quasi-linear in the input size.
+    Fast,
+
+    /// Independent blocks of the input are processed in parallel, one per thread, like in the
+    /// [parallel zerofier].
+    ///
+    /// [parallel zerofier]: crate::math::polynomial::Polynomial::par_zerofier
+    Parallel,
+}
+
+/// The [`Strategy`] some [`Method`] chose.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct StrategySelection {
+    pub method: Method,
+    pub strategy: Strategy,
+}
+
+impl StrategySelection {
+    pub const fn new(method: Method, strategy: Strategy) -> Self {
+        Self { method, strategy }
+    }
+}
+
+thread_local! {
+    static RECORDED_SELECTIONS: RefCell<Option<Vec<StrategySelection>>> =
+        const { RefCell::new(None) };
+}
+
+/// Run `f` and return its result alongside all [strategy selections](StrategySelection) made
+/// during its execution, in order.
+///
+/// Only selections made on the current thread are recorded. The dispatching method called directly
+/// by `f` always runs on the current thread, making the first recorded selection deterministic.
+/// Some methods, like [`Polynomial::zerofier`][zerofier], recurse using rayon; whether or not the
+/// nested selections show up depends on rayon's scheduling.
+///
+/// Calls to this function can be nested. The selections are then only recorded by the innermost
+/// call.
+///
+/// [zerofier]: crate::math::polynomial::Polynomial::zerofier
+pub fn record_strategies<R>(f: impl FnOnce() -> R) -> (R, Vec<StrategySelection>) {
+    let outer_selections = RECORDED_SELECTIONS.replace(Some(vec![]));
+    let result = f();
+    let selections = RECORDED_SELECTIONS.replace(outer_selections);
+    (result, selections.unwrap_or_default())
+}
+
+pub(crate) fn record(method: Method, strategy: Strategy) {
+    RECORDED_SELECTIONS.with_borrow_mut(|selections| {
+        if let Some(selections) = selections {
+            selections.push(StrategySelection::new(method, strategy));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::PoisonError;
+
+    use num_traits::One;
+
+    use crate::config;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::polynomial::Polynomial;
+
+    use super::*;
+
+    type Poly = Polynomial<BFieldElement>;
+
+    /// Some tiers depend on the [configured](crate::config) number of threads.
+    fn first_strategy<R>(f: impl FnOnce() -> R) -> Strategy {
+        let _guard = config::TEST_LOCK
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (_, selections) = record_strategies(f);
+        selections[0].strategy
+    }
+
+    fn polynomial_of_degree(degree: usize) -> Poly {
+        Poly::new(vec![BFieldElement::one(); degree + 1])
+    }
+
+    #[test]
+    fn nothing_is_recorded_without_dispatching_methods() {
+        let (result, selections) = record_strategies(|| 42);
+        assert_eq!(42, result);
+        assert!(selections.is_empty());
+    }
+
+    #[test]
+    fn multiplication_tiers() {
+        let small = polynomial_of_degree(10);
+        let large = polynomial_of_degree(1000);
+        assert_eq!(Strategy::Naive, first_strategy(|| small.multiply(&small)));
+        assert_eq!(Strategy::Fast, first_strategy(|| large.multiply(&large)));
+    }
+
+    #[test]
+    fn squaring_tiers() {
+        let small = polynomial_of_degree(10);
+        let large = polynomial_of_degree(1000);
+        assert_eq!(Strategy::Naive, first_strategy(|| small.square()));
+        assert_eq!(Strategy::Fast, first_strategy(|| large.square()));
+    }
+
+    fn first_strategy_with_threads<R: Send>(
+        num_threads: usize,
+        f: impl FnOnce() -> R + Send,
+    ) -> Strategy {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| first_strategy(f))
+    }
+
+    #[test]
+    fn zerofier_tiers() {
+        let small_domain = (0..10).map(BFieldElement::new).collect::<Vec<_>>();
+        let large_domain = (0..1000).map(BFieldElement::new).collect::<Vec<_>>();
+        let small_zerofier = || Poly::zerofier(&small_domain);
+        let large_zerofier = || Poly::zerofier(&large_domain);
+        assert_eq!(
+            Strategy::Smart,
+            first_strategy_with_threads(1, small_zerofier)
+        );
+        assert_eq!(
+            Strategy::Smart,
+            first_strategy_with_threads(8, small_zerofier)
+        );
+        assert_eq!(
+            Strategy::Fast,
+            first_strategy_with_threads(1, large_zerofier)
+        );
+        assert_eq!(
+            Strategy::Parallel,
+            first_strategy_with_threads(8, large_zerofier)
+        );
+    }
+
+    #[test]
+    fn interpolation_tiers() {
+        let small_domain = (0..10).map(BFieldElement::new).collect::<Vec<_>>();
+        let large_domain = (0..1000).map(BFieldElement::new).collect::<Vec<_>>();
+        let interpolate = |domain: &[_]| Poly::interpolate(domain, domain);
+        assert_eq!(
+            Strategy::Lagrange,
+            first_strategy(|| interpolate(&small_domain))
+        );
+        assert_eq!(
+            Strategy::Fast,
+            first_strategy(|| interpolate(&large_domain))
+        );
+    }
+
+    #[test]
+    fn clean_division_dispatches_to_fast_division_in_tests() {
+        let dividend = polynomial_of_degree(9);
+        let divisor = polynomial_of_degree(4);
+        let (_, selections) = record_strategies(|| dividend.clean_divide(divisor));
+        let expected = StrategySelection::new(Method::CleanDivide, Strategy::Fast);
+        assert_eq!(expected, selections[0]);
+    }
+
+    #[test]
+    fn nested_recording_only_records_innermost() {
+        let polynomial = polynomial_of_degree(10);
+        let ((_, inner), outer) = record_strategies(|| {
+            let _ = polynomial.multiply(&polynomial);
+            record_strategies(|| polynomial.multiply(&polynomial))
+        });
+        assert_eq!(1, outer.len());
+        assert_eq!(1, inner.len());
+    }
+}
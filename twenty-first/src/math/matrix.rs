@@ -0,0 +1,507 @@
+//! Transposition of large matrices, for example, for converting between the column-major layout
+//! used for interpolation and the row-major layout used for constraint evaluation.
+//!
+//! Flat matrices are stored in row-major order, _i.e._, the element in row `r` and column `c` of a
+//! matrix with `num_columns` columns is at index `r·num_columns + c`. To avoid thrashing the caches,
+//! all transpositions work on square blocks of [`BLOCK_SIZE`] × [`BLOCK_SIZE`] elements at a time.
+//!
+//! A [`FieldMatrix`] wraps such a flat matrix together with its dimensions.
+
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use rayon::prelude::*;
+
+use crate::math::traits::FiniteField;
+
+/// The side length of the square blocks the transpositions operate on.
+pub const BLOCK_SIZE: usize = 64;
+
+/// The transpose of the flat, row-major `matrix` of dimensions `num_rows` × `num_columns`. The
+/// result is the flat, row-major matrix of dimensions `num_columns` × `num_rows`.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::math::matrix::transpose;
+/// let matrix = [1, 2, 3, 4, 5, 6];
+/// assert_eq!(vec![1, 4, 2, 5, 3, 6], transpose(&matrix, 2, 3));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of the `matrix` is not `num_rows · num_columns`.
+pub fn transpose<T: Copy + Send + Sync>(
+    matrix: &[T],
+    num_rows: usize,
+    num_columns: usize,
+) -> Vec<T> {
+    assert_dimensions(matrix.len(), num_rows, num_columns);
+    let Some(&first_element) = matrix.first() else {
+        return vec![];
+    };
+    let mut transposed = vec![first_element; matrix.len()];
+
+    // Every output block row is written by exactly one thread, one block at a time. Within a
+    // block, the output is written contiguously, while the strided reads hit only the block's
+    // input rows.
+    transposed
+        .par_chunks_mut(BLOCK_SIZE * num_rows)
+        .enumerate()
+        .for_each(|(block_index, output_rows)| {
+            let first_column = block_index * BLOCK_SIZE;
+            let num_block_columns = output_rows.len() / num_rows;
+            for first_row in (0..num_rows).step_by(BLOCK_SIZE) {
+                let last_row = num_rows.min(first_row + BLOCK_SIZE);
+                for column in 0..num_block_columns {
+                    let output_row = &mut output_rows[column * num_rows..];
+                    let input_column = first_column + column;
+                    for row in first_row..last_row {
+                        output_row[row] = matrix[row * num_columns + input_column];
+                    }
+                }
+            }
+        });
+
+    transposed
+}
+
+/// Transpose the flat, row-major `matrix` of dimensions `num_rows` × `num_columns` in place.
+/// Afterwards, the `matrix` is the flat, row-major matrix of dimensions `num_columns` ×
+/// `num_rows`.
+///
+/// Square matrices are transposed by swapping blocks, without any additional memory. For other
+/// matrices, the permutation is applied by following its cycles, which requires one bit of
+/// bookkeeping per element and has worse memory locality. If memory allows, prefer
+/// [`transpose`] for non-square matrices.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::math::matrix::transpose_in_place;
+/// let mut matrix = [1, 2, 3, 4, 5, 6];
+/// transpose_in_place(&mut matrix, 3, 2);
+/// assert_eq!([1, 3, 5, 2, 4, 6], matrix);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of the `matrix` is not `num_rows · num_columns`.
+pub fn transpose_in_place<T>(matrix: &mut [T], num_rows: usize, num_columns: usize) {
+    assert_dimensions(matrix.len(), num_rows, num_columns);
+    if num_rows == num_columns {
+        transpose_square_in_place(matrix, num_rows);
+    } else {
+        transpose_rectangular_in_place(matrix, num_rows);
+    }
+}
+
+fn transpose_square_in_place<T>(matrix: &mut [T], side_length: usize) {
+    for first_row in (0..side_length).step_by(BLOCK_SIZE) {
+        let last_row = side_length.min(first_row + BLOCK_SIZE);
+        for first_column in (first_row..side_length).step_by(BLOCK_SIZE) {
+            let last_column = side_length.min(first_column + BLOCK_SIZE);
+            for row in first_row..last_row {
+                // On the diagonal block, only swap elements above its diagonal.
+                for column in first_column.max(row + 1)..last_column {
+                    matrix.swap(row * side_length + column, column * side_length + row);
+                }
+            }
+        }
+    }
+}
+
+fn transpose_rectangular_in_place<T>(matrix: &mut [T], num_rows: usize) {
+    // The element at index `i` moves to index `i·num_rows mod (len - 1)`; the last element stays.
+    let len = matrix.len();
+    if len < 2 {
+        return;
+    }
+    let modulus = len - 1;
+    let destination =
+        |index: usize| ((index as u128 * num_rows as u128) % modulus as u128) as usize;
+
+    // One bit per element, marking the elements already moved to their destination.
+    let mut visited = vec![0_u64; len.div_ceil(64)];
+    let is_visited = |bits: &[u64], index: usize| (bits[index / 64] >> (index % 64)) & 1 == 1;
+    let mark_visited = |bits: &mut [u64], index: usize| bits[index / 64] |= 1 << (index % 64);
+    for cycle_start in 1..modulus {
+        if is_visited(&visited, cycle_start) {
+            continue;
+        }
+        let mut index = destination(cycle_start);
+        while index != cycle_start {
+            matrix.swap(cycle_start, index);
+            mark_visited(&mut visited, index);
+            index = destination(index);
+        }
+        mark_visited(&mut visited, cycle_start);
+    }
+}
+
+/// The transpose of the `matrix` given as a list of equally long rows. As with any matrix, the
+/// rows might also be interpreted as columns.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::math::matrix::transpose_rows;
+/// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// let columns = vec![vec![1, 4], vec![2, 5], vec![3, 6]];
+/// assert_eq!(columns, transpose_rows(&rows));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the rows are not all of the same length.
+pub fn transpose_rows<T: Copy + Send + Sync>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
+    let Some(first_row) = matrix.first() else {
+        return vec![];
+    };
+    let num_columns = first_row.len();
+    assert!(
+        matrix.iter().all(|row| row.len() == num_columns),
+        "All rows of the matrix must be of the same length."
+    );
+
+    (0..num_columns)
+        .into_par_iter()
+        .step_by(BLOCK_SIZE)
+        .flat_map_iter(|first_column| {
+            let last_column = num_columns.min(first_column + BLOCK_SIZE);
+            let mut columns = vec![Vec::with_capacity(matrix.len()); last_column - first_column];
+            for row in matrix {
+                for (column, &element) in columns.iter_mut().zip(&row[first_column..last_column]) {
+                    column.push(element);
+                }
+            }
+            columns
+        })
+        .collect()
+}
+
+/// A matrix of field elements, stored contiguously in row-major order.
+///
+/// Compared to a `Vec<Vec<FF>>`, all elements live in one allocation, and rows are guaranteed to
+/// be of equal length. Rows are available as slices, columns as strided views.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::matrix::FieldMatrix;
+/// let matrix = FieldMatrix::new(bfe_vec![1, 2, 3, 4, 5, 6], 2, 3);
+/// assert_eq!(&bfe_vec![4, 5, 6], matrix.row(1));
+/// assert_eq!(bfe_vec![2, 5], matrix.column(1).copied().collect::<Vec<_>>());
+/// assert_eq!(bfe!(6), matrix[(1, 2)]);
+/// assert_eq!(matrix.column(2).nth(1), matrix.transpose().row(2).get(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldMatrix<FF: FiniteField> {
+    elements: Vec<FF>,
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<FF: FiniteField> FieldMatrix<FF> {
+    /// Interpret the `elements` as a row-major matrix with the given dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of `elements` is not `num_rows · num_columns`.
+    pub fn new(elements: Vec<FF>, num_rows: usize, num_columns: usize) -> Self {
+        assert_dimensions(elements.len(), num_rows, num_columns);
+        Self {
+            elements,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    pub fn zero(num_rows: usize, num_columns: usize) -> Self {
+        let num_elements = num_rows
+            .checked_mul(num_columns)
+            .expect("number of elements should fit into a usize");
+        Self::new(vec![FF::zero(); num_elements], num_rows, num_columns)
+    }
+
+    /// The matrix consisting of the given rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows are not all of the same length.
+    pub fn from_rows(rows: &[Vec<FF>]) -> Self {
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == num_columns),
+            "All rows of the matrix must be of the same length."
+        );
+        Self::new(rows.concat(), rows.len(), num_columns)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// All elements of the matrix in row-major order.
+    pub fn as_slice(&self) -> &[FF] {
+        &self.elements
+    }
+
+    /// All elements of the matrix in row-major order.
+    pub fn into_elements(self) -> Vec<FF> {
+        self.elements
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub fn row(&self, index: usize) -> &[FF] {
+        assert!(index < self.num_rows, "row index {index} out of bounds");
+        let start = index * self.num_columns;
+        &self.elements[start..start + self.num_columns]
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub fn row_mut(&mut self, index: usize) -> &mut [FF] {
+        assert!(index < self.num_rows, "row index {index} out of bounds");
+        let start = index * self.num_columns;
+        &mut self.elements[start..start + self.num_columns]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[FF]> {
+        (0..self.num_rows).map(|index| self.row(index))
+    }
+
+    /// A strided view of the column at the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub fn column(&self, index: usize) -> impl ExactSizeIterator<Item = &FF> {
+        assert!(
+            index < self.num_columns,
+            "column index {index} out of bounds"
+        );
+        // For matrices without rows, the element slice is empty, even for a valid `index`.
+        let elements = self.elements.get(index..).unwrap_or_default();
+        elements.iter().step_by(self.num_columns)
+    }
+
+    /// A mutable strided view of the column at the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub fn column_mut(&mut self, index: usize) -> impl ExactSizeIterator<Item = &mut FF> {
+        assert!(
+            index < self.num_columns,
+            "column index {index} out of bounds"
+        );
+        let num_columns = self.num_columns;
+        let elements = self.elements.get_mut(index..).unwrap_or_default();
+        elements.iter_mut().step_by(num_columns)
+    }
+
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &FF>> {
+        (0..self.num_columns).map(|index| self.column(index))
+    }
+
+    /// The transposed matrix. See also [`transpose`].
+    pub fn transpose(&self) -> Self {
+        let elements = transpose(&self.elements, self.num_rows, self.num_columns);
+        Self::new(elements, self.num_columns, self.num_rows)
+    }
+
+    /// Transpose the matrix in place. See also [`transpose_in_place`].
+    pub fn transpose_in_place(&mut self) {
+        transpose_in_place(&mut self.elements, self.num_rows, self.num_columns);
+        std::mem::swap(&mut self.num_rows, &mut self.num_columns);
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<FF>> {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl<FF: FiniteField> Index<(usize, usize)> for FieldMatrix<FF> {
+    type Output = FF;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(
+            column < self.num_columns,
+            "column index {column} out of bounds"
+        );
+        &self.row(row)[column]
+    }
+}
+
+impl<FF: FiniteField> IndexMut<(usize, usize)> for FieldMatrix<FF> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            column < self.num_columns,
+            "column index {column} out of bounds"
+        );
+        &mut self.row_mut(row)[column]
+    }
+}
+
+fn assert_dimensions(len: usize, num_rows: usize, num_columns: usize) {
+    assert_eq!(
+        Some(len),
+        num_rows.checked_mul(num_columns),
+        "The matrix must have {num_rows}·{num_columns} elements, but has {len}."
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::bfe_array;
+    use crate::bfe_vec;
+    use crate::math::b_field_element::BFieldElement;
+
+    use super::*;
+
+    fn naive_transpose<T: Copy>(matrix: &[T], num_rows: usize, num_columns: usize) -> Vec<T> {
+        (0..num_columns)
+            .cartesian_product(0..num_rows)
+            .map(|(column, row)| matrix[row * num_columns + column])
+            .collect()
+    }
+
+    #[proptest(cases = 50)]
+    fn transpose_agrees_with_naive_transpose(
+        #[strategy(0_usize..150)] num_rows: usize,
+        #[strategy(0_usize..150)] num_columns: usize,
+        #[strategy(vec(arb(), #num_rows * #num_columns))] matrix: Vec<BFieldElement>,
+    ) {
+        let expected = naive_transpose(&matrix, num_rows, num_columns);
+        prop_assert_eq!(&expected, &transpose(&matrix, num_rows, num_columns));
+
+        let mut in_place = matrix;
+        transpose_in_place(&mut in_place, num_rows, num_columns);
+        prop_assert_eq!(expected, in_place);
+    }
+
+    #[proptest(cases = 50)]
+    fn transpose_square_matrix_in_place_agrees_with_naive_transpose(
+        #[strategy(0_usize..150)] side_length: usize,
+        #[strategy(vec(arb(), #side_length * #side_length))] matrix: Vec<BFieldElement>,
+    ) {
+        let expected = naive_transpose(&matrix, side_length, side_length);
+        let mut in_place = matrix;
+        transpose_in_place(&mut in_place, side_length, side_length);
+        prop_assert_eq!(expected, in_place);
+    }
+
+    #[proptest(cases = 50)]
+    fn transposing_twice_is_identity(
+        #[strategy(0_usize..100)] num_rows: usize,
+        #[strategy(0_usize..100)] num_columns: usize,
+        #[strategy(vec(arb(), #num_rows * #num_columns))] matrix: Vec<BFieldElement>,
+    ) {
+        let transposed = transpose(&matrix, num_rows, num_columns);
+        prop_assert_eq!(&matrix, &transpose(&transposed, num_columns, num_rows));
+    }
+
+    #[proptest(cases = 50)]
+    fn transpose_rows_agrees_with_flat_transpose(
+        #[strategy(1_usize..150)] num_rows: usize,
+        #[strategy(0_usize..150)] num_columns: usize,
+        #[strategy(vec(vec(arb(), #num_columns), #num_rows))] rows: Vec<Vec<BFieldElement>>,
+    ) {
+        let flat_matrix = rows.concat();
+        let transposed = transpose(&flat_matrix, num_rows, num_columns);
+        prop_assert_eq!(transposed, transpose_rows(&rows).concat());
+    }
+
+    #[proptest(cases = 50)]
+    fn field_matrix_views_agree_with_nested_vectors(
+        #[strategy(1_usize..20)] num_rows: usize,
+        #[strategy(1_usize..20)] num_columns: usize,
+        #[strategy(vec(vec(arb(), #num_columns), #num_rows))] rows: Vec<Vec<BFieldElement>>,
+    ) {
+        let matrix = FieldMatrix::from_rows(&rows);
+        prop_assert_eq!(num_rows, matrix.num_rows());
+        prop_assert_eq!(num_columns, matrix.num_columns());
+        prop_assert_eq!(&rows, &matrix.to_rows());
+
+        let columns = transpose_rows(&rows);
+        let matrix_columns = matrix.columns().map(|c| c.copied().collect_vec());
+        prop_assert_eq!(&columns, &matrix_columns.collect_vec());
+        prop_assert_eq!(&columns, &matrix.transpose().to_rows());
+
+        for (r, c) in (0..num_rows).cartesian_product(0..num_columns) {
+            prop_assert_eq!(rows[r][c], matrix[(r, c)]);
+        }
+    }
+
+    #[proptest(cases = 50)]
+    fn field_matrix_can_be_transposed_in_place(
+        #[strategy(0_usize..20)] num_rows: usize,
+        #[strategy(0_usize..20)] num_columns: usize,
+        #[strategy(vec(arb(), #num_rows * #num_columns))] elements: Vec<BFieldElement>,
+    ) {
+        let mut matrix = FieldMatrix::new(elements, num_rows, num_columns);
+        let transposed = matrix.transpose();
+        matrix.transpose_in_place();
+        prop_assert_eq!(transposed, matrix);
+    }
+
+    #[test]
+    fn field_matrix_rows_and_columns_can_be_mutated() {
+        let mut matrix = FieldMatrix::<BFieldElement>::zero(2, 3);
+        matrix.row_mut(1).copy_from_slice(&bfe_array![1, 2, 3]);
+        for element in matrix.column_mut(0) {
+            *element += BFieldElement::new(10);
+        }
+        matrix[(0, 2)] = BFieldElement::new(5);
+        assert_eq!(FieldMatrix::new(bfe_vec![10, 0, 5, 11, 2, 3], 2, 3), matrix);
+    }
+
+    #[test]
+    fn columns_of_field_matrix_without_rows_are_empty() {
+        let mut matrix = FieldMatrix::<BFieldElement>::zero(0, 3);
+        assert_eq!(0, matrix.column(1).len());
+        assert_eq!(0, matrix.column_mut(2).len());
+        assert_eq!(3, matrix.columns().len());
+        assert!(matrix.columns().all(|column| column.len() == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 3 out of bounds")]
+    fn indexing_field_matrix_out_of_bounds_panics() {
+        let matrix = FieldMatrix::<BFieldElement>::zero(4, 3);
+        let _ = matrix[(0, 3)];
+    }
+
+    #[test]
+    fn transposing_empty_matrix_gives_empty_matrix() {
+        assert!(transpose_rows::<BFieldElement>(&[]).is_empty());
+        assert!(transpose::<BFieldElement>(&[], 0, 5).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be of the same length")]
+    fn transposing_ragged_rows_panics() {
+        transpose_rows(&[vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The matrix must have 2·3 elements, but has 5.")]
+    fn transposing_matrix_with_wrong_dimensions_panics() {
+        transpose(&[1, 2, 3, 4, 5], 2, 3);
+    }
+}
@@ -4,9 +4,17 @@ use num_traits::Zero;
 use rand_distr::num_traits::One;
 
 use super::b_field_element::BFieldElement;
+use super::b_field_element::InvertedConstant;
 use super::traits::FiniteField;
 use super::traits::Inverse;
 use super::traits::ModPowU32;
+use super::traits::PrimitiveRootOfUnity;
+use crate::error::NttError;
+
+pub mod streamed;
+
+/// The maximal length of any (i)NTT input. Limited by the internal use of `u32` for indexing.
+pub const MAX_NTT_LENGTH: usize = 1 << 31;
 
 /// ## Perform NTT on slices of prime-field elements
 ///
@@ -121,6 +129,374 @@ pub fn intt<FF: FiniteField + MulAssign<BFieldElement>>(
     }
 }
 
+/// Like [`ntt`], but takes and returns an owned vector. The [primitive root of unity][root] of
+/// the correct order is computed internally.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::ntt::intt_owned;
+/// # use twenty_first::math::ntt::ntt_owned;
+/// let values = bfe_vec![1, 2, 3, 4];
+/// let transformed = ntt_owned(values.clone()).unwrap();
+/// assert_eq!(values, intt_owned(transformed).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of the input is neither zero nor a power of two, or if it is
+/// greater than [`MAX_NTT_LENGTH`].
+///
+/// [root]: PrimitiveRootOfUnity::primitive_root_of_unity
+pub fn ntt_owned<FF: FiniteField + MulAssign<BFieldElement>>(
+    mut x: Vec<FF>,
+) -> Result<Vec<FF>, NttError> {
+    if let Some((omega, log_2_of_n)) = root_and_log_2_for_length(x.len())? {
+        ntt(&mut x, omega, log_2_of_n);
+    }
+    Ok(x)
+}
+
+/// Like [`intt`], but takes and returns an owned vector. The [primitive root of unity][root] of
+/// the correct order is computed internally.
+///
+/// The inverse of [`ntt_owned`].
+///
+/// # Errors
+///
+/// Returns an error if the length of the input is neither zero nor a power of two, or if it is
+/// greater than [`MAX_NTT_LENGTH`].
+///
+/// [root]: PrimitiveRootOfUnity::primitive_root_of_unity
+pub fn intt_owned<FF: FiniteField + MulAssign<BFieldElement>>(
+    mut x: Vec<FF>,
+) -> Result<Vec<FF>, NttError> {
+    if let Some((omega, log_2_of_n)) = root_and_log_2_for_length(x.len())? {
+        intt(&mut x, omega, log_2_of_n);
+    }
+    Ok(x)
+}
+
+/// The odd factors `r` for which [`mixed_radix_ntt`] supports inputs of length `r·2^k`.
+const MIXED_RADIX_ODD_FACTORS: [usize; 3] = [1, 3, 5];
+
+/// Like [`ntt`], but also supports inputs of length 3·2^k or 5·2^k, in addition to powers of two.
+///
+/// The `omega` must be a primitive root of unity of the input's order; see
+/// [`mixed_radix_root_of_unity`]. For inputs of a power-of-two length, this is identical to
+/// [`ntt`]. Otherwise, the input of length `r·2^k` is split into `r` interleaved parts of length
+/// `2^k`, each of which is transformed using [`ntt`]. The results are combined using `2^k`
+/// transforms of length `r`.
+///
+/// This transform is performed in-place.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::ntt::mixed_radix_intt;
+/// # use twenty_first::math::ntt::mixed_radix_ntt;
+/// # use twenty_first::math::ntt::mixed_radix_root_of_unity;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let omega = mixed_radix_root_of_unity(6).unwrap();
+/// let domain = (0..6).map(|i| omega.mod_pow(i)).collect::<Vec<_>>();
+///
+/// let mut values = polynomial.clone().coefficients;
+/// values.resize(6, bfe!(0));
+/// mixed_radix_ntt(&mut values, omega);
+/// assert_eq!(polynomial.batch_evaluate(&domain), values);
+///
+/// mixed_radix_intt(&mut values, omega);
+/// assert_eq!(polynomial, Polynomial::new(values));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the input's length is not of the form 2^k, 3·2^k, or 5·2^k.
+pub fn mixed_radix_ntt<FF: FiniteField + MulAssign<BFieldElement>>(
+    x: &mut [FF],
+    omega: BFieldElement,
+) {
+    let n = x.len();
+    if n == 0 {
+        return;
+    }
+
+    let radix = n >> n.trailing_zeros();
+    assert!(
+        MIXED_RADIX_ODD_FACTORS.contains(&radix),
+        "{}",
+        NttError::LengthNotSmooth(n)
+    );
+    let sub_length = n / radix;
+    if radix == 1 {
+        ntt(x, omega, n.ilog2());
+        return;
+    }
+
+    // `omega` must be a primitive root of unity of order `n`
+    debug_assert_eq!(n as u64, omega.multiplicative_order());
+
+    // the powers ω^(s·k1) for all s < radix and k1 < sub_length, applied to the transformed parts
+    let sub_omega = omega.mod_pow(radix as u64);
+    let parts = (0..radix)
+        .map(|s| {
+            let mut part = x.iter().skip(s).step_by(radix).copied().collect::<Vec<_>>();
+            ntt(&mut part, sub_omega, sub_length.ilog2());
+
+            let omega_to_the_s = omega.mod_pow(s as u64);
+            let mut twiddle = BFieldElement::one();
+            for elem in &mut part {
+                *elem *= twiddle;
+                twiddle *= omega_to_the_s;
+            }
+            part
+        })
+        .collect::<Vec<_>>();
+
+    // X[k1 + sub_length·k2] = Σ_s ω_r^(s·k2)·parts[s][k1], where ω_r = ω^sub_length
+    let omega_r = omega.mod_pow(sub_length as u64);
+    let powers_of_omega_r = (0..radix)
+        .map(|i| omega_r.mod_pow(i as u64))
+        .collect::<Vec<_>>();
+    for k1 in 0..sub_length {
+        for k2 in 0..radix {
+            let mut sum = FF::zero();
+            for (s, part) in parts.iter().enumerate() {
+                let mut summand = part[k1];
+                summand *= powers_of_omega_r[(s * k2) % radix];
+                sum += summand;
+            }
+            x[k1 + sub_length * k2] = sum;
+        }
+    }
+}
+
+/// The inverse of [`mixed_radix_ntt`].
+///
+/// # Panics
+///
+/// Panics if the input's length is not of the form 2^k, 3·2^k, or 5·2^k.
+pub fn mixed_radix_intt<FF: FiniteField + MulAssign<BFieldElement>>(
+    x: &mut [FF],
+    omega: BFieldElement,
+) {
+    let n = BFieldElement::new(u64::try_from(x.len()).unwrap());
+    let n_inv_or_zero = n.inverse_or_zero();
+    mixed_radix_ntt::<FF>(x, omega.inverse());
+    for elem in x.iter_mut() {
+        *elem *= n_inv_or_zero
+    }
+}
+
+/// The primitive root of unity of order `length`, for use in [`mixed_radix_ntt`].
+///
+/// For powers of two, this is the same root as
+/// [`BFieldElement::primitive_root_of_unity`][root].
+///
+/// # Errors
+///
+/// Returns an error if the `length` is neither zero nor of the form 2^k, 3·2^k, or 5·2^k, or if it
+/// is greater than [`MAX_NTT_LENGTH`].
+///
+/// [root]: PrimitiveRootOfUnity::primitive_root_of_unity
+pub fn mixed_radix_root_of_unity(length: usize) -> Result<BFieldElement, NttError> {
+    if length != 0 && !MIXED_RADIX_ODD_FACTORS.contains(&(length >> length.trailing_zeros())) {
+        return Err(NttError::LengthNotSmooth(length));
+    }
+    if length > MAX_NTT_LENGTH {
+        return Err(NttError::DomainTooLarge(length));
+    }
+    if let Some(omega) = BFieldElement::primitive_root_of_unity(length as u64) {
+        return Ok(omega);
+    }
+
+    // The generator has order p - 1, which is divisible by `length`.
+    let cofactor = (BFieldElement::P - 1) / length as u64;
+    Ok(BFieldElement::generator().mod_pow(cofactor))
+}
+
+/// The primitive root of unity of order `length` and the base-2 logarithm of `length`, or `None`
+/// if `length` is 0.
+fn root_and_log_2_for_length(length: usize) -> Result<Option<(BFieldElement, u32)>, NttError> {
+    if length == 0 {
+        return Ok(None);
+    }
+    if !length.is_power_of_two() {
+        return Err(NttError::LengthNotPowerOfTwo(length));
+    }
+    if length > MAX_NTT_LENGTH {
+        return Err(NttError::DomainTooLarge(length));
+    }
+
+    // unwrap is fine: roots of unity exist for all power-of-two orders up to 2^32
+    let omega = BFieldElement::primitive_root_of_unity(length as u64).unwrap();
+    Ok(Some((omega, length.ilog2())))
+}
+
+/// Precomputed twiddle factors for (inverse) NTTs of one fixed length.
+///
+/// Every call to [`ntt`] or [`intt`] recomputes the powers of the [primitive root of unity][root]
+/// it needs. When transforming many inputs of the same length, for example when evaluating many
+/// polynomials on the same domain, precomputing those powers once saves that work.
+///
+/// The outputs are identical to those of [`ntt`] and [`intt`] using the primitive root of unity of
+/// order [`len`](Self::len).
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::ntt::ntt;
+/// # use twenty_first::math::ntt::NttPlan;
+/// let plan = NttPlan::new(4).unwrap();
+/// let mut values = bfe_vec![1, 2, 3, 4];
+/// let mut expected = values.clone();
+///
+/// plan.ntt(&mut values);
+/// ntt(&mut expected, plan.omega(), 2);
+/// assert_eq!(expected, values);
+///
+/// plan.intt(&mut values);
+/// assert_eq!(bfe_vec![1, 2, 3, 4], values);
+/// ```
+///
+/// [root]: PrimitiveRootOfUnity::primitive_root_of_unity
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NttPlan {
+    length: usize,
+    log_2_of_length: u32,
+    omega: BFieldElement,
+
+    /// The powers `ω^i` for `0 ≤ i < length / 2`.
+    powers_of_omega: Vec<BFieldElement>,
+
+    /// The powers `ω^(-i)` for `0 ≤ i < length / 2`.
+    powers_of_omega_inverse: Vec<BFieldElement>,
+}
+
+impl NttPlan {
+    /// Precompute the twiddle factors for (inverse) NTTs of inputs of the given `length`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `length` is neither zero nor a power of two, or if it is greater
+    /// than [`MAX_NTT_LENGTH`].
+    pub fn new(length: usize) -> Result<Self, NttError> {
+        let Some((omega, log_2_of_length)) = root_and_log_2_for_length(length)? else {
+            return Ok(Self {
+                length,
+                log_2_of_length: 0,
+                omega: BFieldElement::one(),
+                powers_of_omega: vec![],
+                powers_of_omega_inverse: vec![],
+            });
+        };
+
+        Ok(Self::with_omega(omega, log_2_of_length))
+    }
+
+    /// The plan for NTTs of inputs of length `2^log_2_of_length`, using the given `omega`, which
+    /// must be a primitive root of unity of that order, instead of the canonical one.
+    pub(crate) fn with_omega(omega: BFieldElement, log_2_of_length: u32) -> Self {
+        let length = 1 << log_2_of_length;
+
+        let powers = |root: BFieldElement| {
+            std::iter::successors(Some(BFieldElement::one()), move |&power| Some(power * root))
+                .take(length / 2)
+                .collect()
+        };
+
+        Self {
+            length,
+            log_2_of_length,
+            omega,
+            powers_of_omega: powers(omega),
+            powers_of_omega_inverse: powers(omega.inverse()),
+        }
+    }
+
+    /// The length of the inputs this plan transforms.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether this plan transforms empty inputs only.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The primitive root of unity of order [`len`](Self::len), or 1 if the plan is
+    /// [empty](Self::is_empty).
+    pub fn omega(&self) -> BFieldElement {
+        self.omega
+    }
+
+    /// Like [`ntt`], using the precomputed twiddle factors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not the [plan's length](Self::len).
+    pub fn ntt<FF: FiniteField + MulAssign<BFieldElement>>(&self, x: &mut [FF]) {
+        self.transform(x, &self.powers_of_omega);
+    }
+
+    /// Like [`intt`], using the precomputed twiddle factors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not the [plan's length](Self::len).
+    pub fn intt<FF: FiniteField + MulAssign<BFieldElement>>(&self, x: &mut [FF]) {
+        self.transform(x, &self.powers_of_omega_inverse);
+
+        let n = BFieldElement::new(self.length as u64);
+        let n_inv_or_zero = n.inverse_or_zero();
+        for elem in x.iter_mut() {
+            *elem *= n_inv_or_zero;
+        }
+    }
+
+    fn transform<FF: FiniteField + MulAssign<BFieldElement>>(
+        &self,
+        x: &mut [FF],
+        powers_of_root: &[BFieldElement],
+    ) {
+        assert_eq!(
+            self.length,
+            x.len(),
+            "The input's length must match the length of the NTT plan."
+        );
+
+        let n = self.length as u32;
+        for k in 0..n {
+            let rk = bitreverse(k, self.log_2_of_length);
+            if k < rk {
+                x.swap(rk as usize, k as usize);
+            }
+        }
+
+        let mut m = 1;
+        while m < n {
+            let stride = n / (2 * m);
+            let mut k = 0;
+            while k < n {
+                for j in 0..m {
+                    let u = x[(k + j) as usize];
+                    let mut v = x[(k + j + m) as usize];
+                    v *= powers_of_root[(j * stride) as usize];
+                    x[(k + j) as usize] = u + v;
+                    x[(k + j + m) as usize] = u - v;
+                }
+
+                k += 2 * m;
+            }
+
+            m *= 2;
+        }
+    }
+}
+
 #[inline]
 pub fn bitreverse_usize(mut n: usize, l: usize) -> usize {
     let mut r = 0;
@@ -151,6 +527,7 @@ pub fn bitreverse_order<FF>(array: &mut [FF]) {
 ///  - it is followed up by INTT (e.g. for fast multiplication)
 ///  - the powers_of_omega_bitreversed can be precomputed (which
 ///    is not the case here).
+///
 /// In that case, be sure to use the matching `intt_noswap` and
 /// don't forget to unscale by n, e.g. using `unscale`.
 pub fn ntt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], omega: BFieldElement) {
@@ -246,9 +623,9 @@ pub fn intt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], ome
 /// Unscale the array by multiplying every element by the
 /// inverse of the array's length. Useful for following up intt.
 pub fn unscale(array: &mut [BFieldElement]) {
-    let ninv = BFieldElement::new(array.len() as u64).inverse();
+    let n = InvertedConstant::new(BFieldElement::new(array.len() as u64));
     for a in array.iter_mut() {
-        *a *= ninv;
+        *a /= n;
     }
 }
 
@@ -449,6 +826,151 @@ mod fast_ntt_attempt_tests {
         assert_eq!(original_input, input);
     }
 
+    #[proptest(cases = 20)]
+    fn owned_ntt_is_equivalent_to_ntt(
+        #[strategy((0_usize..12).prop_map(|l| 1 << l))] _vector_length: usize,
+        #[strategy(vec(arb(), #_vector_length))] input: Vec<XFieldElement>,
+    ) {
+        let mut expected = input.clone();
+        let root_of_unity = BFieldElement::primitive_root_of_unity(input.len() as u64).unwrap();
+        ntt(&mut expected, root_of_unity, input.len().ilog2());
+
+        let transformed = ntt_owned(input.clone()).unwrap();
+        prop_assert_eq!(&expected, &transformed);
+        prop_assert_eq!(input, intt_owned(transformed).unwrap());
+    }
+
+    #[test]
+    fn owned_ntt_of_empty_input_is_empty() {
+        assert!(ntt_owned::<BFieldElement>(vec![]).unwrap().is_empty());
+        assert!(intt_owned::<BFieldElement>(vec![]).unwrap().is_empty());
+    }
+
+    #[proptest]
+    fn owned_ntt_of_input_with_length_not_power_of_two_fails(
+        #[filter(!#length.is_power_of_two())]
+        #[strategy(1_usize..1000)]
+        length: usize,
+    ) {
+        let input = bfe_vec![0; length];
+        let expected_err = NttError::LengthNotPowerOfTwo(length);
+        prop_assert_eq!(Err(expected_err), ntt_owned(input.clone()));
+        prop_assert_eq!(Err(expected_err), intt_owned(input));
+    }
+
+    #[proptest]
+    fn ntt_with_plan_is_equivalent_to_ntt(
+        #[strategy(0_u32..12)] log_2_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_length))] values: Vec<XFieldElement>,
+    ) {
+        let plan = NttPlan::new(values.len()).unwrap();
+        let mut expected = values.clone();
+        ntt(&mut expected, plan.omega(), log_2_length);
+
+        let mut transformed = values.clone();
+        plan.ntt(&mut transformed);
+        prop_assert_eq!(&expected, &transformed);
+
+        plan.intt(&mut transformed);
+        prop_assert_eq!(values, transformed);
+    }
+
+    #[test]
+    fn ntt_plan_for_empty_input_transforms_nothing() {
+        let plan = NttPlan::new(0).unwrap();
+        assert!(plan.is_empty());
+
+        let mut values: Vec<BFieldElement> = vec![];
+        plan.ntt(&mut values);
+        plan.intt(&mut values);
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn ntt_plan_for_length_not_power_of_two_cannot_be_created() {
+        assert_eq!(Err(NttError::LengthNotPowerOfTwo(3)), NttPlan::new(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "must match the length of the NTT plan")]
+    fn ntt_with_plan_of_different_length_panics() {
+        let plan = NttPlan::new(8).unwrap();
+        plan.ntt(&mut bfe_array![1, 2, 3, 4]);
+    }
+
+    #[proptest]
+    fn mixed_radix_ntt_evaluates_polynomial_on_subgroup(
+        #[strategy(prop::sample::select(&MIXED_RADIX_ODD_FACTORS[..]))] _odd_factor: usize,
+        #[strategy(0_u32..8)] _log_2_of_power_of_two: u32,
+        #[strategy(vec(arb(), #_odd_factor << #_log_2_of_power_of_two))] values: Vec<XFieldElement>,
+    ) {
+        let length = values.len();
+        let omega = mixed_radix_root_of_unity(length).unwrap();
+        let domain = (0..length)
+            .map(|i| omega.mod_pow(i as u64).lift())
+            .collect_vec();
+        let expected = Polynomial::new(values.clone()).batch_evaluate(&domain);
+
+        let mut transformed = values.clone();
+        mixed_radix_ntt(&mut transformed, omega);
+        prop_assert_eq!(&expected, &transformed);
+
+        mixed_radix_intt(&mut transformed, omega);
+        prop_assert_eq!(values, transformed);
+    }
+
+    #[proptest]
+    fn mixed_radix_ntt_of_power_of_two_length_is_ntt(
+        #[strategy(0_u32..10)] log_2_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_length))] values: Vec<BFieldElement>,
+    ) {
+        let omega = mixed_radix_root_of_unity(values.len()).unwrap();
+        prop_assert_eq!(
+            BFieldElement::primitive_root_of_unity(values.len() as u64),
+            Some(omega)
+        );
+
+        let mut expected = values.clone();
+        ntt(&mut expected, omega, log_2_length);
+        let mut transformed = values;
+        mixed_radix_ntt(&mut transformed, omega);
+        prop_assert_eq!(expected, transformed);
+    }
+
+    #[proptest]
+    fn mixed_radix_root_of_unity_is_primitive(
+        #[strategy(prop::sample::select(&MIXED_RADIX_ODD_FACTORS[1..]))] odd_factor: usize,
+        #[strategy(0_u32..=28)] log_2_of_power_of_two: u32,
+    ) {
+        let length = (odd_factor << log_2_of_power_of_two) as u64;
+        let omega = mixed_radix_root_of_unity(length as usize).unwrap();
+        prop_assert_eq!(length, omega.multiplicative_order());
+    }
+
+    #[test]
+    fn mixed_radix_root_of_unity_for_unsupported_length_does_not_exist() {
+        for length in [7, 9, 15, 3 * 5 * 8] {
+            let err = mixed_radix_root_of_unity(length).unwrap_err();
+            assert_eq!(NttError::LengthNotSmooth(length), err);
+        }
+        let err = mixed_radix_root_of_unity(3 << 30).unwrap_err();
+        assert_eq!(NttError::DomainTooLarge(3 << 30), err);
+    }
+
+    #[test]
+    fn mixed_radix_ntt_on_empty_input_does_nothing() {
+        let mut values: Vec<BFieldElement> = vec![];
+        mixed_radix_ntt(&mut values, BFieldElement::one());
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "length must be of the form")]
+    fn mixed_radix_ntt_of_unsupported_length_panics() {
+        let omega = BFieldElement::generator().mod_pow((BFieldElement::P - 1) / 7);
+        mixed_radix_ntt(&mut bfe_array![1, 2, 3, 4, 5, 6, 7], omega);
+    }
+
     #[test]
     fn b_field_ntt_with_length_32() {
         let mut input_output = bfe_vec![
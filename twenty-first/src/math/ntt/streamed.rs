@@ -0,0 +1,472 @@
+//! (I)NTTs over codewords that are stored in chunks, for example because they do not fit into RAM.
+//!
+//! The regular [`ntt`] needs the entire codeword in one contiguous slice, and accesses it all over
+//! the place. The functions in this module only require access to one [chunk](IndexedChunks) at a
+//! time, and work on small blocks of the codeword at a time. This allows the chunks to be backed
+//! by memory-mapped files or other storage that is loaded on demand.
+//!
+//! Internally, a transform of length `n = R·C` is computed using the four-step algorithm: the
+//! codeword is interpreted as a row-major `R × C` matrix. Then,
+//!
+//! 1. all columns are transformed, in blocks of [`BLOCK_SIZE`] columns,
+//! 1. every element is multiplied by a twiddle factor,
+//! 1. all rows are transformed, and
+//! 1. the matrix is transposed.
+//!
+//! The memory required beyond the chunks themselves is proportional to `BLOCK_SIZE·√n`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::ntt::ntt_owned;
+//! # use twenty_first::math::ntt::streamed::ntt_streamed;
+//! # use twenty_first::math::traits::PrimitiveRootOfUnity;
+//! let codeword = (0..64).map(BFieldElement::new).collect::<Vec<_>>();
+//! let mut chunks = codeword.chunks(16).map(|c| c.to_vec()).collect::<Vec<_>>();
+//!
+//! let omega = BFieldElement::primitive_root_of_unity(64).unwrap();
+//! ntt_streamed(&mut chunks, omega, 6);
+//! assert_eq!(ntt_owned(codeword).unwrap(), chunks.concat());
+//! ```
+
+use std::ops::MulAssign;
+
+use num_traits::One;
+use rayon::prelude::*;
+
+use crate::config;
+use crate::math::b_field_element::BFieldElement;
+use crate::math::matrix::BLOCK_SIZE;
+use crate::math::ntt::ntt;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+
+/// Storage of a codeword in chunks of equal length.
+///
+/// The chunks are indexed consecutively, starting at 0. Concatenating them in order gives the
+/// codeword.
+pub trait IndexedChunks<FF> {
+    fn num_chunks(&self) -> usize;
+
+    /// The length of every chunk.
+    fn chunk_length(&self) -> usize;
+
+    fn chunk(&self, index: usize) -> &[FF];
+
+    fn chunk_mut(&mut self, index: usize) -> &mut [FF];
+}
+
+impl<FF> IndexedChunks<FF> for [Vec<FF>] {
+    fn num_chunks(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk_length(&self) -> usize {
+        self.first().map_or(0, |chunk| chunk.len())
+    }
+
+    fn chunk(&self, index: usize) -> &[FF] {
+        &self[index]
+    }
+
+    fn chunk_mut(&mut self, index: usize) -> &mut [FF] {
+        &mut self[index]
+    }
+}
+
+impl<FF> IndexedChunks<FF> for Vec<Vec<FF>> {
+    fn num_chunks(&self) -> usize {
+        self.as_slice().num_chunks()
+    }
+
+    fn chunk_length(&self) -> usize {
+        self.as_slice().chunk_length()
+    }
+
+    fn chunk(&self, index: usize) -> &[FF] {
+        self.as_slice().chunk(index)
+    }
+
+    fn chunk_mut(&mut self, index: usize) -> &mut [FF] {
+        self.as_mut_slice().chunk_mut(index)
+    }
+}
+
+/// Like [`ntt`], but for a codeword stored in [chunks](IndexedChunks).
+///
+/// # Panics
+///
+/// Panics if the chunks are not all of the same length. Panics if the total length of the
+/// codeword is not `2^log_2_of_n`, except for empty codewords. In debug mode, panics if `omega` is
+/// not a primitive root of unity of that order.
+pub fn ntt_streamed<FF, C>(chunks: &mut C, omega: BFieldElement, log_2_of_n: u32)
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+    C: IndexedChunks<FF> + ?Sized,
+{
+    let n = assert_geometry(chunks, log_2_of_n);
+    if n <= 1 {
+        return;
+    }
+    debug_assert!(
+        omega.mod_pow(n as u64).is_one() && !omega.mod_pow(n as u64 / 2).is_one(),
+        "Got {omega} which is not a primitive {n}th root of 1"
+    );
+
+    let log_2_num_rows = log_2_of_n / 2;
+    let log_2_num_columns = log_2_of_n - log_2_num_rows;
+    let mut codeword = ChunkedCodeword { chunks };
+    codeword.transform_columns_and_twiddle(omega, log_2_num_rows, log_2_num_columns);
+    codeword.transform_rows(omega, log_2_num_rows, log_2_num_columns);
+    codeword.transpose(log_2_num_rows, log_2_num_columns);
+}
+
+/// Like [`intt`](crate::math::ntt::intt), but for a codeword stored in [chunks](IndexedChunks).
+///
+/// The inverse of [`ntt_streamed`].
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`ntt_streamed`].
+pub fn intt_streamed<FF, C>(chunks: &mut C, omega: BFieldElement, log_2_of_n: u32)
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+    C: IndexedChunks<FF> + ?Sized,
+{
+    let n = assert_geometry(chunks, log_2_of_n);
+    if n == 0 {
+        return;
+    }
+
+    ntt_streamed(chunks, omega.inverse(), log_2_of_n);
+    let n_inv = BFieldElement::new(n as u64).inverse();
+    for index in 0..chunks.num_chunks() {
+        for element in chunks.chunk_mut(index) {
+            *element *= n_inv;
+        }
+    }
+}
+
+/// Assert that the chunks are of equal length and hold `2^log_2_of_n` elements in total, or none
+/// at all. Returns the total number of elements.
+fn assert_geometry<FF, C>(chunks: &C, log_2_of_n: u32) -> usize
+where
+    C: IndexedChunks<FF> + ?Sized,
+{
+    let chunk_length = chunks.chunk_length();
+    assert!(
+        (0..chunks.num_chunks()).all(|index| chunks.chunk(index).len() == chunk_length),
+        "All chunks must be of length {chunk_length}."
+    );
+
+    let n = chunks.num_chunks() * chunk_length;
+    assert!(
+        n == 0 || n == 1 << log_2_of_n,
+        "The codeword must be of length 2^{log_2_of_n}, but is of length {n}."
+    );
+    n
+}
+
+/// A view on [chunks](IndexedChunks) that allows accessing contiguous ranges of the codeword,
+/// independent of the chunk boundaries.
+struct ChunkedCodeword<'c, C: ?Sized> {
+    chunks: &'c mut C,
+}
+
+impl<C: ?Sized> ChunkedCodeword<'_, C> {
+    /// Copy the codeword's elements starting at index `start` into the `buffer`.
+    fn read<FF: Copy>(&self, start: usize, buffer: &mut [FF])
+    where
+        C: IndexedChunks<FF>,
+    {
+        let chunk_length = self.chunks.chunk_length();
+        let mut num_read = 0;
+        while num_read < buffer.len() {
+            let index = start + num_read;
+            let offset = index % chunk_length;
+            let num_to_read = (chunk_length - offset).min(buffer.len() - num_read);
+            let chunk = self.chunks.chunk(index / chunk_length);
+            buffer[num_read..num_read + num_to_read]
+                .copy_from_slice(&chunk[offset..offset + num_to_read]);
+            num_read += num_to_read;
+        }
+    }
+
+    /// Copy the `buffer` into the codeword, starting at index `start`.
+    fn write<FF: Copy>(&mut self, start: usize, buffer: &[FF])
+    where
+        C: IndexedChunks<FF>,
+    {
+        let chunk_length = self.chunks.chunk_length();
+        let mut num_written = 0;
+        while num_written < buffer.len() {
+            let index = start + num_written;
+            let offset = index % chunk_length;
+            let num_to_write = (chunk_length - offset).min(buffer.len() - num_written);
+            let chunk = self.chunks.chunk_mut(index / chunk_length);
+            chunk[offset..offset + num_to_write]
+                .copy_from_slice(&buffer[num_written..num_written + num_to_write]);
+            num_written += num_to_write;
+        }
+    }
+
+    /// Copy the square block whose top left corner is at index `start` into the `buffer`,
+    /// transposing it. The block's side length is the length of the `row_buffer`, and the rows of
+    /// the matrix the block is part of are of length `row_length`.
+    fn read_transposed_block<FF: Copy>(
+        &self,
+        start: usize,
+        row_length: usize,
+        buffer: &mut [FF],
+        row_buffer: &mut [FF],
+    ) where
+        C: IndexedChunks<FF>,
+    {
+        let block_size = row_buffer.len();
+        for row in 0..block_size {
+            self.read(start + row * row_length, row_buffer);
+            for (column, &element) in row_buffer.iter().enumerate() {
+                buffer[column * block_size + row] = element;
+            }
+        }
+    }
+
+    /// Steps 1 and 2: transform every column of length `R` using root `ω^C`, then multiply the
+    /// element in row `r` and column `c` by `ω^(r·c)`.
+    fn transform_columns_and_twiddle<FF>(
+        &mut self,
+        omega: BFieldElement,
+        log_2_num_rows: u32,
+        log_2_num_columns: u32,
+    ) where
+        FF: FiniteField + MulAssign<BFieldElement>,
+        C: IndexedChunks<FF>,
+    {
+        let num_rows = 1 << log_2_num_rows;
+        let num_columns = 1 << log_2_num_columns;
+        let column_omega = omega.mod_pow(num_columns as u64);
+        let block_width = BLOCK_SIZE.min(num_columns);
+
+        // The block of columns is stored transposed, _i.e._, one column per row.
+        let mut block = vec![FF::zero(); block_width * num_rows];
+        let mut row_buffer = vec![FF::zero(); block_width];
+        for first_column in (0..num_columns).step_by(block_width) {
+            for row in 0..num_rows {
+                self.read(row * num_columns + first_column, &mut row_buffer);
+                for (column, &element) in row_buffer.iter().enumerate() {
+                    block[column * num_rows + row] = element;
+                }
+            }
+
+            config::install(|| {
+                block
+                    .par_chunks_mut(num_rows)
+                    .enumerate()
+                    .for_each(|(column_offset, column)| {
+                        ntt(column, column_omega, log_2_num_rows);
+                        let twiddle_base = omega.mod_pow((first_column + column_offset) as u64);
+                        let mut twiddle = BFieldElement::one();
+                        for element in column {
+                            *element *= twiddle;
+                            twiddle *= twiddle_base;
+                        }
+                    })
+            });
+
+            for row in 0..num_rows {
+                for (column, element) in row_buffer.iter_mut().enumerate() {
+                    *element = block[column * num_rows + row];
+                }
+                self.write(row * num_columns + first_column, &row_buffer);
+            }
+        }
+    }
+
+    /// Step 3: transform every row of length `C` using root `ω^R`.
+    fn transform_rows<FF>(
+        &mut self,
+        omega: BFieldElement,
+        log_2_num_rows: u32,
+        log_2_num_columns: u32,
+    ) where
+        FF: FiniteField + MulAssign<BFieldElement>,
+        C: IndexedChunks<FF>,
+    {
+        let num_rows = 1 << log_2_num_rows;
+        let num_columns = 1 << log_2_num_columns;
+        let row_omega = omega.mod_pow(num_rows as u64);
+
+        // Use a buffer of about the same size as for the column transforms.
+        let rows_per_batch = (BLOCK_SIZE * num_rows / num_columns).clamp(1, num_rows);
+        let mut batch = vec![FF::zero(); rows_per_batch * num_columns];
+        for first_row in (0..num_rows).step_by(rows_per_batch) {
+            let start = first_row * num_columns;
+            self.read(start, &mut batch);
+            config::install(|| {
+                batch
+                    .par_chunks_mut(num_columns)
+                    .for_each(|row| ntt(row, row_omega, log_2_num_columns))
+            });
+            self.write(start, &batch);
+        }
+    }
+
+    /// Step 4: transpose the `R × C` matrix, where `C` is either `R` or `2R`.
+    ///
+    /// The transposition happens in place. First, the one or two square `R × R` sub-matrices are
+    /// transposed block by block. If `C = 2R`, the rows of the transposed matrix are then in the
+    /// wrong order: the row of length `R` at position `2i` needs to go to position `i`, and the
+    /// row at position `2i + 1` needs to go to position `R + i`. This is fixed by following the
+    /// cycles of that permutation.
+    fn transpose<FF>(&mut self, log_2_num_rows: u32, log_2_num_columns: u32)
+    where
+        FF: FiniteField,
+        C: IndexedChunks<FF>,
+    {
+        let num_rows = 1 << log_2_num_rows;
+        let num_columns = 1 << log_2_num_columns;
+        let block_size = BLOCK_SIZE.min(num_rows);
+        let num_blocks = num_rows / block_size;
+
+        let mut block = vec![FF::zero(); block_size * block_size];
+        let mut other_block = vec![FF::zero(); block_size * block_size];
+        let mut row_buffer = vec![FF::zero(); block_size];
+        for first_sub_matrix_column in (0..num_columns).step_by(num_rows) {
+            let block_start = |block_row: usize, block_column: usize| {
+                let row = block_row * block_size;
+                let column = first_sub_matrix_column + block_column * block_size;
+                row * num_columns + column
+            };
+
+            for block_row in 0..num_blocks {
+                for block_column in block_row..num_blocks {
+                    let start = block_start(block_row, block_column);
+                    let other_start = block_start(block_column, block_row);
+                    self.read_transposed_block(start, num_columns, &mut block, &mut row_buffer);
+                    self.read_transposed_block(
+                        other_start,
+                        num_columns,
+                        &mut other_block,
+                        &mut row_buffer,
+                    );
+
+                    // The buffers hold the transposed blocks, which are written row by row into
+                    // each other's place.
+                    for row in 0..block_size {
+                        let row_elements = &other_block[row * block_size..][..block_size];
+                        self.write(start + row * num_columns, row_elements);
+                    }
+                    for row in 0..block_size {
+                        let row_elements = &block[row * block_size..][..block_size];
+                        self.write(other_start + row * num_columns, row_elements);
+                    }
+                }
+            }
+        }
+
+        if num_columns == num_rows {
+            return;
+        }
+
+        let destination = |position: usize| match position % 2 {
+            0 => position / 2,
+            _ => num_rows + position / 2,
+        };
+        let num_segments = 2 * num_rows;
+        let mut is_in_place = vec![false; num_segments];
+        let mut carried_segment = vec![FF::zero(); num_rows];
+        let mut displaced_segment = vec![FF::zero(); num_rows];
+        for cycle_start in 0..num_segments {
+            if is_in_place[cycle_start] {
+                continue;
+            }
+            self.read(cycle_start * num_rows, &mut carried_segment);
+            let mut position = cycle_start;
+            loop {
+                let next_position = destination(position);
+                is_in_place[next_position] = true;
+                self.read(next_position * num_rows, &mut displaced_segment);
+                self.write(next_position * num_rows, &carried_segment);
+                std::mem::swap(&mut carried_segment, &mut displaced_segment);
+                position = next_position;
+                if position == cycle_start {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::math::ntt::intt;
+    use crate::math::traits::PrimitiveRootOfUnity;
+    use crate::math::x_field_element::XFieldElement;
+
+    use super::*;
+
+    fn into_chunks<FF: Copy>(codeword: &[FF], log_2_chunk_length: u32) -> Vec<Vec<FF>> {
+        codeword
+            .chunks(1 << log_2_chunk_length)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+
+    #[proptest(cases = 100)]
+    fn streamed_ntt_agrees_with_ntt(
+        #[strategy(0_u32..=14)] log_2_n: u32,
+        #[strategy(0..=#log_2_n)] log_2_chunk_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_n))] codeword: Vec<BFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_n).unwrap();
+        let mut chunks = into_chunks(&codeword, log_2_chunk_length);
+        ntt_streamed(&mut chunks, omega, log_2_n);
+
+        let mut expected = codeword;
+        ntt(&mut expected, omega, log_2_n);
+        prop_assert_eq!(expected, chunks.concat());
+    }
+
+    #[proptest(cases = 50)]
+    fn streamed_intt_agrees_with_intt(
+        #[strategy(0_u32..=12)] log_2_n: u32,
+        #[strategy(0..=#log_2_n)] log_2_chunk_length: u32,
+        #[strategy(vec(arb(), 1 << #log_2_n))] codeword: Vec<XFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_n).unwrap();
+        let mut chunks = into_chunks(&codeword, log_2_chunk_length);
+        intt_streamed(chunks.as_mut_slice(), omega, log_2_n);
+
+        let mut expected = codeword;
+        intt(&mut expected, omega, log_2_n);
+        prop_assert_eq!(expected, chunks.concat());
+    }
+
+    #[test]
+    fn streamed_ntt_of_empty_codeword_is_empty() {
+        let mut chunks = Vec::<Vec<BFieldElement>>::new();
+        ntt_streamed(&mut chunks, BFieldElement::one(), 0);
+        intt_streamed(&mut chunks, BFieldElement::one(), 0);
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be of length 2^3")]
+    fn streamed_ntt_of_codeword_with_wrong_length_panics() {
+        let mut chunks = vec![vec![BFieldElement::one(); 2]; 3];
+        ntt_streamed(&mut chunks, BFieldElement::one(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "All chunks must be of length 2")]
+    fn streamed_ntt_of_chunks_with_different_lengths_panics() {
+        let mut chunks = vec![vec![BFieldElement::one(); 2], vec![BFieldElement::one(); 4]];
+        ntt_streamed(&mut chunks, BFieldElement::one(), 3);
+    }
+}
@@ -0,0 +1,193 @@
+//! Count the field operations performed by some computation.
+//!
+//! Wall-clock benchmarks are noisy and depend on the machine. When comparing alternative
+//! algorithms, for example two ways to compute a [zerofier], the number of field operations is
+//! often the more telling metric. With feature `op-count` enabled, the arithmetic of
+//! [`BFieldElement`]s and [`XFieldElement`]s increments thread-local counters, which can be read
+//! using [`snapshot`].
+//!
+//! Counts are inclusive: an operation implemented in terms of other counted operations also counts
+//! those. For example, an [`XFieldElement`] multiplication also counts as 12 base field
+//! multiplications, and an inversion also counts the multiplications of the exponentiation it is
+//! implemented with. Operations mixing [`BFieldElement`]s and [`XFieldElement`]s only count the
+//! base field operations they consist of. Operations in `const` contexts, like
+//! [`BFieldElement::mod_pow`], are not counted.
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::op_count;
+//! let (product, counts) = op_count::count(|| bfe!(3) * bfe!(5) + bfe!(7));
+//! assert_eq!(bfe!(22), product);
+//! assert_eq!(1, counts.bfe_multiplications);
+//! assert_eq!(1, counts.bfe_additions);
+//! ```
+//!
+//! [zerofier]: crate::math::polynomial::Polynomial::zerofier
+
+use std::cell::Cell;
+use std::ops::Sub;
+
+#[cfg(doc)]
+use crate::math::b_field_element::BFieldElement;
+#[cfg(doc)]
+use crate::math::x_field_element::XFieldElement;
+
+/// The number of field operations performed on some thread.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct OpCounts {
+    /// Additions, subtractions, and negations of [`BFieldElement`]s.
+    pub bfe_additions: u64,
+    pub bfe_multiplications: u64,
+    pub bfe_inversions: u64,
+
+    /// Additions and subtractions of [`XFieldElement`]s.
+    pub xfe_additions: u64,
+    pub xfe_multiplications: u64,
+    pub xfe_inversions: u64,
+}
+
+impl OpCounts {
+    const ZERO: Self = Self {
+        bfe_additions: 0,
+        bfe_multiplications: 0,
+        bfe_inversions: 0,
+        xfe_additions: 0,
+        xfe_multiplications: 0,
+        xfe_inversions: 0,
+    };
+}
+
+/// The operations counted between two [snapshots](snapshot).
+impl Sub for OpCounts {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            bfe_additions: self.bfe_additions - rhs.bfe_additions,
+            bfe_multiplications: self.bfe_multiplications - rhs.bfe_multiplications,
+            bfe_inversions: self.bfe_inversions - rhs.bfe_inversions,
+            xfe_additions: self.xfe_additions - rhs.xfe_additions,
+            xfe_multiplications: self.xfe_multiplications - rhs.xfe_multiplications,
+            xfe_inversions: self.xfe_inversions - rhs.xfe_inversions,
+        }
+    }
+}
+
+thread_local! {
+    static OP_COUNTS: Cell<OpCounts> = const { Cell::new(OpCounts::ZERO) };
+}
+
+/// The number of field operations performed on the current thread since it started or since the
+/// last call to [`reset`].
+///
+/// Many methods, for example of [`Polynomial`][poly], distribute their work using rayon. The
+/// operations performed on rayon's worker threads are not counted by the calling thread's
+/// counters. Use [`count`] to get the complete count of some computation.
+///
+/// [poly]: crate::math::polynomial::Polynomial
+pub fn snapshot() -> OpCounts {
+    OP_COUNTS.get()
+}
+
+/// Set all counters of the current thread to zero.
+pub fn reset() {
+    OP_COUNTS.set(OpCounts::ZERO);
+}
+
+/// Run `f` and return its result alongside the number of field operations it performed.
+///
+/// In order to count all operations, including those that would otherwise be distributed across
+/// several threads, `f` is run on a dedicated rayon thread pool with a single thread. This makes
+/// the counts deterministic, but also means that `f` runs sequentially.
+///
+/// # Panics
+///
+/// Panics if the dedicated thread pool cannot be created.
+pub fn count<R: Send>(f: impl FnOnce() -> R + Send) -> (R, OpCounts) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("thread pool for counting field operations should be creatable");
+
+    pool.install(|| {
+        let counts_before = snapshot();
+        let result = f();
+        (result, snapshot() - counts_before)
+    })
+}
+
+pub(crate) fn increment(kind: impl FnOnce(&mut OpCounts) -> &mut u64) {
+    let mut counts = OP_COUNTS.get();
+    *kind(&mut counts) += 1;
+    OP_COUNTS.set(counts);
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::One;
+
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::polynomial::Polynomial;
+    use crate::math::traits::Inverse;
+    use crate::math::x_field_element::XFieldElement;
+    use crate::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn base_field_operations_are_counted() {
+        let a = bfe!(3);
+        let b = bfe!(5);
+        let (_, counts) = count(|| (a + b, a - b, -a, a * b, a.inverse()));
+
+        assert_eq!(3, counts.bfe_additions);
+        assert!(counts.bfe_multiplications > 1);
+        assert_eq!(1, counts.bfe_inversions);
+        assert_eq!(0, counts.xfe_additions);
+        assert_eq!(0, counts.xfe_multiplications);
+        assert_eq!(0, counts.xfe_inversions);
+    }
+
+    #[test]
+    fn extension_field_operations_count_their_base_field_operations() {
+        let a = xfe!([1, 2, 3]);
+        let b = xfe!([4, 5, 6]);
+        let (_, counts) = count(|| a * b);
+
+        let expected = OpCounts {
+            bfe_additions: 9,
+            bfe_multiplications: 12,
+            xfe_multiplications: 1,
+            ..OpCounts::default()
+        };
+        assert_eq!(expected, counts);
+    }
+
+    #[test]
+    fn assigning_operations_are_counted() {
+        let (_, counts) = count(|| {
+            let mut a = XFieldElement::one();
+            a += a;
+            a -= a;
+            a *= a;
+        });
+        assert_eq!(2, counts.xfe_additions);
+        assert_eq!(1, counts.xfe_multiplications);
+    }
+
+    #[test]
+    fn operations_on_rayon_threads_are_counted() {
+        let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+        let domain = (0..1000).map(BFieldElement::new).collect::<Vec<_>>();
+        let (_, counts) = count(|| polynomial.batch_evaluate(&domain));
+        assert_eq!(3000, counts.bfe_multiplications);
+    }
+
+    #[test]
+    fn resetting_sets_counts_to_zero() {
+        let _ = bfe!(3) * bfe!(5);
+        assert_ne!(OpCounts::default(), snapshot());
+        reset();
+        assert_eq!(OpCounts::default(), snapshot());
+    }
+}
@@ -0,0 +1,321 @@
+//! Padding of execution traces to a power-of-two height.
+//!
+//! Interpolating the columns of an execution trace requires the trace's height to be a power of
+//! two. Padding rows can break the constraints that the original rows satisfy, and which ones they
+//! break depends on the [padding strategy](PaddingStrategy). Instead of having every prover figure
+//! out where which [kind of constraint](ConstraintKind) still holds, a [`PaddedTrace`] knows the
+//! [rows on which constraints are enforced](PaddedTrace::enforced_rows) and the corresponding
+//! [zerofier](PaddedTrace::zerofier) to divide the constraint polynomials by.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::matrix::FieldMatrix;
+//! # use twenty_first::math::padding::ConstraintKind;
+//! # use twenty_first::math::padding::PaddedTrace;
+//! # use twenty_first::math::padding::PaddingStrategy;
+//! # use twenty_first::math::traits::PrimitiveRootOfUnity;
+//! let trace = FieldMatrix::from_rows(&[bfe_vec![1, 1], bfe_vec![2, 1], bfe_vec![3, 2]]);
+//! let strategy = PaddingStrategy::RepeatLastRow;
+//! let padded = PaddedTrace::new(trace, strategy, &mut rand::thread_rng());
+//! assert_eq!(4, padded.padded_height());
+//! assert_eq!(&bfe_vec![3, 2], padded.trace().row(3));
+//!
+//! // The last row of the padded trace is a copy of the last original row.
+//! assert_eq!(3..4, padded.enforced_rows(ConstraintKind::Terminal));
+//!
+//! let omega = BFieldElement::primitive_root_of_unity(4).unwrap();
+//! let zerofier = padded.zerofier(ConstraintKind::Terminal, omega);
+//! assert_eq!(bfe!(0), zerofier.evaluate(omega.mod_pow(3)));
+//! ```
+
+use std::ops::Range;
+
+use num_traits::One;
+use num_traits::Zero;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::Rng;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::matrix::FieldMatrix;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+
+/// How to fill the rows between the last row of the original trace and the next power of two.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PaddingStrategy {
+    /// All padding rows are zero. A zero row generally does not satisfy any constraints, so they
+    /// can only be enforced on the original rows. The verifier has to know the original height.
+    Zero,
+
+    /// All padding rows are copies of the last original row. They satisfy all
+    /// [consistency constraints](ConstraintKind::Consistency), and the last row of the padded
+    /// trace satisfies all [terminal constraints](ConstraintKind::Terminal). Both can be enforced
+    /// independent of the original height. Transitions into or within the padding are not
+    /// guaranteed to be valid.
+    RepeatLastRow,
+
+    /// All padding rows are uniformly random. Like [`Zero`](Self::Zero), constraints can only be
+    /// enforced on the original rows. The random rows randomize the columns' interpolants.
+    Random,
+}
+
+/// The kinds of constraints an execution trace is subject to, distinguished by the rows they
+/// apply to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ConstraintKind {
+    /// Constraints on the first row.
+    Initial,
+
+    /// Constraints on every row in isolation.
+    Consistency,
+
+    /// Constraints on every pair of consecutive rows. Enforced on row `i` means that the
+    /// constraints hold for rows `i` and `i + 1`.
+    Transition,
+
+    /// Constraints on the last row.
+    Terminal,
+}
+
+/// An execution trace, padded to a power-of-two height. See the
+/// [module-level documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaddedTrace<FF: FiniteField> {
+    trace: FieldMatrix<FF>,
+    original_height: usize,
+    strategy: PaddingStrategy,
+}
+
+impl<FF: FiniteField> PaddedTrace<FF> {
+    /// Pad the `trace` to the next power of two using the given `strategy`. The `rng` is only used
+    /// for [random padding](PaddingStrategy::Random).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `trace` has no rows.
+    pub fn new<R: Rng + ?Sized>(
+        trace: FieldMatrix<FF>,
+        strategy: PaddingStrategy,
+        rng: &mut R,
+    ) -> Self
+    where
+        Standard: Distribution<FF>,
+    {
+        let original_height = trace.num_rows();
+        assert_ne!(0, original_height, "The trace must have at least one row.");
+
+        let num_columns = trace.num_columns();
+        let padded_height = original_height.next_power_of_two();
+        let num_padding_rows = padded_height - original_height;
+        let padding_row = trace.row(original_height - 1).to_vec();
+
+        let mut elements = trace.into_elements();
+        elements.reserve(num_padding_rows * num_columns);
+        for _ in 0..num_padding_rows {
+            match strategy {
+                PaddingStrategy::Zero => elements.extend(vec![FF::zero(); num_columns]),
+                PaddingStrategy::RepeatLastRow => elements.extend_from_slice(&padding_row),
+                PaddingStrategy::Random => {
+                    elements.extend((0..num_columns).map(|_| rng.gen::<FF>()))
+                }
+            }
+        }
+
+        Self {
+            trace: FieldMatrix::new(elements, padded_height, num_columns),
+            original_height,
+            strategy,
+        }
+    }
+
+    pub fn trace(&self) -> &FieldMatrix<FF> {
+        &self.trace
+    }
+
+    pub fn into_trace(self) -> FieldMatrix<FF> {
+        self.trace
+    }
+
+    pub fn strategy(&self) -> PaddingStrategy {
+        self.strategy
+    }
+
+    /// The number of rows of the trace before padding.
+    pub fn original_height(&self) -> usize {
+        self.original_height
+    }
+
+    /// The number of rows of the trace after padding, a power of two.
+    pub fn padded_height(&self) -> usize {
+        self.trace.num_rows()
+    }
+
+    /// The rows on which constraints of the given kind are enforced, given the
+    /// [padding strategy](PaddingStrategy).
+    pub fn enforced_rows(&self, kind: ConstraintKind) -> Range<usize> {
+        let original_height = self.original_height;
+        let padded_height = self.padded_height();
+        let repeats_last_row = self.strategy == PaddingStrategy::RepeatLastRow;
+
+        match kind {
+            ConstraintKind::Initial => 0..1,
+            ConstraintKind::Consistency if repeats_last_row => 0..padded_height,
+            ConstraintKind::Consistency => 0..original_height,
+            ConstraintKind::Transition => 0..original_height - 1,
+            ConstraintKind::Terminal if repeats_last_row => padded_height - 1..padded_height,
+            ConstraintKind::Terminal => original_height - 1..original_height,
+        }
+    }
+
+    /// The polynomial that vanishes on exactly those points of the trace domain that correspond
+    /// to the [rows on which constraints of the given kind are enforced](Self::enforced_rows).
+    /// Row `i` corresponds to `trace_domain_generator^i`.
+    ///
+    /// Dividing a constraint polynomial of the given kind by this zerofier leaves no remainder if
+    /// and only if the trace satisfies the constraints on all enforced rows.
+    pub fn zerofier(
+        &self,
+        kind: ConstraintKind,
+        trace_domain_generator: BFieldElement,
+    ) -> Polynomial<BFieldElement> {
+        let rows = self.enforced_rows(kind);
+        if rows == (0..self.padded_height()) {
+            // x^padded_height - 1
+            let mut coefficients = vec![BFieldElement::zero(); self.padded_height() + 1];
+            coefficients[0] = -BFieldElement::one();
+            coefficients[self.padded_height()] = BFieldElement::one();
+            return Polynomial::new(coefficients);
+        }
+
+        let first_root = trace_domain_generator.mod_pow(rows.start as u64);
+        let roots: Vec<_> = std::iter::successors(Some(first_root), |&root| {
+            Some(root * trace_domain_generator)
+        })
+        .take(rows.len())
+        .collect();
+        Polynomial::zerofier(&roots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::traits::PrimitiveRootOfUnity;
+
+    const STRATEGIES: [PaddingStrategy; 3] = [
+        PaddingStrategy::Zero,
+        PaddingStrategy::RepeatLastRow,
+        PaddingStrategy::Random,
+    ];
+    const KINDS: [ConstraintKind; 4] = [
+        ConstraintKind::Initial,
+        ConstraintKind::Consistency,
+        ConstraintKind::Transition,
+        ConstraintKind::Terminal,
+    ];
+
+    fn random_trace(
+        num_rows: usize,
+        num_columns: usize,
+        rng: &mut StdRng,
+    ) -> FieldMatrix<BFieldElement> {
+        let elements = (0..num_rows * num_columns).map(|_| rng.gen()).collect_vec();
+        FieldMatrix::new(elements, num_rows, num_columns)
+    }
+
+    #[proptest(cases = 50)]
+    fn padding_preserves_original_rows(
+        #[strategy(1_usize..100)] num_rows: usize,
+        #[strategy(0_usize..5)] num_columns: usize,
+        #[strategy(0_usize..3)] strategy_index: usize,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let trace = random_trace(num_rows, num_columns, &mut rng);
+        let strategy = STRATEGIES[strategy_index];
+        let padded = PaddedTrace::new(trace.clone(), strategy, &mut rng);
+
+        prop_assert_eq!(num_rows, padded.original_height());
+        prop_assert_eq!(num_rows.next_power_of_two(), padded.padded_height());
+        prop_assert_eq!(num_columns, padded.trace().num_columns());
+        for (original_row, padded_row) in trace.rows().zip(padded.trace().rows()) {
+            prop_assert_eq!(original_row, padded_row);
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn padding_rows_follow_strategy(#[strategy(1_usize..100)] num_rows: usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let trace = random_trace(num_rows, 3, &mut rng);
+        let last_row = trace.row(num_rows - 1).to_vec();
+
+        let zero_padded = PaddedTrace::new(trace.clone(), PaddingStrategy::Zero, &mut rng);
+        for row in zero_padded.trace().rows().skip(num_rows) {
+            prop_assert!(row.iter().all(|element| element.is_zero()));
+        }
+
+        let strategy = PaddingStrategy::RepeatLastRow;
+        let repeat_padded = PaddedTrace::new(trace, strategy, &mut rng);
+        for row in repeat_padded.trace().rows().skip(num_rows) {
+            prop_assert_eq!(last_row.as_slice(), row);
+        }
+    }
+
+    #[proptest(cases = 50)]
+    fn zerofier_vanishes_exactly_on_enforced_rows(
+        #[strategy(1_usize..40)] num_rows: usize,
+        #[strategy(0_usize..3)] strategy_index: usize,
+        #[strategy(0_usize..4)] kind_index: usize,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let trace = random_trace(num_rows, 1, &mut rng);
+        let strategy = STRATEGIES[strategy_index];
+        let padded = PaddedTrace::new(trace, strategy, &mut rng);
+
+        let kind = KINDS[kind_index];
+        let padded_height = padded.padded_height();
+        let omega = BFieldElement::primitive_root_of_unity(padded_height as u64).unwrap();
+        let zerofier = padded.zerofier(kind, omega);
+        let enforced_rows = padded.enforced_rows(kind);
+        prop_assert_eq!(enforced_rows.len() as isize, zerofier.degree());
+
+        for row in 0..padded_height {
+            let vanishes = zerofier.evaluate(omega.mod_pow(row as u64)).is_zero();
+            prop_assert_eq!(enforced_rows.contains(&row), vanishes);
+        }
+    }
+
+    #[test]
+    fn only_repeating_last_row_enforces_constraints_independent_of_original_height() {
+        let mut rng = StdRng::seed_from_u64(0x9add);
+        let trace = random_trace(5, 2, &mut rng);
+        let strategy = PaddingStrategy::RepeatLastRow;
+        let repeat = PaddedTrace::new(trace.clone(), strategy, &mut rng);
+        assert_eq!(0..8, repeat.enforced_rows(ConstraintKind::Consistency));
+        assert_eq!(7..8, repeat.enforced_rows(ConstraintKind::Terminal));
+        assert_eq!(0..4, repeat.enforced_rows(ConstraintKind::Transition));
+
+        let zero = PaddedTrace::new(trace, PaddingStrategy::Zero, &mut rng);
+        assert_eq!(0..5, zero.enforced_rows(ConstraintKind::Consistency));
+        assert_eq!(4..5, zero.enforced_rows(ConstraintKind::Terminal));
+        assert_eq!(0..4, zero.enforced_rows(ConstraintKind::Transition));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one row")]
+    fn empty_trace_cannot_be_padded() {
+        let trace = FieldMatrix::<BFieldElement>::zero(0, 3);
+        let _ = PaddedTrace::new(trace, PaddingStrategy::Zero, &mut StdRng::seed_from_u64(0));
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -18,18 +19,46 @@ use itertools::Itertools;
 use num_bigint::BigInt;
 use num_traits::One;
 use num_traits::Zero;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::Rng;
 use rayon::prelude::*;
 
+use crate::bfe_vec;
+use crate::config;
+use crate::error::NttError;
+use crate::error::PolynomialError;
+use crate::math::matrix::FieldMatrix;
 use crate::math::ntt::intt;
+use crate::math::ntt::mixed_radix_intt;
+use crate::math::ntt::mixed_radix_ntt;
+use crate::math::ntt::mixed_radix_root_of_unity;
 use crate::math::ntt::ntt;
+use crate::math::ntt::NttPlan;
+use crate::math::ntt::MAX_NTT_LENGTH;
+use crate::math::scratch;
 use crate::math::traits::FiniteField;
 use crate::math::traits::ModPowU32;
+use crate::math::traits::ModPowU64;
+use crate::math::x_field_element::EXTENSION_DEGREE;
 use crate::prelude::BFieldElement;
 use crate::prelude::Inverse;
 use crate::prelude::XFieldElement;
 
 use super::traits::PrimitiveRootOfUnity;
 
+/// Report the [strategy](crate::math::instrumentation::Strategy) a dispatching method chose. Does
+/// nothing unless feature `instrumentation` is enabled.
+macro_rules! record_strategy {
+    ($method:ident, $strategy:ident) => {
+        #[cfg(any(test, feature = "instrumentation"))]
+        crate::math::instrumentation::record(
+            crate::math::instrumentation::Method::$method,
+            crate::math::instrumentation::Strategy::$strategy,
+        );
+    };
+}
+
 impl<FF: FiniteField> Zero for Polynomial<FF> {
     fn zero() -> Self {
         Self {
@@ -75,27 +104,121 @@ impl<FF: FiniteField> Hash for Polynomial<FF> {
 
 impl<FF: FiniteField> Display for Polynomial<FF> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let degree = match self.degree() {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl<FF: FiniteField> Polynomial<FF> {
+    /// A configurable way to [`Display`] `self`. By default, the result is identical to the
+    /// polynomial's own [`Display`] implementation: the monomials are listed in order of
+    /// descending degree, and the variable is `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 0, 2, 3]);
+    /// assert_eq!("3x^3 + 2x^2 + 1", polynomial.display().to_string());
+    /// assert_eq!(
+    ///     "1 + 2t^2 + 3t^3",
+    ///     polynomial.display().ascending().with_variable("t").to_string()
+    /// );
+    /// ```
+    pub fn display(&self) -> PolynomialDisplay<'_, FF> {
+        PolynomialDisplay {
+            polynomial: self,
+            variable: "x",
+            ascending: false,
+            latex: false,
+        }
+    }
+
+    /// `self` in LaTeX math notation, with monomials in order of descending degree.
+    ///
+    /// Coefficients are formatted using their [`Display`] implementation. For different
+    /// variables or orders, see [`display`](Self::display).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1]);
+    /// assert_eq!("x^{10} + 2x^{2} + 1", polynomial.to_latex());
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.display().latex().to_string()
+    }
+}
+
+/// A configurable [`Display`] of a [`Polynomial`], created by [`Polynomial::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct PolynomialDisplay<'a, FF: FiniteField> {
+    polynomial: &'a Polynomial<FF>,
+    variable: &'a str,
+    ascending: bool,
+    latex: bool,
+}
+
+impl<'a, FF: FiniteField> PolynomialDisplay<'a, FF> {
+    /// Use the given symbol for the polynomial's variable instead of `x`.
+    #[must_use]
+    pub fn with_variable(self, variable: &'a str) -> Self {
+        Self { variable, ..self }
+    }
+
+    /// List the monomials in order of ascending degree.
+    #[must_use]
+    pub fn ascending(self) -> Self {
+        Self {
+            ascending: true,
+            ..self
+        }
+    }
+
+    /// Use LaTeX math notation, _i.e._, `x^{12}` instead of `x^12`.
+    #[must_use]
+    pub fn latex(self) -> Self {
+        Self {
+            latex: true,
+            ..self
+        }
+    }
+}
+
+impl<FF: FiniteField> Display for PolynomialDisplay<'_, FF> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let degree = match self.polynomial.degree() {
             -1 => return write!(f, "0"),
             d => d as usize,
         };
 
-        for pow in (0..=degree).rev() {
-            let coeff = self.coefficients[pow];
+        let powers = (0..=degree).collect_vec();
+        let powers = match self.ascending {
+            true => powers,
+            false => powers.into_iter().rev().collect(),
+        };
+
+        let mut is_first_monomial = true;
+        for pow in powers {
+            let coeff = self.polynomial.coefficients[pow];
             if coeff.is_zero() {
                 continue;
             }
 
-            if pow != degree {
+            if !is_first_monomial {
                 write!(f, " + ")?;
             }
+            is_first_monomial = false;
+
             if !coeff.is_one() || pow == 0 {
                 write!(f, "{coeff}")?;
             }
-            match pow {
-                0 => (),
-                1 => write!(f, "x")?,
-                _ => write!(f, "x^{pow}")?,
+            let variable = self.variable;
+            match (pow, self.latex) {
+                (0, _) => (),
+                (1, _) => write!(f, "{variable}")?,
+                (_, false) => write!(f, "{variable}^{pow}")?,
+                (_, true) => write!(f, "{variable}^{{{pow}}}")?,
             }
         }
 
@@ -120,7 +243,7 @@ impl<FF: FiniteField> Eq for Polynomial<FF> {}
 
 impl<FF> Polynomial<FF>
 where
-    FF: FiniteField + MulAssign<BFieldElement>,
+    FF: FiniteField + MulAssign<BFieldElement> + 'static,
 {
     /// [Fast multiplication](Self::multiply) is slower than [naïve multiplication](Self::mul)
     /// for polynomials of degree less than this threshold.
@@ -167,6 +290,11 @@ where
 
     /// It is the caller's responsibility that this function is called with sufficiently large input
     /// to be safe and to be faster than `square`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the square is too large to be computed using NTTs. See also
+    /// [`checked_square`](Self::checked_square).
     #[must_use]
     pub fn fast_square(&self) -> Self {
         let degree = self.degree();
@@ -178,15 +306,10 @@ where
         }
 
         let result_degree: u64 = 2 * self.degree() as u64;
-        let order = (result_degree + 1).next_power_of_two();
-        let root_res = BFieldElement::primitive_root_of_unity(order);
-        let root = match root_res {
-            Some(n) => n,
-            None => panic!("Failed to find primitive root for order = {order}"),
-        };
+        let order = ntt_length_for_degree(result_degree as usize).unwrap_or_else(|e| panic!("{e}"));
+        let root = BFieldElement::primitive_root_of_unity(order as u64).unwrap();
 
-        let mut coefficients = self.coefficients.to_vec();
-        coefficients.resize(order as usize, FF::zero());
+        let mut coefficients = self.padded_coefficients(order);
         let log_2_of_n = coefficients.len().ilog2();
         ntt::<FF>(&mut coefficients, root, log_2_of_n);
 
@@ -200,6 +323,10 @@ where
         Polynomial { coefficients }
     }
 
+    /// # Panics
+    ///
+    /// Panics if the square is too large to be computed using NTTs. See also
+    /// [`checked_square`](Self::checked_square).
     #[must_use]
     pub fn square(&self) -> Self {
         let degree = self.degree();
@@ -211,8 +338,10 @@ where
         // size exceeds a length of 64.
         let squared_coefficient_len = self.degree() as usize * 2 + 1;
         if squared_coefficient_len > 64 {
+            record_strategy!(Square, Fast);
             return self.fast_square();
         }
+        record_strategy!(Square, Naive);
 
         let zero = FF::zero();
         let one = FF::one();
@@ -235,6 +364,19 @@ where
         }
     }
 
+    /// Like [`square`](Self::square), but returns an error instead of panicking if the square is
+    /// too large to be computed using NTTs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the degree of the square is not smaller than [`MAX_NTT_LENGTH`].
+    pub fn checked_square(&self) -> Result<Self, PolynomialError> {
+        if let Ok(degree) = usize::try_from(self.degree()) {
+            ntt_length_for_degree(2 * degree)?;
+        }
+        Ok(self.square())
+    }
+
     #[must_use]
     pub fn fast_mod_pow(&self, pow: BigInt) -> Self {
         let one = FF::one();
@@ -270,15 +412,44 @@ where
     ///
     /// Prefer this over [`self * other`](Self::mul) since it chooses the fastest multiplication
     /// strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product is too large to be computed using NTTs. See also
+    /// [`checked_multiply`](Self::checked_multiply).
     #[must_use]
     pub fn multiply(&self, other: &Self) -> Self {
         if self.degree() + other.degree() < Self::FAST_MULTIPLY_CUTOFF_THRESHOLD {
+            record_strategy!(Multiply, Naive);
             self.naive_multiply(other)
         } else {
+            record_strategy!(Multiply, Fast);
             self.fast_multiply(other)
         }
     }
 
+    /// Like [`multiply`](Self::multiply), but returns an error instead of panicking if the
+    /// product is too large to be computed using NTTs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the degree of the product is not smaller than [`MAX_NTT_LENGTH`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let a = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// let b = Polynomial::new(bfe_vec![4, 5]);
+    /// assert_eq!(Ok(a.multiply(&b)), a.checked_multiply(&b));
+    /// ```
+    pub fn checked_multiply(&self, other: &Self) -> Result<Self, PolynomialError> {
+        if let Ok(degree) = usize::try_from(self.degree() + other.degree()) {
+            ntt_length_for_degree(degree)?;
+        }
+        Ok(self.multiply(other))
+    }
+
     /// Use [Self::multiply] instead. Only `pub` to allow benchmarking; not considered part of the
     /// public API.
     ///
@@ -287,35 +458,80 @@ where
     ///
     /// The time complexity of this method is in O(n·log(n)), where `n` is the sum of the degrees
     /// of the operands. The time complexity of the naive multiplication is in O(n^2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product is too large to be computed using NTTs.
     #[doc(hidden)]
     pub fn fast_multiply(&self, other: &Self) -> Self {
         let Ok(degree) = usize::try_from(self.degree() + other.degree()) else {
             return Self::zero();
         };
-        let order = (degree + 1).next_power_of_two();
+        let order = ntt_length_for_degree(degree).unwrap_or_else(|e| panic!("{e}"));
         let order_u64 = u64::try_from(order).unwrap();
         let root = BFieldElement::primitive_root_of_unity(order_u64).unwrap();
 
-        let mut lhs_coefficients = self.coefficients.to_vec();
-        let mut rhs_coefficients = other.coefficients.to_vec();
-
-        lhs_coefficients.resize(order, FF::zero());
-        rhs_coefficients.resize(order, FF::zero());
+        let mut lhs_coefficients = self.padded_coefficients(order);
+        let mut rhs_coefficients = other.padded_coefficients(order);
 
         ntt::<FF>(&mut lhs_coefficients, root, order.ilog2());
         ntt::<FF>(&mut rhs_coefficients, root, order.ilog2());
 
-        let mut hadamard_product: Vec<FF> = rhs_coefficients
-            .into_iter()
-            .zip(lhs_coefficients)
-            .map(|(r, l)| r * l)
-            .collect();
+        // Re-use the buffer of the left-hand side for the Hadamard product.
+        for (l, &r) in lhs_coefficients.iter_mut().zip(&rhs_coefficients) {
+            *l *= r;
+        }
+        scratch::recycle(rhs_coefficients);
+        let mut hadamard_product = lhs_coefficients;
 
         intt::<FF>(&mut hadamard_product, root, order.ilog2());
         hadamard_product.truncate(degree + 1);
         Self::new(hadamard_product)
     }
 
+    /// Like [`fast_multiply`](Self::fast_multiply), but uses the precomputed twiddle factors of
+    /// the given [`NttPlan`]. Useful when computing many products of similar degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::ntt::NttPlan;
+    /// let plan = NttPlan::new(8).unwrap();
+    /// let a = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// let b = Polynomial::new(bfe_vec![4, 5, 6, 7]);
+    /// assert_eq!(a.multiply(&b), a.fast_multiply_with_plan(&b, &plan));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plan's length is smaller than or equal to the degree of the product.
+    pub fn fast_multiply_with_plan(&self, other: &Self, plan: &NttPlan) -> Self {
+        let Ok(degree) = usize::try_from(self.degree() + other.degree()) else {
+            return Self::zero();
+        };
+        assert!(
+            plan.len() > degree,
+            "The NTT plan's length must be greater than the degree of the product."
+        );
+
+        let mut lhs_coefficients = self.padded_coefficients(plan.len());
+        let mut rhs_coefficients = other.padded_coefficients(plan.len());
+        plan.ntt(&mut lhs_coefficients);
+        plan.ntt(&mut rhs_coefficients);
+
+        // Re-use the buffer of the left-hand side for the Hadamard product.
+        for (l, &r) in lhs_coefficients.iter_mut().zip(&rhs_coefficients) {
+            *l *= r;
+        }
+        scratch::recycle(rhs_coefficients);
+        let mut hadamard_product = lhs_coefficients;
+
+        plan.intt(&mut hadamard_product);
+        hadamard_product.truncate(degree + 1);
+        Self::new(hadamard_product)
+    }
+
     /// Compute the lowest degree polynomial with the provided roots.
     /// Also known as “vanishing polynomial.”
     ///
@@ -334,25 +550,42 @@ where
     /// assert!(zerofier.batch_evaluate(&non_roots).iter().all(|x| !x.is_zero()));
     /// ```
     pub fn zerofier(roots: &[FF]) -> Self {
+        let num_threads = config::current_num_threads();
         if roots.len() < Self::FAST_ZEROFIER_CUTOFF_THRESHOLD {
+            record_strategy!(Zerofier, Smart);
             Self::smart_zerofier(roots)
+        } else if roots.len() < Self::FAST_ZEROFIER_CUTOFF_THRESHOLD * num_threads {
+            record_strategy!(Zerofier, Parallel);
+            Self::par_zerofier(roots)
         } else {
+            record_strategy!(Zerofier, Fast);
             Self::fast_zerofier(roots)
         }
     }
 
+    /// Like [`zerofier`](Self::zerofier), but returns an error instead of panicking if the
+    /// zerofier is too large to be computed using NTTs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the degree of the zerofier, _i.e._, the number of roots, is not smaller
+    /// than [`MAX_NTT_LENGTH`]. The error is the same as the one
+    /// [`checked_multiply`](Self::checked_multiply) returns for products that are too large.
+    pub fn checked_zerofier(roots: &[FF]) -> Result<Self, PolynomialError> {
+        ntt_length_for_degree(roots.len())?;
+        Ok(Self::zerofier(roots))
+    }
+
     /// Only `pub` to allow benchmarking; not considered part of the public API.
     #[doc(hidden)]
     pub fn smart_zerofier(roots: &[FF]) -> Self {
         let mut zerofier = vec![FF::zero(); roots.len() + 1];
         zerofier[0] = FF::one();
-        let mut num_coeffs = 1;
-        for &root in roots {
+        for (num_coeffs, &root) in (1..).zip(roots) {
             for k in (1..=num_coeffs).rev() {
                 zerofier[k] = zerofier[k - 1] - root * zerofier[k];
             }
             zerofier[0] = -root * zerofier[0];
-            num_coeffs += 1;
         }
         Self::new(zerofier)
     }
@@ -361,12 +594,133 @@ where
     #[doc(hidden)]
     pub fn fast_zerofier(roots: &[FF]) -> Self {
         let mid_point = roots.len() / 2;
-        let (left, right) = rayon::join(
-            || Self::zerofier(&roots[..mid_point]),
-            || Self::zerofier(&roots[mid_point..]),
-        );
+        let (left, right) = config::install(|| {
+            rayon::join(
+                || Self::zerofier(&roots[..mid_point]),
+                || Self::zerofier(&roots[mid_point..]),
+            )
+        });
+
+        let zerofier = left.multiply(&right);
+        scratch::recycle(left.coefficients);
+        scratch::recycle(right.coefficients);
+        zerofier
+    }
+
+    /// The [zerofier](Self::zerofier) of the `roots`, computed by splitting the roots into one
+    /// block per thread of the [configured](crate::config) thread pool, computing the zerofier of
+    /// every block in parallel, and multiplying the results.
+    ///
+    /// The [fast zerofier](Self::fast_zerofier) only uses more than one thread once the number of
+    /// roots is at least twice the cutoff below which the [smart zerofier](Self::smart_zerofier) is
+    /// faster, and all threads only for much larger inputs. The parallel zerofier keeps all threads
+    /// busy if every thread's block is below that cutoff, which is why
+    /// [`zerofier`](Self::zerofier) dispatches to it for such inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let roots = bfe_vec![2, 4, 6, 8, 10];
+    /// assert_eq!(Polynomial::zerofier(&roots), Polynomial::par_zerofier(&roots));
+    /// ```
+    pub fn par_zerofier(roots: &[FF]) -> Self {
+        config::install(|| {
+            let num_threads = rayon::current_num_threads().max(1);
+            let block_size = roots.len().div_ceil(num_threads).max(1);
+            roots
+                .par_chunks(block_size)
+                .map(Self::zerofier)
+                .reduce(Self::one, |left, right| left.multiply(&right))
+        })
+    }
+
+    /// The evaluations of the [zerofier](Self::zerofier) of the `roots` on the coset domain
+    /// generated by `generator^i * offset`, skipping the coefficients of the complete zerofier.
+    ///
+    /// The roots are split into two halves. The zerofier of each half is [evaluated on the
+    /// coset](Self::chunked_fast_coset_evaluate), and the two evaluations are multiplied pointwise.
+    /// This replaces the last multiplication of the [fast zerofier](Self::fast_zerofier), which is
+    /// also its most expensive one, by a pointwise product, at the cost of one additional NTT of
+    /// length `order`. This pays off if the `order` is at most twice the number of roots; see
+    /// `cargo bench --bench zerofier`. Otherwise, as well as for few roots, the zerofier is
+    /// evaluated directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+    /// let roots = bfe_vec![2, 4, 6];
+    /// let offset = BFieldElement::generator();
+    /// let omega = BFieldElement::primitive_root_of_unity(4).unwrap();
+    ///
+    /// let evaluations = Polynomial::coset_zerofier_evaluations(&roots, offset, omega, 4);
+    /// let zerofier = Polynomial::zerofier(&roots);
+    /// assert_eq!(zerofier.fast_coset_evaluate(offset, omega, 4), evaluations);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `order` is not a power of two.
+    pub fn coset_zerofier_evaluations<S>(
+        roots: &[FF],
+        offset: S,
+        generator: BFieldElement,
+        order: usize,
+    ) -> Vec<FF>
+    where
+        S: Clone + One + Mul<Output = S> + ModPowU64 + Send + Sync,
+        FF: Mul<S, Output = FF>,
+    {
+        let evaluate = |some_roots: &[FF]| {
+            Self::zerofier(some_roots).chunked_fast_coset_evaluate(offset.clone(), generator, order)
+        };
+        if roots.len() < Self::FAST_ZEROFIER_CUTOFF_THRESHOLD || order > 2 * roots.len() {
+            return evaluate(roots);
+        }
+
+        let (left, right) = roots.split_at(roots.len() / 2);
+        let (mut evaluations, right_evaluations) =
+            rayon::join(|| evaluate(left), || evaluate(right));
+        for (evaluation, right_evaluation) in evaluations.iter_mut().zip(right_evaluations) {
+            *evaluation *= right_evaluation;
+        }
+        evaluations
+    }
+
+    /// A random polynomial that is divisible by the [zerofier](Self::zerofier) of the given
+    /// `roots`, _i.e._, that evaluates to zero on all of them.
+    ///
+    /// The result is the zerofier multiplied by a uniformly random polynomial of degree exactly
+    /// `extra_degree`. Consequently, the degree of the result is `roots.len() + extra_degree`.
+    /// Mainly useful for generating test cases for clean division and quotienting.
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let roots = bfe_vec![1, 2, 3];
+    /// let polynomial = Polynomial::random_with_roots(&roots, 4, &mut rand::thread_rng());
+    /// assert_eq!(7, polynomial.degree());
+    /// assert_eq!(bfe_vec![0, 0, 0], polynomial.batch_evaluate(&roots));
+    /// ```
+    pub fn random_with_roots<R: Rng + ?Sized>(
+        roots: &[FF],
+        extra_degree: usize,
+        rng: &mut R,
+    ) -> Self
+    where
+        Standard: Distribution<FF>,
+    {
+        let mut cofactor_coefficients: Vec<FF> = (0..extra_degree).map(|_| rng.gen()).collect();
+        let leading_coefficient = loop {
+            let candidate: FF = rng.gen();
+            if !candidate.is_zero() {
+                break candidate;
+            }
+        };
+        cofactor_coefficients.push(leading_coefficient);
 
-        left.multiply(&right)
+        Self::zerofier(roots).multiply(&Self::new(cofactor_coefficients))
     }
 
     /// Construct the lowest-degree polynomial interpolating the given points.
@@ -397,12 +751,107 @@ where
         );
 
         if domain.len() <= Self::FAST_INTERPOLATE_CUTOFF_THRESHOLD {
+            record_strategy!(Interpolate, Lagrange);
             Self::lagrange_interpolate(domain, values)
         } else {
+            record_strategy!(Interpolate, Fast);
             Self::fast_interpolate(domain, values)
         }
     }
 
+    /// Like [`interpolate`](Self::interpolate), but returns an error instead of panicking.
+    ///
+    /// Additionally, the domain is checked for duplicates. For a domain with duplicates,
+    /// [`interpolate`](Self::interpolate) neither panics nor returns a meaningful result.
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::error::PolynomialError;
+    /// let values = bfe_vec![1, 3, 5];
+    /// let err = Polynomial::checked_interpolate(&bfe_vec![0, 1, 0], &values).unwrap_err();
+    /// assert_eq!(PolynomialError::DuplicateDomainPoint(2), err);
+    ///
+    /// let polynomial = Polynomial::checked_interpolate(&bfe_vec![0, 1, 2], &values).unwrap();
+    /// assert_eq!(Polynomial::new(bfe_vec![1, 2]), polynomial);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the domain is empty.
+    /// - Returns an error if the domain and values are not of the same length.
+    /// - Returns an error if the domain contains duplicates.
+    /// - Returns an error if the domain is too large for the NTT-based interpolation.
+    pub fn checked_interpolate(domain: &[FF], values: &[FF]) -> Result<Self, PolynomialError> {
+        if domain.len() != values.len() {
+            return Err(PolynomialError::LengthMismatch(domain.len(), values.len()));
+        }
+        if domain.is_empty() {
+            return Err(PolynomialError::EmptyDomain);
+        }
+        let mut seen_points = HashSet::with_capacity(domain.len());
+        if let Some(index) = domain.iter().position(|point| !seen_points.insert(point)) {
+            return Err(PolynomialError::DuplicateDomainPoint(index));
+        }
+        // Interpolation computes the zerofier of the domain.
+        ntt_length_for_degree(domain.len())?;
+
+        Ok(Self::interpolate(domain, values))
+    }
+
+    /// The unique polynomial of degree `domain.len()` with the given `leading_coefficient` that
+    /// interpolates the given points. If the `leading_coefficient` is zero, this is the
+    /// [interpolant](Self::interpolate) of the points.
+    ///
+    /// Unlike [`interpolate`](Self::interpolate), the domain may be empty, in which case the
+    /// result is the constant polynomial `leading_coefficient`.
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let domain = bfe_vec![0, 1, 2];
+    /// let values = bfe_vec![5, 5, 5];
+    /// let polynomial = Polynomial::interpolate_with_leading_coefficient(&domain, &values, bfe!(2));
+    ///
+    /// assert_eq!(3, polynomial.degree());
+    /// assert_eq!(Some(bfe!(2)), polynomial.leading_coefficient());
+    /// assert_eq!(values, polynomial.batch_evaluate(&domain));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided domain and values are not of the same length.
+    pub fn interpolate_with_leading_coefficient(
+        domain: &[FF],
+        values: &[FF],
+        leading_coefficient: FF,
+    ) -> Self {
+        assert_eq!(
+            domain.len(),
+            values.len(),
+            "The domain and values lists have to be of equal length."
+        );
+        if domain.is_empty() {
+            return Self::from_constant(leading_coefficient);
+        }
+
+        // The zerofier has degree `domain.len()`, while the interpolant's degree is smaller. Adding
+        // a multiple of the zerofier does not change the values on the domain.
+        let zerofier = Self::zerofier(domain).scalar_mul(leading_coefficient);
+        zerofier + Self::interpolate(domain, values)
+    }
+
+    /// The unique monic polynomial of degree `domain.len()` that interpolates the given points.
+    ///
+    /// See also [`interpolate_with_leading_coefficient`][with_lc].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided domain and values are not of the same length.
+    ///
+    /// [with_lc]: Self::interpolate_with_leading_coefficient
+    pub fn interpolate_monic(domain: &[FF], values: &[FF]) -> Self {
+        Self::interpolate_with_leading_coefficient(domain, values, FF::one())
+    }
+
     /// Any fast interpolation will use NTT, so this is mainly used for testing/integrity
     /// purposes. This also means that it is not pivotal that this function has an optimal
     /// runtime.
@@ -512,7 +961,7 @@ where
 
     pub fn batch_fast_interpolate(
         domain: &[FF],
-        values_matrix: &Vec<Vec<FF>>,
+        values_matrix: &[Vec<FF>],
         primitive_root: BFieldElement,
         root_order: usize,
     ) -> Vec<Self> {
@@ -532,17 +981,21 @@ where
         let mut zerofier_dictionary: HashMap<(FF, FF), Polynomial<FF>> = HashMap::default();
         let mut offset_inverse_dictionary: HashMap<(FF, FF), Vec<FF>> = HashMap::default();
 
+        // Only the rows' slices are collected; copying the values is left to the targets of the
+        // first recursion step.
+        let rows = values_matrix.iter().map(Vec::as_slice).collect_vec();
         Self::batch_fast_interpolate_with_memoization(
             domain,
-            values_matrix,
+            &rows,
             &mut zerofier_dictionary,
             &mut offset_inverse_dictionary,
         )
     }
 
+    /// Interpolate every row of the values matrix over the `domain`.
     fn batch_fast_interpolate_with_memoization(
         domain: &[FF],
-        values_matrix: &Vec<Vec<FF>>,
+        rows: &[&[FF]],
         zerofier_dictionary: &mut HashMap<(FF, FF), Polynomial<FF>>,
         offset_inverse_dictionary: &mut HashMap<(FF, FF), Vec<FF>>,
     ) -> Vec<Self> {
@@ -550,7 +1003,7 @@ where
         // machine.
         const OPTIMAL_CUTOFF_POINT_FOR_BATCHED_INTERPOLATION: usize = 16;
         if domain.len() < OPTIMAL_CUTOFF_POINT_FOR_BATCHED_INTERPOLATION {
-            return values_matrix
+            return rows
                 .iter()
                 .map(|values| Self::lagrange_interpolate(domain, values))
                 .collect();
@@ -598,37 +1051,38 @@ where
         };
 
         // prepare target matrices
-        let all_left_targets: Vec<_> = values_matrix
+        let num_rows = rows.len();
+        let left_targets = rows
             .par_iter()
-            .map(|values| {
-                values[..half]
+            .flat_map_iter(|row| {
+                row[..half]
                     .iter()
                     .zip(left_offset_inverse.iter())
-                    .map(|(n, d)| n.to_owned() * *d)
-                    .collect()
+                    .map(|(&n, &d)| n * d)
             })
             .collect();
-        let all_right_targets: Vec<_> = values_matrix
+        let right_targets = rows
             .par_iter()
-            .map(|values| {
-                values[half..]
-                    .par_iter()
-                    .zip(right_offset_inverse.par_iter())
-                    .map(|(n, d)| n.to_owned() * *d)
-                    .collect()
+            .flat_map_iter(|row| {
+                row[half..]
+                    .iter()
+                    .zip(right_offset_inverse.iter())
+                    .map(|(&n, &d)| n * d)
             })
             .collect();
+        let all_left_targets = FieldMatrix::new(left_targets, num_rows, half);
+        let all_right_targets = FieldMatrix::new(right_targets, num_rows, domain.len() - half);
 
         // recurse
         let left_interpolants = Self::batch_fast_interpolate_with_memoization(
             &domain[..half],
-            &all_left_targets,
+            &all_left_targets.rows().collect_vec(),
             zerofier_dictionary,
             offset_inverse_dictionary,
         );
         let right_interpolants = Self::batch_fast_interpolate_with_memoization(
             &domain[half..],
-            &all_right_targets,
+            &all_right_targets.rows().collect_vec(),
             zerofier_dictionary,
             offset_inverse_dictionary,
         );
@@ -648,10 +1102,116 @@ where
         interpolants
     }
 
+    /// Change the basis of `self` from the monomial basis to the Lagrange basis over the given
+    /// `domain`. In other words, evaluate `self` on the `domain`, but keep track of the `domain`.
+    ///
+    /// The inverse of [`from_lagrange_basis`](Self::from_lagrange_basis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// let lagrange_form = polynomial.to_lagrange_basis(&bfe_vec![0, 1, 2]);
+    /// assert_eq!(&bfe_vec![1, 6, 17], lagrange_form.values());
+    /// assert_eq!(polynomial, Polynomial::from_lagrange_basis(&lagrange_form));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the `domain` contains duplicates.
+    /// - Panics if the degree of `self` is not smaller than the length of the `domain`, in which
+    ///   case `self` cannot be represented in the Lagrange basis over the `domain`.
+    pub fn to_lagrange_basis(&self, domain: &[FF]) -> LagrangeForm<FF> {
+        assert!(
+            self.degree() < domain.len() as isize,
+            "degree {} polynomial cannot be represented in the Lagrange basis over {} points",
+            self.degree(),
+            domain.len()
+        );
+        LagrangeForm::new(domain.to_vec(), self.batch_evaluate(domain))
+    }
+
+    /// Change the basis of the given polynomial from the Lagrange basis to the monomial basis.
+    /// In other words, interpolate the values over the domain.
+    ///
+    /// The inverse of [`to_lagrange_basis`](Self::to_lagrange_basis).
+    pub fn from_lagrange_basis(lagrange_form: &LagrangeForm<FF>) -> Self {
+        if lagrange_form.domain.is_empty() {
+            return Self::zero();
+        }
+        Self::interpolate(&lagrange_form.domain, &lagrange_form.values)
+    }
+
+    /// Evaluate `self` in every point of the `domain`. The points are distributed across threads
+    /// according to the [parallelism configuration](config).
     pub fn batch_evaluate(&self, domain: &[FF]) -> Vec<FF> {
         // According to `cargo bench --bench evaluation` on mjolnir, parallel evaluation is always
         // faster than fast evaluation.
-        domain.par_iter().map(|&p| self.evaluate(p)).collect()
+        if !config::should_parallelize(domain.len()) {
+            return domain.iter().map(|&p| self.evaluate(p)).collect();
+        }
+        config::install(|| domain.par_iter().map(|&p| self.evaluate(p)).collect())
+    }
+
+    /// Lazily evaluate `self` in every point of the `domain`, in order. Unlike
+    /// [`batch_evaluate`](Self::batch_evaluate), the domain does not need to be collected first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// let powers_of_two = std::iter::successors(Some(bfe!(1)), |&x| Some(x * bfe!(2)));
+    /// let values = polynomial.evaluate_iter(powers_of_two).take(3);
+    /// assert_eq!(bfe_vec![6, 17, 57], values.collect::<Vec<_>>());
+    /// ```
+    pub fn evaluate_iter<'a>(
+        &'a self,
+        domain: impl IntoIterator<Item = FF> + 'a,
+    ) -> impl Iterator<Item = FF> + 'a {
+        domain.into_iter().map(|p| self.evaluate(p))
+    }
+
+    /// The parallel counterpart of [`evaluate_iter`](Self::evaluate_iter). Collecting the result
+    /// preserves the order of the `domain`.
+    ///
+    /// The evaluation happens in whichever rayon thread pool collects the result, not in the one
+    /// [configured](crate::config) for this crate.
+    ///
+    /// To evaluate on a sequential iterator in parallel, bridge it using
+    /// [`par_bridge`](rayon::iter::ParallelBridge::par_bridge). Note that this loses the order.
+    pub fn par_evaluate_iter<'a>(
+        &'a self,
+        domain: impl IntoParallelIterator<Item = FF> + 'a,
+    ) -> impl ParallelIterator<Item = FF> + 'a {
+        domain.into_par_iter().map(|p| self.evaluate(p))
+    }
+
+    /// Like [`batch_evaluate`](Self::batch_evaluate), but only if the degree of `self` does not
+    /// exceed the `claimed_degree_bound`.
+    ///
+    /// Use this to make sure that a polynomial that is about to be committed to actually has the
+    /// degree the protocol assumes. To check the degree of an already computed codeword, see
+    /// [`estimate_degree`](crate::math::codeword::estimate_degree).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the degree of `self` is larger than the `claimed_degree_bound`.
+    pub fn evaluate_with_degree_bound(
+        &self,
+        domain: &[FF],
+        claimed_degree_bound: isize,
+    ) -> Result<Vec<FF>, PolynomialError> {
+        let degree = self.degree();
+        if degree > claimed_degree_bound {
+            return Err(PolynomialError::DegreeBoundExceeded(
+                degree,
+                claimed_degree_bound,
+            ));
+        }
+
+        Ok(self.batch_evaluate(domain))
     }
 
     /// Only `pub` to allow benchmarking; not considered part of the public API.
@@ -681,7 +1241,7 @@ where
                 let zerofier = Self::zerofier(half_domain);
                 let (_, zerofier_inverse, _) = Self::xgcd(zerofier.clone(), Self::zero());
                 let quotient = self.multiply(&zerofier_inverse);
-                let remainder = self.clone() - quotient.multiply(&zerofier);
+                let remainder = self - quotient.multiply(&zerofier);
                 remainder.batch_evaluate(half_domain)
             })
             .flatten()
@@ -690,6 +1250,9 @@ where
 
     /// Fast evaluate on a coset domain, which is the group generated by `generator^i * offset`.
     ///
+    /// The `order` of the domain can be a power of two, or of the form 3·2^k or 5·2^k; see
+    /// [`mixed_radix_ntt`] and [`mixed_radix_root_of_unity`].
+    ///
     /// # Performance
     ///
     /// If possible, use a [base field element](BFieldElement) as the offset.
@@ -697,7 +1260,10 @@ where
     /// # Panics
     ///
     /// Panics if the order of the domain generated by the `generator` is smaller than or equal to
-    /// the degree of `self`.
+    /// the degree of `self`. Use [`chunked_fast_coset_evaluate`](Self::chunked_fast_coset_evaluate)
+    /// for such domains.
+    ///
+    /// Panics if the `order` is not of one of the supported forms.
     pub fn fast_coset_evaluate<S>(
         &self,
         offset: S,
@@ -720,50 +1286,378 @@ where
             greater than the degree of the polynomial."
         );
 
-        let mut coefficients = self.scale(offset).coefficients;
-        coefficients.resize(order, FF::zero());
-
-        let log_2_of_n = coefficients.len().ilog2();
-        ntt::<FF>(&mut coefficients, generator, log_2_of_n);
+        let mut coefficients = self.padded_scaled_coefficients(offset, order);
+        mixed_radix_ntt::<FF>(&mut coefficients, generator);
         coefficients
     }
 
-    /// The inverse of [`Self::fast_coset_evaluate`].
+    /// Like [`fast_coset_evaluate`](Self::fast_coset_evaluate), but uses the precomputed twiddle
+    /// factors of the given [`NttPlan`]. The domain is generated by the [plan's
+    /// root of unity](NttPlan::omega) and has the [plan's length](NttPlan::len).
     ///
-    /// # Performance
+    /// # Examples
     ///
-    /// If possible, use a [base field element](BFieldElement) as the offset.
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::ntt::NttPlan;
+    /// let plan = NttPlan::new(8).unwrap();
+    /// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// let offset = BFieldElement::generator();
+    ///
+    /// let values = polynomial.fast_coset_evaluate_with_plan(offset, &plan);
+    /// assert_eq!(polynomial.fast_coset_evaluate(offset, plan.omega(), 8), values);
+    /// ```
     ///
     /// # Panics
     ///
-    /// Panics if the length of `values` does not equal the order of the domain generated by the
-    /// `generator`.
-    pub fn fast_coset_interpolate<S>(offset: S, generator: BFieldElement, values: &[FF]) -> Self
+    /// Panics if the plan's length is smaller than or equal to the degree of `self`.
+    pub fn fast_coset_evaluate_with_plan<S>(&self, offset: S, plan: &NttPlan) -> Vec<FF>
     where
-        S: Clone + One + Inverse,
+        S: Clone + One,
         FF: Mul<S, Output = FF>,
     {
-        let length = values.len();
-        let mut mut_values = values.to_vec();
-
-        intt(&mut mut_values, generator, length.ilog2());
-        let poly = Polynomial::new(mut_values);
+        assert!(
+            (plan.len() as isize) > self.degree(),
+            "`Polynomial::fast_coset_evaluate_with_plan` is currently limited to domains of order \
+            greater than the degree of the polynomial."
+        );
 
-        poly.scale(offset.inverse())
+        let mut coefficients = self.padded_scaled_coefficients(offset, plan.len());
+        plan.ntt(&mut coefficients);
+        coefficients
     }
 
-    /// Divide `self` by some `divisor`.
+    /// The low-degree extension of `self` by the factor `blowup`: the evaluations on the coset
+    /// domain `{offset·ω^i}` of the given `length`, where `ω` is a primitive `length`th root of
+    /// unity, and the degree of `self` is less than `length / blowup`.
     ///
-    /// # Panics
+    /// Like [`fast_coset_evaluate`](Self::fast_coset_evaluate), the NTT buffer is taken from the
+    /// [scratch pool](scratch) if it is enabled. Extending many polynomials to the same domain in
+    /// a loop and [recycling](scratch::recycle) every extension once it is no longer needed
+    /// therefore re-uses the same few buffers.
     ///
-    /// Panics if the `divisor` is zero.
-    pub fn divide(&self, divisor: &Self) -> Self {
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 2, 3, 4]);
+    /// let offset = BFieldElement::generator();
+    ///
+    /// let extension = polynomial.lde_evaluate(offset, 16, 4);
+    /// let omega = BFieldElement::primitive_root_of_unity(16).unwrap();
+    /// assert_eq!(polynomial.fast_coset_evaluate(offset, omega, 16), extension);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the `length` is not a power of two, or too large for an NTT.
+    /// - Panics if the `blowup` is zero or does not divide the `length`.
+    /// - Panics if the degree of `self` is not smaller than `length / blowup`.
+    pub fn lde_evaluate(&self, offset: BFieldElement, length: usize, blowup: usize) -> Vec<FF>
+    where
+        FF: Mul<BFieldElement, Output = FF>,
+    {
+        assert!(
+            length.is_power_of_two(),
+            "length {length} must be a power of two"
+        );
+        assert!(
+            blowup != 0 && length.is_multiple_of(blowup),
+            "blowup {blowup} must divide the length {length}"
+        );
+        let num_coefficients = length / blowup;
+        assert!(
+            self.degree() < num_coefficients as isize,
+            "polynomial of degree {} must have fewer than {num_coefficients} coefficients",
+            self.degree()
+        );
+
+        let generator = BFieldElement::primitive_root_of_unity(length as u64)
+            .expect("length should be small enough for an NTT");
+        self.fast_coset_evaluate(offset, generator, length)
+    }
+
+    /// [Fast evaluate](Self::fast_coset_evaluate) each of the `polynomials` on the same coset
+    /// domain.
+    ///
+    /// The powers of the `offset` are computed only once and shared among all polynomials, which
+    /// are then evaluated in parallel. For orders that are a power of two, the twiddle factors are
+    /// computed only once, too, and shared through one [`NttPlan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+    /// let polynomials = [
+    ///     Polynomial::new(bfe_vec![1, 2, 3]),
+    ///     Polynomial::new(bfe_vec![4, 5]),
+    /// ];
+    /// let offset = BFieldElement::generator();
+    /// let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+    ///
+    /// let codewords = Polynomial::batch_fast_coset_evaluate(&polynomials, offset, omega, 8);
+    /// assert_eq!(polynomials[1].fast_coset_evaluate(offset, omega, 8), codewords[1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the order of the domain generated by the `generator` is smaller than or equal to
+    /// the degree of any of the `polynomials`.
+    pub fn batch_fast_coset_evaluate<S>(
+        polynomials: &[Self],
+        offset: S,
+        generator: BFieldElement,
+        order: usize,
+    ) -> Vec<Vec<FF>>
+    where
+        S: Clone + One + Send + Sync,
+        FF: Mul<S, Output = FF>,
+    {
+        // See `fast_coset_evaluate` for why this limitation exists.
+        assert!(
+            polynomials.iter().all(|p| (order as isize) > p.degree()),
+            "`Polynomial::batch_fast_coset_evaluate` is currently limited to domains of order \
+            greater than the degree of the polynomials."
+        );
+
+        // Any coefficients beyond the domain's order are zero.
+        let num_powers = polynomials
+            .iter()
+            .map(|p| p.coefficients.len())
+            .max()
+            .unwrap_or(0)
+            .min(order);
+        let powers_of_offset =
+            std::iter::successors(Some(S::one()), |power| Some(power.clone() * offset.clone()))
+                .take(num_powers)
+                .collect_vec();
+
+        let plan = (order.is_power_of_two() && !polynomials.is_empty())
+            .then(|| NttPlan::with_omega(generator, order.ilog2()));
+
+        config::install(|| {
+            polynomials
+                .par_iter()
+                .map(|polynomial| {
+                    let mut coefficients = scratch::take(order);
+                    let scaled_coefficients = polynomial
+                        .coefficients
+                        .iter()
+                        .zip(&powers_of_offset)
+                        .map(|(&coefficient, power)| coefficient * power.clone());
+                    coefficients.extend(scaled_coefficients);
+                    coefficients.resize(order, FF::zero());
+                    match &plan {
+                        Some(plan) => plan.ntt(&mut coefficients),
+                        None => mixed_radix_ntt(&mut coefficients, generator),
+                    }
+                    coefficients
+                })
+                .collect()
+        })
+    }
+
+    /// Like [`fast_coset_evaluate`](Self::fast_coset_evaluate), but returns an error instead of
+    /// panicking or silently producing a wrong result if the domain is not suitable.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the `order` is not of one of the forms supported by
+    ///   [`fast_coset_evaluate`](Self::fast_coset_evaluate), or too large for an NTT.
+    /// - Returns an error if the `generator` does not generate the subgroup of the given `order`.
+    /// - Returns an error if the `order` is smaller than or equal to the degree of `self`.
+    pub fn checked_fast_coset_evaluate<S>(
+        &self,
+        offset: S,
+        generator: BFieldElement,
+        order: usize,
+    ) -> Result<Vec<FF>, PolynomialError>
+    where
+        S: Clone + One,
+        FF: Mul<S, Output = FF>,
+    {
+        // Only the checks of the order's form and size are needed, not the root of unity itself.
+        mixed_radix_root_of_unity(order)?;
+        if generator.is_zero() || generator.multiplicative_order() != order as u64 {
+            return Err(PolynomialError::InvalidGenerator(order));
+        }
+        if order as isize <= self.degree() {
+            return Err(PolynomialError::DomainTooSmall(self.degree(), order));
+        }
+
+        Ok(self.fast_coset_evaluate(offset, generator, order))
+    }
+
+    /// Like [`fast_coset_evaluate`](Self::fast_coset_evaluate), but for polynomials of any
+    /// degree, including those with a degree greater than or equal to the `order` of the domain.
+    ///
+    /// Every point `x` of the coset satisfies `x^order = offset^order`. Hence, `self` agrees with
+    /// its remainder modulo `x^order - offset^order` on the entire coset. That remainder is
+    /// computed by splitting the coefficients into chunks of length `order` and adding them up,
+    /// scaling the `j`th chunk by `offset^(order·j)`. The remainder is then evaluated using a
+    /// single NTT of length `order`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+    /// let polynomial = Polynomial::new(bfe_vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let offset = BFieldElement::generator();
+    /// let omega = BFieldElement::primitive_root_of_unity(4).unwrap();
+    /// let domain = (0..4).map(|i| offset * omega.mod_pow(i)).collect::<Vec<_>>();
+    ///
+    /// let values = polynomial.chunked_fast_coset_evaluate(offset, omega, 4);
+    /// assert_eq!(polynomial.batch_evaluate(&domain), values);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `order` is not a power of two.
+    pub fn chunked_fast_coset_evaluate<S>(
+        &self,
+        offset: S,
+        generator: BFieldElement,
+        order: usize,
+    ) -> Vec<FF>
+    where
+        S: Clone + One + Mul<Output = S> + ModPowU64,
+        FF: Mul<S, Output = FF>,
+    {
+        assert!(
+            order.is_power_of_two(),
+            "The order of the domain must be a power of two, but is {order}."
+        );
+        if (order as isize) > self.degree() {
+            return self.fast_coset_evaluate(offset, generator, order);
+        }
+
+        let wrap_around_factor = offset.mod_pow_u64(order as u64);
+        let mut power_of_wrap_around_factor = S::one();
+        let mut remainder_coefficients = vec![FF::zero(); order];
+        for chunk in self.coefficients.chunks(order) {
+            for (remainder_coefficient, &coefficient) in
+                remainder_coefficients.iter_mut().zip(chunk)
+            {
+                *remainder_coefficient += coefficient * power_of_wrap_around_factor.clone();
+            }
+            power_of_wrap_around_factor = power_of_wrap_around_factor * wrap_around_factor.clone();
+        }
+
+        Self::new(remainder_coefficients).fast_coset_evaluate(offset, generator, order)
+    }
+
+    /// The inverse of [`Self::fast_coset_evaluate`].
+    ///
+    /// # Performance
+    ///
+    /// If possible, use a [base field element](BFieldElement) as the offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `values` does not equal the order of the domain generated by the
+    /// `generator`, or if that length is not of a form supported by [`mixed_radix_ntt`].
+    pub fn fast_coset_interpolate<S>(offset: S, generator: BFieldElement, values: &[FF]) -> Self
+    where
+        S: Clone + One + Inverse,
+        FF: Mul<S, Output = FF>,
+    {
+        let mut coefficients = scratch::take(values.len());
+        coefficients.extend_from_slice(values);
+        mixed_radix_intt(&mut coefficients, generator);
+        Self::scale_in_place(&mut coefficients, offset.inverse());
+
+        Polynomial::new(coefficients)
+    }
+
+    /// The inverse of [`Self::fast_coset_evaluate_with_plan`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `values` does not equal the [plan's length](NttPlan::len).
+    pub fn fast_coset_interpolate_with_plan<S>(offset: S, plan: &NttPlan, values: &[FF]) -> Self
+    where
+        S: Clone + One + Inverse,
+        FF: Mul<S, Output = FF>,
+    {
+        let mut coefficients = scratch::take(values.len());
+        coefficients.extend_from_slice(values);
+        plan.intt(&mut coefficients);
+        Self::scale_in_place(&mut coefficients, offset.inverse());
+
+        Polynomial::new(coefficients)
+    }
+
+    /// Divide `self` by some `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `divisor` is zero.
+    pub fn divide(&self, divisor: &Self) -> Self {
         // According to `cargo bench --bench poly_div` on mjolnir, naive division is always faster
         // than fast division.
         let (quotient, _) = self.naive_divide(divisor);
         quotient
     }
 
+    /// Like [`divide`](Self::divide), but returns an error instead of panicking if the `divisor`
+    /// is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `divisor` is zero.
+    pub fn checked_divide(&self, divisor: &Self) -> Result<Self, PolynomialError> {
+        if divisor.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        Ok(self.divide(divisor))
+    }
+
+    /// The quotients `(f_i(x) - f_i(z)) / (x - z)` of all the `polynomials` `f_i` and the linear
+    /// polynomial `x - z`, as used in DEEP-style provers.
+    ///
+    /// Dividing by a linear polynomial only requires a single pass over the coefficients. The
+    /// values `f_i(z)` are not needed: they only affect the remainder, which is zero by
+    /// construction. For the same quotients in codeword form, see
+    /// [`codeword::batch_deep_quotients`](crate::math::codeword::batch_deep_quotients).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomials = [
+    ///     Polynomial::new(bfe_vec![1, 2, 3]),
+    ///     Polynomial::new(bfe_vec![4, 5]),
+    /// ];
+    /// let z = bfe!(7);
+    /// let quotients = Polynomial::batch_deep_quotients(&polynomials, z);
+    ///
+    /// let x_minus_z = Polynomial::new(vec![-z, bfe!(1)]);
+    /// let value = Polynomial::new(vec![polynomials[0].evaluate(z)]);
+    /// assert_eq!(polynomials[0].clone() - value, quotients[0].clone() * x_minus_z);
+    /// ```
+    pub fn batch_deep_quotients(polynomials: &[Self], z: FF) -> Vec<Self> {
+        polynomials
+            .par_iter()
+            .map(|polynomial| {
+                let num_coefficients = polynomial.coefficients.len();
+                let mut quotient = vec![FF::zero(); num_coefficients.saturating_sub(1)];
+                let mut accumulator = FF::zero();
+                for (quotient_coefficient, &coefficient) in quotient
+                    .iter_mut()
+                    .rev()
+                    .zip(polynomial.coefficients.iter().rev())
+                {
+                    accumulator = accumulator * z + coefficient;
+                    *quotient_coefficient = accumulator;
+                }
+                Self::new(quotient)
+            })
+            .collect()
+    }
+
     /// Polynomial long division with `self` as the dividend, divided by some `divisor`.
     /// Only `pub` to allow benchmarking; not considered part of the public API.
     ///
@@ -842,6 +1736,185 @@ where
         let num_coefficients_to_retain = n.min(self.coefficients.len());
         Self::new(self.coefficients[..num_coefficients_to_retain].into())
     }
+
+    /// `self^exponent mod modulus`
+    ///
+    /// Reduces after every step of the square-and-multiply loop, making the intermediate results
+    /// no larger than the modulus. In particular, the exponent may be huge without blowing up
+    /// the degree of any intermediate result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let x = Polynomial::new(bfe_vec![0, 1]);
+    /// let x_squared_plus_one = Polynomial::new(bfe_vec![1, 0, 1]);
+    /// let minus_one = Polynomial::new(bfe_vec![-1]);
+    /// assert_eq!(minus_one, x.pow_mod(2, &x_squared_plus_one));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `modulus` is zero.
+    pub fn pow_mod(&self, exponent: u64, modulus: &Self) -> Self {
+        let reduce = |poly: Self| {
+            let (_, mut remainder) = poly.naive_divide(modulus);
+            remainder.normalize();
+            remainder
+        };
+
+        let base = reduce(self.clone());
+        let mut acc = reduce(Self::one());
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            acc = reduce(acc.multiply(&acc));
+            if exponent & (1 << i) != 0 {
+                acc = reduce(acc.multiply(&base));
+            }
+        }
+
+        acc
+    }
+
+    /// The [companion matrix](https://en.wikipedia.org/wiki/Companion_matrix) of `self`, made monic.
+    ///
+    /// For a polynomial `xⁿ + c_{n-1}·xⁿ⁻¹ + … + c_0`, the `n`×`n` companion matrix has ones on the
+    /// sub-diagonal, the values `-c_0, …, -c_{n-1}` in the last column, and zeros everywhere else.
+    /// Applied to the coefficient vector of some polynomial `g` of degree less than `n`, it
+    /// produces the coefficient vector of `x·g mod self`. In particular, `self` is the
+    /// characteristic polynomial of its companion matrix, up to scaling. A constant polynomial has
+    /// a companion matrix of size 0×0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::matrix::FieldMatrix;
+    /// let polynomial = Polynomial::new(bfe_vec![3, 2, 1]); // x² + 2x + 3
+    /// let expected = FieldMatrix::new(bfe_vec![0, -3, 1, -2], 2, 2);
+    /// assert_eq!(expected, polynomial.companion_matrix());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is the zero polynomial.
+    pub fn companion_matrix(&self) -> FieldMatrix<FF> {
+        let leading_coefficient = self
+            .leading_coefficient()
+            .expect("the zero polynomial has no companion matrix");
+        let leading_coefficient_inv = leading_coefficient.inverse();
+        let n = self.degree() as usize;
+
+        let mut companion_matrix = FieldMatrix::zero(n, n);
+        for i in 0..n {
+            if i > 0 {
+                companion_matrix[(i, i - 1)] = FF::one();
+            }
+            companion_matrix[(i, n - 1)] = -self.coefficients[i] * leading_coefficient_inv;
+        }
+        companion_matrix
+    }
+
+    /// The `k`th term of the linear recurrence sequence with characteristic polynomial `self` and
+    /// the given `initial_values`.
+    ///
+    /// With `self` being `c_d·xᵈ + … + c_0`, the sequence `s` satisfies
+    /// `c_d·s_{i+d} + … + c_0·s_i = 0` for all `i`, and starts with the `d` given
+    /// `initial_values`. The `k`th term is computed through `xᵏ mod self`, which takes
+    /// O(d²·log(k)) operations instead of the O(d·k) operations required to unroll the
+    /// recurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let fibonacci = Polynomial::new(bfe_vec![-1, -1, 1]); // x² - x - 1
+    /// let initial_values = bfe_array![0, 1];
+    /// assert_eq!(bfe!(55), fibonacci.linear_recurrence_kth_term(&initial_values, 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is the zero polynomial, or if the number of `initial_values` does not match
+    /// the degree of `self`.
+    pub fn linear_recurrence_kth_term(&self, initial_values: &[FF], k: u64) -> FF {
+        assert!(!self.is_zero(), "the zero polynomial defines no recurrence");
+        assert_eq!(
+            self.degree() as usize,
+            initial_values.len(),
+            "the number of initial values must match the degree of the characteristic polynomial"
+        );
+
+        let x = Self::new(vec![FF::zero(), FF::one()]);
+        let x_to_the_k = x.pow_mod(k, self);
+        x_to_the_k
+            .coefficients
+            .iter()
+            .zip(initial_values)
+            .map(|(&c, &s)| c * s)
+            .fold(FF::zero(), |acc, term| acc + term)
+    }
+
+    /// The monic characteristic polynomial of the shortest linear recurrence generating the
+    /// `sequence`, found using the
+    /// [Berlekamp–Massey algorithm](https://en.wikipedia.org/wiki/Berlekamp%E2%80%93Massey_algorithm).
+    ///
+    /// The returned polynomial `m` of degree `d` satisfies
+    /// `m.linear_recurrence_kth_term(&sequence[..d], k) == sequence[k]` for all `k`. If the
+    /// `sequence` is generated by a recurrence of degree `d`, then `2·d` terms are sufficient to
+    /// recover it uniquely. The minimal polynomial of an all-zero sequence is the constant 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let fibonacci_numbers = bfe_vec![0, 1, 1, 2, 3, 5];
+    /// let minimal_polynomial = Polynomial::minimal_polynomial(&fibonacci_numbers);
+    /// assert_eq!(Polynomial::new(bfe_vec![-1, -1, 1]), minimal_polynomial); // x² - x - 1
+    /// ```
+    pub fn minimal_polynomial(sequence: &[FF]) -> Self {
+        // The connection polynomial `c` satisfies `Σ_i c_i·s_{n-i} = 0` for all `n ≥ length`.
+        let mut connection_polynomial = vec![FF::one()];
+        let mut previous_connection_polynomial = vec![FF::one()];
+        let mut length = 0;
+        let mut steps_since_length_change = 1;
+        let mut previous_discrepancy = FF::one();
+
+        for n in 0..sequence.len() {
+            let discrepancy = connection_polynomial
+                .iter()
+                .zip(sequence[..=n].iter().rev())
+                .fold(FF::zero(), |acc, (&c, &s)| acc + c * s);
+            if discrepancy.is_zero() {
+                steps_since_length_change += 1;
+                continue;
+            }
+
+            let correction_factor = discrepancy / previous_discrepancy;
+            let new_length = connection_polynomial
+                .len()
+                .max(previous_connection_polynomial.len() + steps_since_length_change);
+            let mut corrected_polynomial = connection_polynomial.clone();
+            corrected_polynomial.resize(new_length, FF::zero());
+            for (i, &c) in previous_connection_polynomial.iter().enumerate() {
+                corrected_polynomial[i + steps_since_length_change] -= correction_factor * c;
+            }
+
+            if 2 * length <= n {
+                length = n + 1 - length;
+                previous_connection_polynomial = connection_polynomial;
+                previous_discrepancy = discrepancy;
+                steps_since_length_change = 1;
+            } else {
+                steps_since_length_change += 1;
+            }
+            connection_polynomial = corrected_polynomial;
+        }
+
+        // The characteristic polynomial is the connection polynomial's reversal of degree `length`.
+        connection_polynomial.resize(length + 1, FF::zero());
+        connection_polynomial.reverse();
+        Self::new(connection_polynomial)
+    }
 }
 
 impl Polynomial<BFieldElement> {
@@ -872,49 +1945,384 @@ impl Polynomial<BFieldElement> {
     #[must_use]
     pub fn clean_divide(mut self, mut divisor: Self) -> Self {
         if divisor.degree() < Self::CLEAN_DIVIDE_CUTOFF_THRESHOLD {
+            record_strategy!(CleanDivide, Naive);
             return self.divide(&divisor);
         }
+        record_strategy!(CleanDivide, Fast);
+
+        // Incompleteness workaround: Manually check whether 0 is a root of the divisor.
+        // f(0) == 0 <=> f's constant term is 0
+        if divisor.coefficients.first().is_some_and(Zero::is_zero) {
+            // Clean division implies the dividend also has 0 as a root.
+            assert!(self.coefficients[0].is_zero());
+            self.coefficients.remove(0);
+            divisor.coefficients.remove(0);
+        }
+
+        // Incompleteness workaround: Move both dividend and divisor to an extension field.
+        let offset = XFieldElement::from([0, 1, 0]);
+        let mut dividend_coefficients = self.scale(offset).coefficients;
+        let mut divisor_coefficients = divisor.scale(offset).coefficients;
+
+        // See the comment in `fast_coset_evaluate` why this bound is necessary.
+        let dividend_deg_plus_1 = usize::try_from(self.degree() + 1).unwrap();
+        let order = dividend_deg_plus_1.next_power_of_two();
+        let order_u64 = u64::try_from(order).unwrap();
+        let root = BFieldElement::primitive_root_of_unity(order_u64).unwrap();
+
+        dividend_coefficients.resize(order, XFieldElement::zero());
+        divisor_coefficients.resize(order, XFieldElement::zero());
+
+        ntt(&mut dividend_coefficients, root, order.ilog2());
+        ntt(&mut divisor_coefficients, root, order.ilog2());
+
+        let divisor_inverses = XFieldElement::batch_inversion(divisor_coefficients);
+        let mut quotient_codeword = dividend_coefficients
+            .into_iter()
+            .zip(divisor_inverses)
+            .map(|(l, r)| l * r)
+            .collect_vec();
+
+        intt(&mut quotient_codeword, root, order.ilog2());
+        let quotient = Polynomial::new(quotient_codeword);
+
+        // If the division was clean, “unscaling” brings all coefficients back to the base field.
+        let quotient = quotient.scale(offset.inverse());
+        quotient
+            .try_unlift()
+            .expect("quotient of clean division should be in the base field")
+    }
+
+    /// Whether `self` is irreducible over the [base field](BFieldElement), _i.e._, whether it is
+    /// of degree at least 1 and cannot be written as the product of two polynomials of lower
+    /// degree.
+    ///
+    /// Uses Rabin's irreducibility test. The runtime is roughly cubic in the degree of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let shah_polynomial = XFieldElement::shah_polynomial();
+    /// assert!(shah_polynomial.is_irreducible());
+    ///
+    /// let x_squared_minus_one = Polynomial::new(bfe_vec![-1, 0, 1]);
+    /// assert!(!x_squared_minus_one.is_irreducible());
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        let Ok(degree) = usize::try_from(self.degree()) else {
+            return false;
+        };
+        if degree == 0 {
+            return false;
+        }
+        if degree == 1 {
+            return true;
+        }
+
+        let x = Self::new(bfe_vec![0, 1]);
+        let frobenius = |poly: &Self| poly.pow_mod(BFieldElement::P, self);
+
+        // `x^(p^i) mod self` for i in 0..=degree
+        let mut frobenius_powers_of_x = vec![x.clone()];
+        for i in 0..degree {
+            let next = frobenius(&frobenius_powers_of_x[i]);
+            frobenius_powers_of_x.push(next);
+        }
+
+        // `self` divides `x^(p^degree) - x` if and only if all its irreducible factors have a
+        // degree dividing `degree`.
+        if frobenius_powers_of_x[degree] != x.clone() % self.clone() {
+            return false;
+        }
+
+        // Those factors must all be of degree `degree`, _i.e._, no factor of `self` may be shared
+        // with `x^(p^(degree / q)) - x` for any prime factor `q` of `degree`.
+        prime_factors(degree).into_iter().all(|q| {
+            let candidate = &frobenius_powers_of_x[degree / q] - &x;
+            let (gcd, _, _) = Polynomial::xgcd(self.clone(), candidate);
+            gcd.is_one()
+        })
+    }
+
+    /// A uniformly random, monic, [irreducible](Self::is_irreducible) polynomial of the given
+    /// degree.
+    ///
+    /// Roughly one in `degree` many monic polynomials is irreducible, which is the expected number
+    /// of candidates this method samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree is 0; no polynomial of degree 0 is irreducible.
+    pub fn random_irreducible<R: Rng + ?Sized>(degree: usize, rng: &mut R) -> Self {
+        assert_ne!(
+            0, degree,
+            "irreducible polynomials must have degree at least 1"
+        );
+        loop {
+            let mut coefficients: Vec<BFieldElement> = (0..degree).map(|_| rng.gen()).collect();
+            coefficients.push(BFieldElement::one());
+            let candidate = Self::new(coefficients);
+            if candidate.is_irreducible() {
+                return candidate;
+            }
+        }
+    }
+
+    /// The monic polynomial that has the same irreducible factors as `self`, but each with
+    /// multiplicity one. The square-free part of a non-zero constant polynomial is one, the
+    /// square-free part of the zero polynomial is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::zerofier(&bfe_array![1, 1, 1, 2]).scalar_mul(bfe!(5));
+    /// let expected = Polynomial::zerofier(&bfe_array![1, 2]);
+    /// assert_eq!(expected, polynomial.square_free_part());
+    /// ```
+    pub fn square_free_part(&self) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+
+        // The degree of any polynomial that fits into memory is smaller than the field's
+        // characteristic. Hence, the derivative of a non-constant polynomial is non-zero, and the
+        // repeated factors of `self` are exactly those it shares with its derivative.
+        let (repeated_factors, _, _) = Self::xgcd(self.clone(), self.formal_derivative());
+        let (square_free_part, _) = self.naive_divide(&repeated_factors);
+        let leading_coefficient = square_free_part.leading_coefficient().unwrap();
+        square_free_part.scalar_mul(leading_coefficient.inverse())
+    }
+
+    /// All distinct roots of `self` in the [base field](BFieldElement), in ascending order.
+    ///
+    /// The roots are found by extracting the product of all linear factors as
+    /// `gcd(self, x^p - x)`, which is then split using equal-degree factorization. This is mainly
+    /// useful for debugging, for example to find out where a composition polynomial vanishes.
+    /// The runtime is roughly quadratic in the degree of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// // (x² - 7)·(x - 3)²·(x - 2), where x² - 7 has no roots because 7 is not a square
+    /// let x_squared_minus_seven = Polynomial::new(bfe_vec![-7, 0, 1]);
+    /// let polynomial = x_squared_minus_seven * Polynomial::zerofier(&bfe_array![3, 3, 2]);
+    /// assert_eq!(bfe_vec![2, 3], polynomial.roots());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is the zero polynomial, of which every field element is a root.
+    pub fn roots(&self) -> Vec<BFieldElement> {
+        assert!(!self.is_zero(), "the zero polynomial has all roots");
+        if self.degree() == 0 {
+            return vec![];
+        }
+
+        let x = Self::new(bfe_vec![0, 1]);
+        let x_to_the_p_minus_x = x.pow_mod(BFieldElement::P, self) - x;
+        let (linear_factors, _, _) = Self::xgcd(self.clone(), x_to_the_p_minus_x);
+
+        let mut roots = vec![];
+        let mut unsplit = vec![linear_factors];
+        let mut shift = BFieldElement::zero();
+        while let Some(factor) = unsplit.pop() {
+            match factor.degree() {
+                0 => continue,
+                1 => {
+                    roots.push(-factor.coefficients[0] / factor.coefficients[1]);
+                    continue;
+                }
+                _ => (),
+            }
+
+            // For every root r, (r + shift)^((p-1)/2) is 1 for roughly half of all shifts, and -1
+            // for the other half. The gcd below collects the former roots, splitting the factor.
+            let x_plus_shift = Self::new(vec![shift, BFieldElement::one()]);
+            let exponent = (BFieldElement::P - 1) / 2;
+            let candidate = x_plus_shift.pow_mod(exponent, &factor) - Self::one();
+            let (split, _, _) = Self::xgcd(factor.clone(), candidate);
+            shift.increment();
+
+            if split.degree() <= 0 || split.degree() == factor.degree() {
+                unsplit.push(factor);
+                continue;
+            }
+
+            let (cofactor, _) = factor.naive_divide(&split);
+            unsplit.push(split);
+            unsplit.push(cofactor);
+        }
+
+        roots.sort_by_key(|root| root.value());
+        roots
+    }
+}
+
+/// The length of the NTT needed to compute a polynomial of the given degree, _i.e._, the smallest
+/// power of two greater than the degree.
+fn ntt_length_for_degree(degree: usize) -> Result<usize, NttError> {
+    let num_coefficients = degree.saturating_add(1);
+    match num_coefficients.checked_next_power_of_two() {
+        Some(length) if length <= MAX_NTT_LENGTH => Ok(length),
+        Some(length) => Err(NttError::DomainTooLarge(length)),
+        None => Err(NttError::DomainTooLarge(num_coefficients)),
+    }
+}
+
+/// The distinct prime factors of `n`, in ascending order.
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = vec![];
+    let mut candidate = 2;
+    while candidate * candidate <= n {
+        if n.is_multiple_of(candidate) {
+            factors.push(candidate);
+            while n.is_multiple_of(candidate) {
+                n /= candidate;
+            }
+        }
+        candidate += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+impl Polynomial<XFieldElement> {
+    /// Whether all coefficients of `self` are elements of the [base field](BFieldElement).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(xfe_vec![1, 2, 3]);
+    /// assert!(polynomial.is_in_base_field());
+    ///
+    /// let polynomial = Polynomial::new(vec![xfe!(1), xfe!([0, 1, 0])]);
+    /// assert!(!polynomial.is_in_base_field());
+    /// ```
+    pub fn is_in_base_field(&self) -> bool {
+        self.coefficients.iter().all(|c| c.unlift().is_some())
+    }
+
+    /// The polynomial over the [base field](BFieldElement) with the same coefficients as `self`,
+    /// if all coefficients of `self` are elements of the base field. Otherwise, `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(xfe_vec![1, 2, 3]);
+    /// let expected = Polynomial::new(bfe_vec![1, 2, 3]);
+    /// assert_eq!(Some(expected), polynomial.try_unlift());
+    /// ```
+    pub fn try_unlift(&self) -> Option<Polynomial<BFieldElement>> {
+        let coefficients: Option<_> = self.coefficients.iter().map(|c| c.unlift()).collect();
+        coefficients.map(Polynomial::new)
+    }
+
+    /// Split `self` into its coordinate polynomials over the [base field](BFieldElement). The
+    /// `i`th coordinate polynomial's coefficients are the `i`th coefficients of the
+    /// [extension field elements](XFieldElement) that are the coefficients of `self`.
+    ///
+    /// This is useful for processing a polynomial over the extension field coordinate-wise, for
+    /// example using base field NTTs. The inverse of
+    /// [`from_coordinate_polynomials`](Self::from_coordinate_polynomials).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let polynomial = Polynomial::new(vec![xfe!([1, 2, 3]), xfe!([4, 5, 6])]);
+    /// let [p0, p1, p2] = polynomial.clone().into_coordinate_polynomials();
+    /// assert_eq!(Polynomial::new(bfe_vec![1, 4]), p0);
+    /// assert_eq!(Polynomial::new(bfe_vec![2, 5]), p1);
+    /// assert_eq!(Polynomial::new(bfe_vec![3, 6]), p2);
+    ///
+    /// let reassembled = Polynomial::from_coordinate_polynomials([p0, p1, p2]);
+    /// assert_eq!(polynomial, reassembled);
+    /// ```
+    pub fn into_coordinate_polynomials(self) -> [Polynomial<BFieldElement>; EXTENSION_DEGREE] {
+        std::array::from_fn(|i| {
+            let coordinates = self.coefficients.iter().map(|c| c.coefficients[i]);
+            Polynomial::new(coordinates.collect())
+        })
+    }
 
-        // Incompleteness workaround: Manually check whether 0 is a root of the divisor.
-        // f(0) == 0 <=> f's constant term is 0
-        if divisor.coefficients.first().is_some_and(Zero::is_zero) {
-            // Clean division implies the dividend also has 0 as a root.
-            assert!(self.coefficients[0].is_zero());
-            self.coefficients.remove(0);
-            divisor.coefficients.remove(0);
-        }
+    /// Assemble a polynomial over the extension field from its coordinate polynomials over the
+    /// [base field](BFieldElement). The coordinate polynomials may be of different degrees.
+    ///
+    /// The inverse of [`into_coordinate_polynomials`](Self::into_coordinate_polynomials).
+    pub fn from_coordinate_polynomials(
+        coordinate_polynomials: [Polynomial<BFieldElement>; EXTENSION_DEGREE],
+    ) -> Self {
+        let num_coefficients = coordinate_polynomials
+            .iter()
+            .map(|p| p.coefficients.len())
+            .max()
+            .unwrap_or(0);
+        let coordinate = |p: &Polynomial<BFieldElement>, j| {
+            p.coefficients
+                .get(j)
+                .copied()
+                .unwrap_or_else(BFieldElement::zero)
+        };
+        let coefficients = (0..num_coefficients)
+            .map(|j| {
+                let coordinates = coordinate_polynomials.each_ref().map(|p| coordinate(p, j));
+                XFieldElement::new(coordinates)
+            })
+            .collect();
 
-        // Incompleteness workaround: Move both dividend and divisor to an extension field.
-        let offset = XFieldElement::from([0, 1, 0]);
-        let mut dividend_coefficients = self.scale(offset).coefficients;
-        let mut divisor_coefficients = divisor.scale(offset).coefficients;
+        Self::new(coefficients)
+    }
+}
 
-        // See the comment in `fast_coset_evaluate` why this bound is necessary.
-        let dividend_deg_plus_1 = usize::try_from(self.degree() + 1).unwrap();
-        let order = dividend_deg_plus_1.next_power_of_two();
-        let order_u64 = u64::try_from(order).unwrap();
-        let root = BFieldElement::primitive_root_of_unity(order_u64).unwrap();
+/// A polynomial in the Lagrange basis over some fixed domain, _i.e._, the polynomial's values on
+/// the domain, together with that domain. In contrast to a plain `Vec<FF>`, this type cannot be
+/// confused with the coefficients of a [`Polynomial`], which are with respect to the monomial
+/// basis.
+///
+/// Convert from and to the monomial basis using [`Polynomial::to_lagrange_basis`] and
+/// [`Polynomial::from_lagrange_basis`], respectively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeForm<FF: FiniteField> {
+    domain: Vec<FF>,
+    values: Vec<FF>,
+}
 
-        dividend_coefficients.resize(order, XFieldElement::zero());
-        divisor_coefficients.resize(order, XFieldElement::zero());
+impl<FF: FiniteField> LagrangeForm<FF> {
+    /// # Panics
+    ///
+    /// - Panics if the `domain` and `values` are not of the same length.
+    /// - Panics if the `domain` contains duplicates.
+    pub fn new(domain: Vec<FF>, values: Vec<FF>) -> Self {
+        assert_eq!(
+            domain.len(),
+            values.len(),
+            "The domain and values lists have to be of equal length."
+        );
+        assert!(
+            domain.iter().all_unique(),
+            "The domain must not contain duplicates."
+        );
 
-        ntt(&mut dividend_coefficients, root, order.ilog2());
-        ntt(&mut divisor_coefficients, root, order.ilog2());
+        Self { domain, values }
+    }
 
-        let divisor_inverses = XFieldElement::batch_inversion(divisor_coefficients);
-        let mut quotient_codeword = dividend_coefficients
-            .into_iter()
-            .zip(divisor_inverses)
-            .map(|(l, r)| l * r)
-            .collect_vec();
+    pub fn domain(&self) -> &[FF] {
+        &self.domain
+    }
 
-        intt(&mut quotient_codeword, root, order.ilog2());
-        let quotient = Polynomial::new(quotient_codeword);
+    pub fn values(&self) -> &[FF] {
+        &self.values
+    }
 
-        // If the division was clean, “unscaling” brings all coefficients back to the base field.
-        let quotient = quotient.scale(offset.inverse());
-        let coeffs = quotient.coefficients.into_iter();
-        coeffs.map(|c| c.unlift().unwrap()).collect_vec().into()
+    pub fn into_values(self) -> Vec<FF> {
+        self.values
     }
 }
 
@@ -1009,6 +2417,17 @@ impl<FF: FiniteField> Polynomial<FF> {
         }
     }
 
+    /// Like [`leading_coefficient`](Self::leading_coefficient), but returns an error for the zero
+    /// polynomial, making it consistent with the other `checked_` methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self` is the zero polynomial.
+    pub fn checked_leading_coefficient(&self) -> Result<FF, PolynomialError> {
+        self.leading_coefficient()
+            .ok_or(PolynomialError::ZeroPolynomial)
+    }
+
     pub fn are_colinear_3(p0: (FF, FF), p1: (FF, FF), p2: (FF, FF)) -> bool {
         if p0.0 == p1.0 || p1.0 == p2.0 || p2.0 == p0.0 {
             return false;
@@ -1133,7 +2552,7 @@ impl<FF: FiniteField> Polynomial<FF> {
             let set: bool =
                 !(pow.clone() & Into::<BigInt>::into(1u128 << (bit_length - 1 - i))).is_zero();
             if set {
-                acc = acc * self.clone();
+                acc = acc * self;
             }
         }
 
@@ -1144,6 +2563,46 @@ impl<FF: FiniteField> Polynomial<FF> {
         self.coefficients.splice(0..0, vec![zero; power]);
     }
 
+    /// The coefficients of `self`, padded with zeros or truncated to the given `length`. Unlike
+    /// cloning and resizing, this allocates at most once, taking the buffer from the
+    /// [scratch pool](scratch) if possible.
+    fn padded_coefficients(&self, length: usize) -> Vec<FF>
+    where
+        FF: 'static,
+    {
+        let num_kept_coefficients = self.coefficients.len().min(length);
+        let mut padded_coefficients = scratch::take(length);
+        padded_coefficients.extend_from_slice(&self.coefficients[..num_kept_coefficients]);
+        padded_coefficients.resize(length, FF::zero());
+        padded_coefficients
+    }
+
+    /// Like [`padded_coefficients`](Self::padded_coefficients), but every coefficient is
+    /// [scaled](Self::scale) by the corresponding power of `alpha`.
+    fn padded_scaled_coefficients<S>(&self, alpha: S, length: usize) -> Vec<FF>
+    where
+        S: Clone + One,
+        FF: Mul<S, Output = FF> + 'static,
+    {
+        let mut padded_coefficients = self.padded_coefficients(length);
+        let num_kept_coefficients = self.coefficients.len().min(length);
+        Self::scale_in_place(&mut padded_coefficients[..num_kept_coefficients], alpha);
+        padded_coefficients
+    }
+
+    /// Multiply the `i`th of the `coefficients` by `alpha^i`. See also [`scale`](Self::scale).
+    fn scale_in_place<S>(coefficients: &mut [FF], alpha: S)
+    where
+        S: Clone + One,
+        FF: Mul<S, Output = FF>,
+    {
+        let mut power_of_alpha = S::one();
+        for coefficient in coefficients {
+            *coefficient = *coefficient * power_of_alpha.clone();
+            power_of_alpha = power_of_alpha * alpha.clone();
+        }
+    }
+
     /// Multiply a polynomial with x^power
     #[must_use]
     pub fn shift_coefficients(&self, power: usize) -> Self {
@@ -1194,49 +2653,63 @@ impl<FF: FiniteField> Polynomial<FF> {
     /// Only `pub` to allow benchmarking; not considered part of the public API.
     #[doc(hidden)]
     pub fn naive_divide(&self, divisor: &Self) -> (Self, Self) {
-        let divisor_lc_inv = divisor
-            .leading_coefficient()
-            .expect("divisor should be non-zero")
-            .inverse();
+        self.clone().divide_in_place(divisor)
+    }
 
-        let Ok(quotient_degree) = usize::try_from(self.degree() - divisor.degree()) else {
-            // self.degree() < divisor.degree()
-            return (Self::zero(), self.to_owned());
+    /// Polynomial long division with `self` as the dividend, divided by some `divisor`. Returns
+    /// (quotient, remainder).
+    ///
+    /// Like [`naive_divide`](Self::naive_divide) but consumes `self`, re-using its coefficient
+    /// buffer for the remainder. This saves an allocation if the dividend is not needed anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let dividend = Polynomial::new(bfe_vec![1, 2, 3, 4]);
+    /// let divisor = Polynomial::new(bfe_vec![1, 1]);
+    /// let (quotient, remainder) = dividend.clone().divide_in_place(&divisor);
+    /// assert_eq!(dividend, quotient * divisor + remainder);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `divisor` is zero.
+    pub fn divide_in_place(mut self, divisor: &Self) -> (Self, Self) {
+        let Ok(divisor_degree) = usize::try_from(divisor.degree()) else {
+            panic!("divisor should be non-zero");
+        };
+        let Ok(dividend_degree) = usize::try_from(self.degree()) else {
+            return (Self::zero(), self);
+        };
+        let Some(quotient_degree) = dividend_degree.checked_sub(divisor_degree) else {
+            return (Self::zero(), self);
         };
-        debug_assert!(!self.is_zero());
-
-        // quotient is built from back to front, must be reversed later
-        let mut rev_quotient = Vec::with_capacity(quotient_degree + 1);
-        let mut remainder = self.clone();
-        remainder.normalize();
-
-        // The divisor is also iterated back to front.
-        // It is normalized manually to avoid it being a `&mut` argument.
-        let rev_divisor = divisor.coefficients.iter().rev();
-        let normal_rev_divisor = rev_divisor.skip_while(|c| c.is_zero());
-
-        for _ in 0..=quotient_degree {
-            let remainder_lc = remainder.coefficients.pop().unwrap();
-            let quotient_coeff = remainder_lc * divisor_lc_inv;
-            rev_quotient.push(quotient_coeff);
 
+        // Working on the normalized slices allows addressing coefficients by index windows.
+        let (&divisor_lc, divisor_tail) = divisor.coefficients[..=divisor_degree]
+            .split_last()
+            .unwrap();
+        let divisor_lc_inv = divisor_lc.inverse();
+        let remainder = &mut self.coefficients;
+        remainder.truncate(dividend_degree + 1);
+
+        let mut quotient = vec![FF::zero(); quotient_degree + 1];
+        for (i, quotient_coeff) in quotient.iter_mut().enumerate().rev() {
+            *quotient_coeff = remainder[i + divisor_degree] * divisor_lc_inv;
             if quotient_coeff.is_zero() {
                 continue;
             }
 
-            // don't use `.degree()` to still count leading zeros in intermittent remainders
-            let remainder_degree = remainder.coefficients.len().saturating_sub(1);
-
-            // skip divisor's leading coefficient: it has already been dealt with
-            for (i, &divisor_coeff) in normal_rev_divisor.clone().skip(1).enumerate() {
-                remainder.coefficients[remainder_degree - i] -= quotient_coeff * divisor_coeff;
+            // the divisor's leading coefficient is skipped: it has already been dealt with
+            let remainder_window = &mut remainder[i..i + divisor_degree];
+            for (remainder_coeff, &divisor_coeff) in remainder_window.iter_mut().zip(divisor_tail) {
+                *remainder_coeff -= *quotient_coeff * divisor_coeff;
             }
         }
+        remainder.truncate(divisor_degree);
 
-        rev_quotient.reverse();
-        let quotient = Self::new(rev_quotient);
-
-        (quotient, remainder)
+        (Self::new(quotient), self)
     }
 }
 
@@ -1313,6 +2786,72 @@ impl<FF: FiniteField> Sub for Polynomial<FF> {
     }
 }
 
+impl<FF: FiniteField> Add<&Polynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn add(mut self, other: &Self) -> Self {
+        let num_common_coefficients = self.coefficients.len().min(other.coefficients.len());
+        for (l, &r) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *l += r;
+        }
+        self.coefficients
+            .extend_from_slice(&other.coefficients[num_common_coefficients..]);
+        self
+    }
+}
+
+impl<FF: FiniteField> Add<Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn add(self, other: Polynomial<FF>) -> Polynomial<FF> {
+        other + self
+    }
+}
+
+impl<FF: FiniteField> Add<&Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn add(self, other: &Polynomial<FF>) -> Polynomial<FF> {
+        let (longer, shorter) = if self.coefficients.len() >= other.coefficients.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        longer.clone() + shorter
+    }
+}
+
+impl<FF: FiniteField> Sub<&Polynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn sub(mut self, other: &Self) -> Self {
+        let num_common_coefficients = self.coefficients.len().min(other.coefficients.len());
+        for (l, &r) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *l -= r;
+        }
+        let remaining_coefficients = &other.coefficients[num_common_coefficients..];
+        self.coefficients
+            .extend(remaining_coefficients.iter().map(|&r| -r));
+        self
+    }
+}
+
+impl<FF: FiniteField> Sub<Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn sub(self, other: Polynomial<FF>) -> Polynomial<FF> {
+        -other + self
+    }
+}
+
+impl<FF: FiniteField> Sub<&Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn sub(self, other: &Polynomial<FF>) -> Polynomial<FF> {
+        self.clone() - other
+    }
+}
+
 impl<FF: FiniteField> Polynomial<FF> {
     /// Extended Euclidean algorithm with polynomials. Computes the greatest
     /// common divisor `gcd` as a monic polynomial, as well as the corresponding
@@ -1334,8 +2873,8 @@ impl<FF: FiniteField> Polynomial<FF> {
 
         while !y.is_zero() {
             let (quotient, remainder) = x.naive_divide(&y);
-            let c = a_factor - quotient.clone() * a1.clone();
-            let d = b_factor - quotient * b1.clone();
+            let c = a_factor - &quotient * &a1;
+            let d = b_factor - quotient * &b1;
 
             x = y;
             y = remainder;
@@ -1387,6 +2926,30 @@ impl<FF: FiniteField> Mul for Polynomial<FF> {
     }
 }
 
+impl<FF: FiniteField> Mul<&Polynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self {
+        self.naive_multiply(other)
+    }
+}
+
+impl<FF: FiniteField> Mul<Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn mul(self, other: Polynomial<FF>) -> Polynomial<FF> {
+        self.naive_multiply(&other)
+    }
+}
+
+impl<FF: FiniteField> Mul<&Polynomial<FF>> for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn mul(self, other: &Polynomial<FF>) -> Polynomial<FF> {
+        self.naive_multiply(other)
+    }
+}
+
 impl<FF: FiniteField> Neg for Polynomial<FF> {
     type Output = Self;
 
@@ -1396,12 +2959,22 @@ impl<FF: FiniteField> Neg for Polynomial<FF> {
     }
 }
 
+impl<FF: FiniteField> Neg for &Polynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn neg(self) -> Polynomial<FF> {
+        -self.clone()
+    }
+}
+
 #[cfg(test)]
 mod test_polynomials {
     use proptest::collection::size_range;
     use proptest::collection::vec;
     use proptest::prelude::*;
     use proptest_arbitrary_interop::arb;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use test_strategy::proptest;
 
     use crate::prelude::*;
@@ -1451,6 +3024,42 @@ mod test_polynomials {
         assert_eq!("2x^4 + 1", polynomial(&[1, 0, 0, 0, 2]).to_string());
     }
 
+    #[test]
+    fn configurable_polynomial_display_test() {
+        let polynomial = |cs: &[u64]| Polynomial::<BFieldElement>::from(cs);
+
+        let ascending = |p: Polynomial<_>| p.display().ascending().to_string();
+        assert_eq!("0", ascending(polynomial(&[])));
+        assert_eq!("3", ascending(polynomial(&[3, 0])));
+        assert_eq!("2 + 5x", ascending(polynomial(&[2, 5])));
+        assert_eq!("3x^3 + 4x^4", ascending(polynomial(&[0, 0, 0, 3, 4])));
+
+        let p = polynomial(&[1, 0, 0, 0, 2]);
+        assert_eq!("2y^4 + 1", p.display().with_variable("y").to_string());
+        assert_eq!(
+            "2\\alpha^{4} + 1",
+            p.display().latex().with_variable("\\alpha").to_string()
+        );
+        assert_eq!("1 + 2x^{4}", p.display().latex().ascending().to_string());
+    }
+
+    #[proptest]
+    fn default_configurable_display_is_display(polynomial: Polynomial<BFieldElement>) {
+        prop_assert_eq!(polynomial.to_string(), polynomial.display().to_string());
+    }
+
+    #[test]
+    fn polynomial_latex_test() {
+        let polynomial = |cs: &[u64]| Polynomial::<BFieldElement>::from(cs);
+
+        assert_eq!("0", polynomial(&[]).to_latex());
+        assert_eq!("x", polynomial(&[0, 1]).to_latex());
+        assert_eq!(
+            "4x^{12} + 3x^{3}",
+            polynomial(&[0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 4]).to_latex()
+        );
+    }
+
     #[proptest]
     fn leading_coefficient_of_zero_polynomial_is_none(#[strategy(0usize..30)] num_zeros: usize) {
         let coefficients = vec![BFieldElement::zero(); num_zeros];
@@ -1808,29 +3417,198 @@ mod test_polynomials {
     }
 
     #[proptest]
-    fn polynomial_multiplication_is_associative(
-        a: Polynomial<BFieldElement>,
-        b: Polynomial<BFieldElement>,
-        c: Polynomial<BFieldElement>,
+    fn polynomial_multiplication_is_associative(
+        a: Polynomial<BFieldElement>,
+        b: Polynomial<BFieldElement>,
+        c: Polynomial<BFieldElement>,
+    ) {
+        prop_assert_eq!((a.clone() * b.clone()) * c.clone(), a * (b * c));
+    }
+
+    #[proptest]
+    fn polynomial_multiplication_is_distributive(
+        a: Polynomial<BFieldElement>,
+        b: Polynomial<BFieldElement>,
+        c: Polynomial<BFieldElement>,
+    ) {
+        prop_assert_eq!(
+            (a.clone() + b.clone()) * c.clone(),
+            (a * c.clone()) + (b * c)
+        );
+    }
+
+    #[proptest]
+    fn polynomial_subtraction_of_self_is_zero(a: Polynomial<BFieldElement>) {
+        prop_assert_eq!(Polynomial::zero(), a.clone() - a);
+    }
+
+    #[proptest]
+    fn lifting_then_unlifting_polynomial_is_identity(poly: Polynomial<BFieldElement>) {
+        let lifted_poly = Polynomial::new(poly.coefficients.iter().map(|c| c.lift()).collect());
+        prop_assert!(lifted_poly.is_in_base_field());
+        prop_assert_eq!(Some(poly), lifted_poly.try_unlift());
+    }
+
+    #[proptest]
+    fn splitting_into_then_assembling_from_coordinate_polynomials_is_identity(
+        poly: Polynomial<XFieldElement>,
+    ) {
+        let coordinate_polynomials = poly.clone().into_coordinate_polynomials();
+        prop_assert_eq!(
+            poly,
+            Polynomial::from_coordinate_polynomials(coordinate_polynomials)
+        );
+    }
+
+    #[proptest]
+    fn coordinate_polynomials_evaluate_to_coordinates_of_evaluation(
+        coordinate_polynomials: [Polynomial<BFieldElement>; EXTENSION_DEGREE],
+        point: BFieldElement,
+    ) {
+        let poly = Polynomial::<XFieldElement>::from_coordinate_polynomials(
+            coordinate_polynomials.clone(),
+        );
+        let evaluation = poly.evaluate(point.lift());
+        let coordinate_evaluations = coordinate_polynomials.map(|p| p.evaluate(point));
+        prop_assert_eq!(XFieldElement::new(coordinate_evaluations), evaluation);
+    }
+
+    #[proptest]
+    fn polynomial_with_coefficient_outside_base_field_cannot_be_unlifted(
+        poly: Polynomial<XFieldElement>,
+        #[filter(#coefficient.unlift().is_none())] coefficient: XFieldElement,
+    ) {
+        let mut coefficients = poly.coefficients;
+        coefficients.push(coefficient);
+        let extension_poly = Polynomial::new(coefficients);
+        prop_assert!(!extension_poly.is_in_base_field());
+        prop_assert_eq!(None, extension_poly.try_unlift());
+    }
+
+    #[proptest]
+    fn changing_basis_to_lagrange_basis_and_back_is_identity(
+        poly: Polynomial<BFieldElement>,
+        #[strategy(vec(arb(), #poly.coefficients.len()..#poly.coefficients.len() + 10))]
+        #[filter(#domain.iter().all_unique())]
+        domain: Vec<BFieldElement>,
+    ) {
+        let lagrange_form = poly.to_lagrange_basis(&domain);
+        prop_assert_eq!(domain.as_slice(), lagrange_form.domain());
+        prop_assert_eq!(poly.batch_evaluate(&domain), lagrange_form.values());
+        prop_assert_eq!(poly, Polynomial::from_lagrange_basis(&lagrange_form));
+    }
+
+    #[proptest]
+    fn changing_basis_from_lagrange_basis_and_back_is_identity(
+        #[strategy(vec(arb(), 1..30))]
+        #[filter(#domain.iter().all_unique())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    ) {
+        let lagrange_form = LagrangeForm::new(domain.clone(), values);
+        let poly = Polynomial::from_lagrange_basis(&lagrange_form);
+        prop_assert_eq!(lagrange_form, poly.to_lagrange_basis(&domain));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be represented in the Lagrange basis")]
+    fn changing_basis_to_lagrange_basis_over_too_small_domain_panics() {
+        let poly = Polynomial::new(bfe_vec![1, 2, 3]);
+        poly.to_lagrange_basis(&bfe_array![0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not contain duplicates")]
+    fn lagrange_form_with_duplicate_domain_points_panics() {
+        LagrangeForm::new(bfe_vec![0, 1, 0], bfe_vec![1, 2, 3]);
+    }
+
+    #[proptest(cases = 20)]
+    fn checked_interpolation_agrees_with_interpolation(
+        #[any(size_range(1..300).lift())]
+        #[filter(#domain.iter().all_unique())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    ) {
+        let interpolant = Polynomial::interpolate(&domain, &values);
+        prop_assert_eq!(
+            interpolant,
+            Polynomial::checked_interpolate(&domain, &values)?
+        );
+    }
+
+    #[proptest]
+    fn checked_interpolation_detects_duplicate_domain_points(
+        #[any(size_range(1..100).lift())]
+        #[filter(#domain.iter().all_unique())]
+        mut domain: Vec<BFieldElement>,
+        #[strategy(0..#domain.len())] duplicated_index: usize,
+        #[strategy(0..=#domain.len())] insertion_index: usize,
+    ) {
+        let duplicate = domain[duplicated_index];
+        domain.insert(insertion_index, duplicate);
+        let values = vec![BFieldElement::zero(); domain.len()];
+
+        let err = Polynomial::checked_interpolate(&domain, &values).unwrap_err();
+        let expected_index = insertion_index.max(duplicated_index + 1);
+        prop_assert_eq!(PolynomialError::DuplicateDomainPoint(expected_index), err);
+    }
+
+    #[test]
+    fn checked_interpolation_rejects_malformed_input() {
+        let empty_domain_err = Polynomial::<BFieldElement>::checked_interpolate(&[], &[]);
+        assert_eq!(Err(PolynomialError::EmptyDomain), empty_domain_err);
+
+        let mismatch_err = Polynomial::checked_interpolate(&bfe_array![1, 2], &bfe_array![3]);
+        assert_eq!(Err(PolynomialError::LengthMismatch(2, 1)), mismatch_err);
+    }
+
+    #[proptest]
+    fn checked_zerofier_agrees_with_zerofier(
+        #[any(size_range(..300).lift())] roots: Vec<BFieldElement>,
+    ) {
+        let zerofier = Polynomial::zerofier(&roots);
+        prop_assert_eq!(zerofier, Polynomial::checked_zerofier(&roots)?);
+    }
+
+    #[proptest]
+    fn checked_leading_coefficient_agrees_with_leading_coefficient(
+        polynomial: Polynomial<BFieldElement>,
     ) {
-        prop_assert_eq!((a.clone() * b.clone()) * c.clone(), a * (b * c));
+        let leading_coefficient = polynomial.leading_coefficient();
+        let checked_leading_coefficient = polynomial.checked_leading_coefficient();
+        prop_assert_eq!(leading_coefficient, checked_leading_coefficient.ok());
+    }
+
+    #[test]
+    fn zero_polynomial_has_no_checked_leading_coefficient() {
+        let zero = Polynomial::<BFieldElement>::zero();
+        assert_eq!(
+            Err(PolynomialError::ZeroPolynomial),
+            zero.checked_leading_coefficient()
+        );
     }
 
     #[proptest]
-    fn polynomial_multiplication_is_distributive(
-        a: Polynomial<BFieldElement>,
-        b: Polynomial<BFieldElement>,
-        c: Polynomial<BFieldElement>,
+    fn checked_division_agrees_with_division(
+        dividend: Polynomial<BFieldElement>,
+        #[filter(!#divisor.is_zero())] divisor: Polynomial<BFieldElement>,
     ) {
-        prop_assert_eq!(
-            (a.clone() + b.clone()) * c.clone(),
-            (a * c.clone()) + (b * c)
-        );
+        let quotient = dividend.divide(&divisor);
+        prop_assert_eq!(quotient, dividend.checked_divide(&divisor)?);
     }
 
     #[proptest]
-    fn polynomial_subtraction_of_self_is_zero(a: Polynomial<BFieldElement>) {
-        prop_assert_eq!(Polynomial::zero(), a.clone() - a);
+    fn checked_division_by_zero_fails(dividend: Polynomial<BFieldElement>) {
+        let quotient = dividend.checked_divide(&Polynomial::zero());
+        prop_assert_eq!(Err(PolynomialError::DivisionByZero), quotient);
+    }
+
+    #[test]
+    fn zero_polynomial_over_empty_domain_is_empty_lagrange_form() {
+        let lagrange_form = Polynomial::<BFieldElement>::zero().to_lagrange_basis(&[]);
+        assert!(lagrange_form.values().is_empty());
+        assert!(Polynomial::from_lagrange_basis(&lagrange_form).is_zero());
     }
 
     #[proptest]
@@ -2002,6 +3780,37 @@ mod test_polynomials {
         prop_assert_eq!(naive_zerofier, fast_zerofier);
     }
 
+    #[proptest(cases = 50)]
+    fn smart_zerofier_and_parallel_zerofier_are_identical(
+        #[any(size_range(..Polynomial::<BFieldElement>::FAST_ZEROFIER_CUTOFF_THRESHOLD * 2).lift())]
+        roots: Vec<BFieldElement>,
+        #[strategy(1_usize..8)] num_threads: usize,
+    ) {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        let par_zerofier = thread_pool.install(|| Polynomial::par_zerofier(&roots));
+        prop_assert_eq!(Polynomial::smart_zerofier(&roots), par_zerofier);
+    }
+
+    #[proptest(cases = 50)]
+    fn coset_zerofier_evaluations_are_evaluations_of_zerofier(
+        #[strategy(vec(arb(), 0..500))] roots: Vec<BFieldElement>,
+        #[strategy(0_usize..10)] log_2_order: usize,
+        offset: BFieldElement,
+    ) {
+        let order = 1 << log_2_order;
+        let generator = BFieldElement::primitive_root_of_unity(order as u64).unwrap();
+        let domain = (0..order as u64)
+            .map(|i| offset * generator.mod_pow(i))
+            .collect_vec();
+
+        let expected = Polynomial::zerofier(&roots).batch_evaluate(&domain);
+        let evaluations = Polynomial::coset_zerofier_evaluations(&roots, offset, generator, order);
+        prop_assert_eq!(expected, evaluations);
+    }
+
     #[proptest(cases = 50)]
     fn smart_zerofier_and_fast_zerofier_are_identical(
         #[any(size_range(..Polynomial::<BFieldElement>::FAST_ZEROFIER_CUTOFF_THRESHOLD * 2).lift())]
@@ -2051,116 +3860,479 @@ mod test_polynomials {
         let domain = bfe_array![6, 12];
         let x_to_the_5_plus_x_to_the_3 = Polynomial::new(bfe_vec![0, 0, 0, 1, 0, 1]);
 
-        let manual_evaluations = domain.map(|x| x.mod_pow(5) + x.mod_pow(3)).to_vec();
-        let fast_evaluations = x_to_the_5_plus_x_to_the_3.fast_evaluate(&domain);
-        assert_eq!(manual_evaluations, fast_evaluations);
+        let manual_evaluations = domain.map(|x| x.mod_pow(5) + x.mod_pow(3)).to_vec();
+        let fast_evaluations = x_to_the_5_plus_x_to_the_3.fast_evaluate(&domain);
+        assert_eq!(manual_evaluations, fast_evaluations);
+    }
+
+    #[proptest]
+    fn slow_and_fast_polynomial_evaluation_are_equivalent(
+        poly: Polynomial<BFieldElement>,
+        #[any(size_range(..1024).lift())] domain: Vec<BFieldElement>,
+    ) {
+        let evaluations = domain.iter().map(|&x| poly.evaluate(x)).collect_vec();
+        let fast_evaluations = poly.fast_evaluate(&domain);
+        prop_assert_eq!(evaluations, fast_evaluations);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero points")]
+    fn lagrange_interpolation_through_no_points_is_impossible() {
+        let _ = Polynomial::<BFieldElement>::lagrange_interpolate(&[], &[]);
+    }
+
+    #[proptest]
+    fn interpolating_through_one_point_gives_constant_polynomial(
+        x: BFieldElement,
+        y: BFieldElement,
+    ) {
+        let interpolant = Polynomial::lagrange_interpolate(&[x], &[y]);
+        let polynomial = Polynomial::from_constant(y);
+        prop_assert_eq!(polynomial, interpolant);
+    }
+
+    #[proptest(cases = 20)]
+    fn interpolation_with_leading_coefficient_has_prescribed_degree_and_leading_coefficient(
+        #[any(size_range(..300).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+        #[filter(!#leading_coefficient.is_zero())] leading_coefficient: BFieldElement,
+    ) {
+        let polynomial =
+            Polynomial::interpolate_with_leading_coefficient(&domain, &values, leading_coefficient);
+        prop_assert_eq!(domain.len() as isize, polynomial.degree());
+        prop_assert_eq!(Some(leading_coefficient), polynomial.leading_coefficient());
+        prop_assert_eq!(values, polynomial.batch_evaluate(&domain));
+    }
+
+    #[proptest]
+    fn lazy_evaluation_is_batch_evaluation(
+        poly: Polynomial<BFieldElement>,
+        domain: Vec<BFieldElement>,
+    ) {
+        let evaluations = poly.batch_evaluate(&domain);
+        let lazy_evaluations = poly.evaluate_iter(domain.iter().copied()).collect_vec();
+        prop_assert_eq!(&evaluations, &lazy_evaluations);
+
+        let par_evaluations: Vec<_> = poly.par_evaluate_iter(domain).collect();
+        prop_assert_eq!(evaluations, par_evaluations);
+    }
+
+    #[proptest(cases = 20)]
+    fn interpolation_with_leading_coefficient_zero_is_interpolation(
+        #[any(size_range(1..100).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    ) {
+        let interpolant = Polynomial::interpolate(&domain, &values);
+        let polynomial =
+            Polynomial::interpolate_with_leading_coefficient(&domain, &values, bfe!(0));
+        prop_assert_eq!(interpolant, polynomial);
+    }
+
+    #[proptest]
+    fn monic_interpolant_of_zeros_is_zerofier(
+        #[any(size_range(..100).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+    ) {
+        let zeros = vec![BFieldElement::zero(); domain.len()];
+        let polynomial = Polynomial::interpolate_monic(&domain, &zeros);
+        prop_assert_eq!(Polynomial::zerofier(&domain), polynomial);
+    }
+
+    #[test]
+    fn monic_interpolation_through_no_points_gives_one() {
+        let polynomial = Polynomial::<XFieldElement>::interpolate_monic(&[], &[]);
+        assert!(polynomial.is_one());
+    }
+
+    #[test]
+    #[should_panic(expected = "zero points")]
+    fn fast_interpolation_through_no_points_is_impossible() {
+        let _ = Polynomial::<BFieldElement>::fast_interpolate(&[], &[]);
+    }
+
+    #[proptest(cases = 10)]
+    fn lagrange_and_fast_interpolation_are_identical(
+        #[any(size_range(1..2048).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    ) {
+        let lagrange_interpolant = Polynomial::lagrange_interpolate(&domain, &values);
+        let fast_interpolant = Polynomial::fast_interpolate(&domain, &values);
+        prop_assert_eq!(lagrange_interpolant, fast_interpolant);
+    }
+
+    #[proptest(cases = 20)]
+    fn interpolation_then_evaluation_is_identity(
+        #[any(size_range(1..2048).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    ) {
+        let interpolant = Polynomial::fast_interpolate(&domain, &values);
+        let evaluations = interpolant.fast_evaluate(&domain);
+        prop_assert_eq!(values, evaluations);
+    }
+
+    #[proptest(cases = 1)]
+    fn fast_batch_interpolation_is_equivalent_to_fast_interpolation(
+        #[any(size_range(1..2048).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+        #[strategy(vec(vec(arb(), #domain.len()), 0..10))] value_vecs: Vec<Vec<BFieldElement>>,
+    ) {
+        let root_order = domain.len().next_power_of_two();
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+
+        let interpolants = value_vecs
+            .iter()
+            .map(|values| Polynomial::fast_interpolate(&domain, values))
+            .collect_vec();
+
+        let batched_interpolants =
+            Polynomial::batch_fast_interpolate(&domain, &value_vecs, root_of_unity, root_order);
+        prop_assert_eq!(interpolants, batched_interpolants);
+    }
+
+    fn coset_domain_of_size_from_generator_with_offset(
+        size: usize,
+        generator: BFieldElement,
+        offset: BFieldElement,
+    ) -> Vec<BFieldElement> {
+        let mut domain = vec![offset];
+        for _ in 1..size {
+            domain.push(domain.last().copied().unwrap() * generator);
+        }
+        domain
+    }
+
+    #[proptest]
+    fn checked_fast_coset_evaluation_agrees_with_fast_coset_evaluation(
+        polynomial: Polynomial<BFieldElement>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        #[filter((#root_order as isize) > #polynomial.degree())]
+        root_order: usize,
+    ) {
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let values = polynomial.fast_coset_evaluate(offset, root_of_unity, root_order);
+        let checked_values =
+            polynomial.checked_fast_coset_evaluate(offset, root_of_unity, root_order)?;
+        prop_assert_eq!(values, checked_values);
+    }
+
+    #[proptest]
+    fn checked_fast_coset_evaluation_supports_mixed_radix_domains(
+        #[strategy(vec(arb(), 0..=3))] coefficients: Vec<BFieldElement>,
+        offset: BFieldElement,
+        #[strategy(prop::sample::select(vec![3_usize, 5]))] odd_factor: usize,
+        #[strategy(0_usize..6)] log_2_of_power_of_two: usize,
+    ) {
+        let polynomial = Polynomial::new(coefficients);
+        let order = odd_factor << log_2_of_power_of_two;
+        let root_of_unity = mixed_radix_root_of_unity(order).unwrap();
+        let values = polynomial.fast_coset_evaluate(offset, root_of_unity, order);
+        let checked_values =
+            polynomial.checked_fast_coset_evaluate(offset, root_of_unity, order)?;
+        prop_assert_eq!(values, checked_values);
+    }
+
+    #[test]
+    fn checked_fast_coset_evaluation_rejects_unsuitable_domains() {
+        let polynomial = Polynomial::new(bfe_vec![1, 2, 3, 4]);
+        let offset = BFieldElement::generator();
+        let omega_8 = BFieldElement::primitive_root_of_unity(8).unwrap();
+        let omega_4 = BFieldElement::primitive_root_of_unity(4).unwrap();
+        let omega_2 = BFieldElement::primitive_root_of_unity(2).unwrap();
+
+        let not_smooth = polynomial.checked_fast_coset_evaluate(offset, omega_8, 7);
+        let not_smooth_err = NttError::LengthNotSmooth(7).into();
+        assert_eq!(Err::<Vec<_>, _>(not_smooth_err), not_smooth);
+
+        let too_large = polynomial.checked_fast_coset_evaluate(offset, omega_8, 3 << 30);
+        let too_large_err = NttError::DomainTooLarge(3 << 30).into();
+        assert_eq!(Err::<Vec<_>, _>(too_large_err), too_large);
+
+        let generator_of_other_mixed_radix_order =
+            polynomial.checked_fast_coset_evaluate(offset, omega_8, 6);
+        assert_eq!(
+            Err(PolynomialError::InvalidGenerator(6)),
+            generator_of_other_mixed_radix_order
+        );
+
+        let generator_of_wrong_order = polynomial.checked_fast_coset_evaluate(offset, omega_4, 8);
+        assert_eq!(
+            Err(PolynomialError::InvalidGenerator(8)),
+            generator_of_wrong_order
+        );
+
+        let too_small = polynomial.checked_fast_coset_evaluate(offset, omega_2, 2);
+        assert_eq!(Err(PolynomialError::DomainTooSmall(3, 2)), too_small);
+
+        let trivial_generator = Polynomial::from_constant(bfe!(1));
+        let wrong_trivial_generator =
+            trivial_generator.checked_fast_coset_evaluate(offset, omega_2, 1);
+        assert_eq!(
+            Err(PolynomialError::InvalidGenerator(1)),
+            wrong_trivial_generator
+        );
+        let one = BFieldElement::one();
+        assert!(trivial_generator
+            .checked_fast_coset_evaluate(offset, one, 1)
+            .is_ok());
+    }
+
+    #[proptest]
+    fn evaluation_with_sufficient_degree_bound_agrees_with_batch_evaluation(
+        polynomial: Polynomial<BFieldElement>,
+        #[strategy(arb())] domain: Vec<BFieldElement>,
+        #[strategy(0_isize..10)] slack: isize,
+    ) {
+        let degree_bound = polynomial.degree() + slack;
+        let evaluations = polynomial.evaluate_with_degree_bound(&domain, degree_bound)?;
+        prop_assert_eq!(polynomial.batch_evaluate(&domain), evaluations);
+    }
+
+    #[proptest]
+    fn evaluation_with_insufficient_degree_bound_fails(
+        #[filter(!#polynomial.is_zero())] polynomial: Polynomial<BFieldElement>,
+        #[strategy(arb())] domain: Vec<BFieldElement>,
+    ) {
+        let degree = polynomial.degree();
+        let err = polynomial
+            .evaluate_with_degree_bound(&domain, degree - 1)
+            .unwrap_err();
+        prop_assert_eq!(
+            PolynomialError::DegreeBoundExceeded(degree, degree - 1),
+            err
+        );
+    }
+
+    #[proptest]
+    fn fast_coset_evaluation_and_fast_evaluation_on_coset_are_identical(
+        polynomial: Polynomial<BFieldElement>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        // due to current limitation in `Polynomial::fast_coset_evaluate`
+        #[filter((#root_order as isize) > #polynomial.degree())]
+        root_order: usize,
+    ) {
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let domain =
+            coset_domain_of_size_from_generator_with_offset(root_order, root_of_unity, offset);
+
+        let fast_values = polynomial.fast_evaluate(&domain);
+        let fast_coset_values = polynomial.fast_coset_evaluate(offset, root_of_unity, root_order);
+        prop_assert_eq!(fast_values, fast_coset_values);
+    }
+
+    #[proptest]
+    fn batch_deep_quotients_agree_with_division_by_linear_polynomial(
+        #[strategy(vec(arb(), 0..10))] polynomials: Vec<Polynomial<XFieldElement>>,
+        z: XFieldElement,
+    ) {
+        let x_minus_z = Polynomial::new(vec![-z, XFieldElement::one()]);
+        let quotients = Polynomial::batch_deep_quotients(&polynomials, z);
+        prop_assert_eq!(polynomials.len(), quotients.len());
+
+        for (polynomial, quotient) in polynomials.iter().zip(quotients) {
+            let (expected_quotient, remainder) = polynomial.naive_divide(&x_minus_z);
+            prop_assert_eq!(expected_quotient, quotient);
+            prop_assert_eq!(polynomial.evaluate(z), remainder.evaluate(z));
+        }
+    }
+
+    #[proptest]
+    fn batch_fast_coset_evaluation_agrees_with_fast_coset_evaluation(
+        #[strategy(vec(arb(), 0..10))] polynomials: Vec<Polynomial<XFieldElement>>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        #[filter(#polynomials.iter().all(|p| (#root_order as isize) > p.degree()))]
+        root_order: usize,
+    ) {
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let codewords = polynomials
+            .iter()
+            .map(|p| p.fast_coset_evaluate(offset, root_of_unity, root_order))
+            .collect_vec();
+        let batch_codewords =
+            Polynomial::batch_fast_coset_evaluate(&polynomials, offset, root_of_unity, root_order);
+        prop_assert_eq!(codewords, batch_codewords);
+    }
+
+    #[proptest]
+    fn batch_fast_coset_evaluation_supports_other_generators_and_mixed_radix_orders(
+        #[strategy(vec(vec(arb(), 0..=4), 0..5))] coefficients: Vec<Vec<BFieldElement>>,
+        offset: BFieldElement,
+        #[strategy(prop::sample::select(vec![8_usize, 16, 6, 12, 10, 20]))] order: usize,
+    ) {
+        let polynomials = coefficients.into_iter().map(Polynomial::new).collect_vec();
+
+        // the inverse of a primitive root of unity is primitive, too
+        let generator = mixed_radix_root_of_unity(order).unwrap().inverse();
+        let codewords = polynomials
+            .iter()
+            .map(|p| p.fast_coset_evaluate(offset, generator, order))
+            .collect_vec();
+        let batch_codewords =
+            Polynomial::batch_fast_coset_evaluate(&polynomials, offset, generator, order);
+        prop_assert_eq!(codewords, batch_codewords);
+    }
+
+    #[test]
+    #[should_panic(expected = "limited to domains of order greater than the degree")]
+    fn batch_fast_coset_evaluation_on_too_small_domain_panics() {
+        let polynomials = [
+            Polynomial::new(bfe_vec![1, 2]),
+            Polynomial::new(bfe_vec![1, 2, 3]),
+        ];
+        let omega = BFieldElement::primitive_root_of_unity(2).unwrap();
+        let _ = Polynomial::batch_fast_coset_evaluate(&polynomials, bfe!(1), omega, 2);
+    }
+
+    #[proptest]
+    fn chunked_fast_coset_evaluation_agrees_with_evaluation_on_coset(
+        polynomial: Polynomial<BFieldElement>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        root_order: usize,
+    ) {
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let domain =
+            coset_domain_of_size_from_generator_with_offset(root_order, root_of_unity, offset);
+
+        let values = polynomial.batch_evaluate(&domain);
+        let chunked_values =
+            polynomial.chunked_fast_coset_evaluate(offset, root_of_unity, root_order);
+        prop_assert_eq!(values, chunked_values);
     }
 
     #[proptest]
-    fn slow_and_fast_polynomial_evaluation_are_equivalent(
-        poly: Polynomial<BFieldElement>,
-        #[any(size_range(..1024).lift())] domain: Vec<BFieldElement>,
+    fn chunked_fast_coset_evaluation_with_extension_field_offset_agrees_with_evaluation(
+        polynomial: Polynomial<XFieldElement>,
+        offset: XFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        root_order: usize,
     ) {
-        let evaluations = domain.iter().map(|&x| poly.evaluate(x)).collect_vec();
-        let fast_evaluations = poly.fast_evaluate(&domain);
-        prop_assert_eq!(evaluations, fast_evaluations);
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let domain = (0..root_order)
+            .map(|i| offset * root_of_unity.mod_pow(i as u64))
+            .collect_vec();
+
+        let values = polynomial.batch_evaluate(&domain);
+        let chunked_values =
+            polynomial.chunked_fast_coset_evaluate(offset, root_of_unity, root_order);
+        prop_assert_eq!(values, chunked_values);
     }
 
     #[test]
-    #[should_panic(expected = "zero points")]
-    fn lagrange_interpolation_through_no_points_is_impossible() {
-        let _ = Polynomial::<BFieldElement>::lagrange_interpolate(&[], &[]);
+    #[should_panic(expected = "must be a power of two")]
+    fn chunked_fast_coset_evaluation_on_domain_of_order_zero_panics() {
+        let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+        let _ = polynomial.chunked_fast_coset_evaluate(bfe!(1), bfe!(1), 0);
     }
 
     #[proptest]
-    fn interpolating_through_one_point_gives_constant_polynomial(
-        x: BFieldElement,
-        y: BFieldElement,
+    fn fast_coset_evaluation_and_interpolation_on_mixed_radix_domain_are_inverses(
+        #[strategy(prop::sample::select(vec![3, 5]))] odd_factor: usize,
+        #[strategy(0_u32..6)] log_2_of_power_of_two: u32,
+        #[filter(!#offset.is_zero())] offset: BFieldElement,
+        #[strategy(vec(arb(), #odd_factor << #log_2_of_power_of_two))] coefficients: Vec<
+            BFieldElement,
+        >,
     ) {
-        let interpolant = Polynomial::lagrange_interpolate(&[x], &[y]);
-        let polynomial = Polynomial::from_constant(y);
-        prop_assert_eq!(polynomial, interpolant);
-    }
+        let order = odd_factor << log_2_of_power_of_two;
+        let generator = crate::math::ntt::mixed_radix_root_of_unity(order).unwrap();
+        let polynomial = Polynomial::new(coefficients);
+        let domain = coset_domain_of_size_from_generator_with_offset(order, generator, offset);
 
-    #[test]
-    #[should_panic(expected = "zero points")]
-    fn fast_interpolation_through_no_points_is_impossible() {
-        let _ = Polynomial::<BFieldElement>::fast_interpolate(&[], &[]);
-    }
+        let values = polynomial.fast_coset_evaluate(offset, generator, order);
+        prop_assert_eq!(polynomial.batch_evaluate(&domain), values.clone());
 
-    #[proptest(cases = 10)]
-    fn lagrange_and_fast_interpolation_are_identical(
-        #[any(size_range(1..2048).lift())]
-        #[filter(#domain.iter().unique().count() == #domain.len())]
-        domain: Vec<BFieldElement>,
-        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
-    ) {
-        let lagrange_interpolant = Polynomial::lagrange_interpolate(&domain, &values);
-        let fast_interpolant = Polynomial::fast_interpolate(&domain, &values);
-        prop_assert_eq!(lagrange_interpolant, fast_interpolant);
+        let interpolant = Polynomial::fast_coset_interpolate(offset, generator, &values);
+        prop_assert_eq!(polynomial, interpolant);
     }
 
-    #[proptest(cases = 20)]
-    fn interpolation_then_evaluation_is_identity(
-        #[any(size_range(1..2048).lift())]
-        #[filter(#domain.iter().unique().count() == #domain.len())]
-        domain: Vec<BFieldElement>,
-        #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
+    #[proptest]
+    fn fast_coset_evaluation_with_plan_and_without_plan_are_identical(
+        polynomial: Polynomial<XFieldElement>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        #[filter((#root_order as isize) > #polynomial.degree())]
+        root_order: usize,
     ) {
-        let interpolant = Polynomial::fast_interpolate(&domain, &values);
-        let evaluations = interpolant.fast_evaluate(&domain);
-        prop_assert_eq!(values, evaluations);
+        let plan = NttPlan::new(root_order).unwrap();
+        let values = polynomial.fast_coset_evaluate(offset, plan.omega(), root_order);
+        let values_with_plan = polynomial.fast_coset_evaluate_with_plan(offset, &plan);
+        prop_assert_eq!(values, values_with_plan);
     }
 
-    #[proptest(cases = 1)]
-    fn fast_batch_interpolation_is_equivalent_to_fast_interpolation(
-        #[any(size_range(1..2048).lift())]
-        #[filter(#domain.iter().unique().count() == #domain.len())]
-        domain: Vec<BFieldElement>,
-        #[strategy(vec(vec(arb(), #domain.len()), 0..10))] value_vecs: Vec<Vec<BFieldElement>>,
+    #[proptest]
+    fn low_degree_extension_is_evaluation_on_coset(
+        #[strategy(0_u32..4)] log_2_blowup: u32,
+        #[strategy(#log_2_blowup..8)] log_2_length: u32,
+        #[strategy(vec(arb(), 0..=1_usize << (#log_2_length - #log_2_blowup)))] coefficients: Vec<
+            BFieldElement,
+        >,
+        offset: BFieldElement,
     ) {
-        let root_order = domain.len().next_power_of_two();
-        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-
-        let interpolants = value_vecs
-            .iter()
-            .map(|values| Polynomial::fast_interpolate(&domain, values))
-            .collect_vec();
+        let (length, blowup) = (1 << log_2_length, 1 << log_2_blowup);
+        let polynomial = Polynomial::new(coefficients);
+        let generator = BFieldElement::primitive_root_of_unity(length as u64).unwrap();
+        let domain = coset_domain_of_size_from_generator_with_offset(length, generator, offset);
 
-        let batched_interpolants =
-            Polynomial::batch_fast_interpolate(&domain, &value_vecs, root_of_unity, root_order);
-        prop_assert_eq!(interpolants, batched_interpolants);
+        let extension = polynomial.lde_evaluate(offset, length, blowup);
+        prop_assert_eq!(polynomial.batch_evaluate(&domain), extension);
     }
 
-    fn coset_domain_of_size_from_generator_with_offset(
-        size: usize,
-        generator: BFieldElement,
-        offset: BFieldElement,
-    ) -> Vec<BFieldElement> {
-        let mut domain = vec![offset];
-        for _ in 1..size {
-            domain.push(domain.last().copied().unwrap() * generator);
-        }
-        domain
+    #[test]
+    #[should_panic(expected = "must have fewer than 2 coefficients")]
+    fn low_degree_extension_of_too_high_degree_panics() {
+        let _ = Polynomial::new(bfe_vec![1, 2, 3]).lde_evaluate(bfe!(1), 8, 4);
     }
 
     #[proptest]
-    fn fast_coset_evaluation_and_fast_evaluation_on_coset_are_identical(
-        polynomial: Polynomial<BFieldElement>,
-        offset: BFieldElement,
+    fn fast_coset_interpolation_with_plan_and_without_plan_are_identical(
+        #[filter(!#offset.is_zero())] offset: BFieldElement,
         #[strategy(0..8usize)]
         #[map(|x: usize| 1 << x)]
-        // due to current limitation in `Polynomial::fast_coset_evaluate`
-        #[filter((#root_order as isize) > #polynomial.degree())]
         root_order: usize,
+        #[strategy(vec(arb(), #root_order))] values: Vec<XFieldElement>,
     ) {
-        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-        let domain =
-            coset_domain_of_size_from_generator_with_offset(root_order, root_of_unity, offset);
+        let plan = NttPlan::new(root_order).unwrap();
+        let interpolant = Polynomial::fast_coset_interpolate(offset, plan.omega(), &values);
+        let interpolant_with_plan =
+            Polynomial::fast_coset_interpolate_with_plan(offset, &plan, &values);
+        prop_assert_eq!(interpolant, interpolant_with_plan);
+    }
 
-        let fast_values = polynomial.fast_evaluate(&domain);
-        let fast_coset_values = polynomial.fast_coset_evaluate(offset, root_of_unity, root_order);
-        prop_assert_eq!(fast_values, fast_coset_values);
+    #[proptest]
+    fn fast_multiplication_with_plan_and_without_plan_are_identical(
+        a: Polynomial<BFieldElement>,
+        b: Polynomial<BFieldElement>,
+    ) {
+        let product_length = (a.degree() + b.degree() + 1).max(0) as usize;
+        let plan = NttPlan::new(product_length.next_power_of_two()).unwrap();
+        prop_assert_eq!(a.fast_multiply(&b), a.fast_multiply_with_plan(&b, &plan));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be greater than the degree of the product")]
+    fn fast_multiplication_with_too_short_plan_panics() {
+        let plan = NttPlan::new(2).unwrap();
+        let a = Polynomial::new(bfe_vec![1, 2]);
+        let _ = a.fast_multiply_with_plan(&a, &plan);
     }
 
     #[proptest]
@@ -2191,6 +4363,44 @@ mod test_polynomials {
         prop_assert_eq!(a, quot * b + rem);
     }
 
+    #[proptest]
+    fn division_in_place_with_leading_zeros_gives_quotient_and_remainder_with_expected_properties(
+        a: Polynomial<BFieldElement>,
+        #[filter(!#b.is_zero())] b: Polynomial<BFieldElement>,
+        #[strategy(0_usize..5)] num_leading_zeros_a: usize,
+        #[strategy(0_usize..5)] num_leading_zeros_b: usize,
+    ) {
+        let mut padded_a = a.clone();
+        padded_a
+            .coefficients
+            .resize(a.coefficients.len() + num_leading_zeros_a, bfe!(0));
+        let mut padded_b = b.clone();
+        padded_b
+            .coefficients
+            .resize(b.coefficients.len() + num_leading_zeros_b, bfe!(0));
+
+        let (quot, rem) = padded_a.divide_in_place(&padded_b);
+        prop_assert!(rem.degree() < b.degree());
+        prop_assert_eq!(a, quot * b + rem);
+    }
+
+    #[test]
+    fn division_in_place_of_zero_with_leading_zeros_gives_zero() {
+        let dividend = Polynomial::new(bfe_vec![0, 0]);
+        let divisor = Polynomial::new(bfe_vec![708_669_603_675_u64]);
+        let (quotient, remainder) = dividend.divide_in_place(&divisor);
+        assert!(quotient.is_zero());
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor should be non-zero")]
+    fn division_in_place_by_zero_panics() {
+        let dividend = Polynomial::new(bfe_vec![1, 2, 3]);
+        let divisor = Polynomial::new(bfe_vec![0, 0]);
+        let _ = dividend.divide_in_place(&divisor);
+    }
+
     #[proptest]
     fn clean_naive_division_gives_quotient_and_remainder_with_expected_properties(
         #[filter(!#a_roots.is_empty())] a_roots: Vec<BFieldElement>,
@@ -2531,6 +4741,326 @@ mod test_polynomials {
         prop_assert_eq!(poly.degree() - 1, poly.formal_derivative().degree());
     }
 
+    #[proptest]
+    fn polynomial_to_some_power_mod_some_modulus_is_equivalent_to_powering_then_reducing(
+        poly: Polynomial<BFieldElement>,
+        #[filter(!#modulus.is_zero())] modulus: Polynomial<BFieldElement>,
+        #[strategy(0_u64..20)] exponent: u64,
+    ) {
+        let expected = poly.mod_pow(exponent.into()) % modulus.clone();
+        prop_assert_eq!(expected, poly.pow_mod(exponent, &modulus));
+    }
+
+    #[proptest]
+    fn operators_on_references_agree_with_operators_on_owned_values(
+        a: Polynomial<BFieldElement>,
+        b: Polynomial<BFieldElement>,
+    ) {
+        let sum = a.clone() + b.clone();
+        prop_assert_eq!(&sum, &(a.clone() + &b));
+        prop_assert_eq!(&sum, &(&a + b.clone()));
+        prop_assert_eq!(&sum, &(&a + &b));
+
+        let difference = a.clone() - b.clone();
+        prop_assert_eq!(&difference, &(a.clone() - &b));
+        prop_assert_eq!(&difference, &(&a - b.clone()));
+        prop_assert_eq!(&difference, &(&a - &b));
+
+        let product = a.clone() * b.clone();
+        prop_assert_eq!(&product, &(a.clone() * &b));
+        prop_assert_eq!(&product, &(&a * b.clone()));
+        prop_assert_eq!(&product, &(&a * &b));
+
+        prop_assert_eq!(-a.clone(), -&a);
+    }
+
+    #[proptest]
+    fn companion_matrix_multiplies_by_x_modulo_the_polynomial(
+        #[filter(!#poly.is_zero())] poly: Polynomial<BFieldElement>,
+        #[strategy(vec(arb(), #poly.degree() as usize))] coefficients: Vec<BFieldElement>,
+    ) {
+        let companion_matrix = poly.companion_matrix();
+        let n = poly.degree() as usize;
+        prop_assert_eq!(n, companion_matrix.num_rows());
+        prop_assert_eq!(n, companion_matrix.num_columns());
+
+        let image = companion_matrix
+            .rows()
+            .map(|row| row.iter().zip(&coefficients).map(|(&m, &c)| m * c).sum())
+            .collect_vec();
+
+        let x = Polynomial::new(bfe_vec![0, 1]);
+        let expected = (x * Polynomial::new(coefficients)) % poly;
+        prop_assert_eq!(expected, Polynomial::new(image));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero polynomial has no companion matrix")]
+    fn zero_polynomial_has_no_companion_matrix() {
+        Polynomial::<BFieldElement>::zero().companion_matrix();
+    }
+
+    #[proptest]
+    fn linear_recurrence_kth_term_agrees_with_unrolled_recurrence(
+        #[filter(!#poly.is_zero())] poly: Polynomial<BFieldElement>,
+        #[strategy(vec(arb(), #poly.degree() as usize))] initial_values: Vec<BFieldElement>,
+        #[strategy(0_u64..50)] k: u64,
+    ) {
+        let degree = poly.degree() as usize;
+        let leading_coefficient_inv = poly.leading_coefficient().unwrap().inverse();
+        let mut sequence = initial_values.clone();
+        while sequence.len() <= k as usize {
+            let window = &sequence[sequence.len() - degree..];
+            let next_term = window
+                .iter()
+                .zip(&poly.coefficients)
+                .map(|(&s, &c)| -c * s * leading_coefficient_inv)
+                .sum();
+            sequence.push(next_term);
+        }
+
+        let kth_term = poly.linear_recurrence_kth_term(&initial_values, k);
+        prop_assert_eq!(sequence[k as usize], kth_term);
+    }
+
+    #[test]
+    fn linear_recurrence_kth_term_computes_large_fibonacci_numbers() {
+        let fibonacci = Polynomial::new(bfe_vec![-1, -1, 1]);
+        let initial_values = bfe_array![0, 1];
+        let f_90 = 2_880_067_194_370_816_120_u64;
+        let kth_term = fibonacci.linear_recurrence_kth_term(&initial_values, 90);
+        assert_eq!(BFieldElement::new(f_90), kth_term);
+    }
+
+    #[proptest]
+    fn minimal_polynomial_generates_the_sequence(
+        #[strategy(vec(arb(), 0..30))] sequence: Vec<BFieldElement>,
+    ) {
+        let minimal_polynomial = Polynomial::minimal_polynomial(&sequence);
+        prop_assert_eq!(Some(bfe!(1)), minimal_polynomial.leading_coefficient());
+
+        let degree = minimal_polynomial.degree() as usize;
+        prop_assert!(degree <= sequence.len());
+        for (k, &term) in sequence.iter().enumerate().skip(degree) {
+            let kth_term =
+                minimal_polynomial.linear_recurrence_kth_term(&sequence[..degree], k as u64);
+            prop_assert_eq!(term, kth_term);
+        }
+    }
+
+    #[proptest]
+    fn minimal_polynomial_recovers_characteristic_polynomial(
+        #[strategy(1_usize..10)] _degree: usize,
+        #[strategy(vec(arb(), #_degree))] lower_coefficients: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #_degree))] initial_values: Vec<BFieldElement>,
+    ) {
+        let mut coefficients = lower_coefficients;
+        coefficients.push(bfe!(1));
+        let characteristic_polynomial = Polynomial::new(coefficients);
+        let sequence = (0..2 * _degree as u64)
+            .map(|k| characteristic_polynomial.linear_recurrence_kth_term(&initial_values, k))
+            .collect_vec();
+
+        // The sequence might satisfy a shorter recurrence, in which case the minimal polynomial is
+        // a proper factor of the characteristic polynomial.
+        let minimal_polynomial = Polynomial::minimal_polynomial(&sequence);
+        let (_, remainder) = characteristic_polynomial.naive_divide(&minimal_polynomial);
+        prop_assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn minimal_polynomial_of_zero_sequence_is_one() {
+        let zero_sequence = vec![BFieldElement::zero(); 10];
+        let minimal_polynomial = Polynomial::minimal_polynomial(&zero_sequence);
+        assert_eq!(Polynomial::one(), minimal_polynomial);
+        assert_eq!(
+            Polynomial::one(),
+            Polynomial::<BFieldElement>::minimal_polynomial(&[])
+        );
+    }
+
+    #[test]
+    fn minimal_polynomial_of_impulse_is_of_degree_sequence_length() {
+        let impulse = bfe_vec![0, 0, 0, 1];
+        let minimal_polynomial = Polynomial::minimal_polynomial(&impulse);
+        assert_eq!(4, minimal_polynomial.degree());
+    }
+
+    #[test]
+    fn minimal_polynomial_of_geometric_sequence_is_linear() {
+        let sequence = (0..10).map(|i| bfe!(3).mod_pow(i)).collect_vec();
+        let minimal_polynomial = Polynomial::minimal_polynomial(&sequence);
+        assert_eq!(Polynomial::new(bfe_vec![-3, 1]), minimal_polynomial);
+    }
+
+    #[test]
+    #[should_panic(expected = "must match the degree")]
+    fn linear_recurrence_with_wrong_number_of_initial_values_panics() {
+        let fibonacci = Polynomial::new(bfe_vec![-1, -1, 1]);
+        fibonacci.linear_recurrence_kth_term(&bfe_array![1], 10);
+    }
+
+    #[test]
+    fn polynomials_of_degree_less_than_one_are_not_irreducible() {
+        assert!(!Polynomial::<BFieldElement>::zero().is_irreducible());
+        assert!(!Polynomial::<BFieldElement>::one().is_irreducible());
+        assert!(!Polynomial::new(bfe_vec![42, 0, 0]).is_irreducible());
+    }
+
+    #[proptest]
+    fn linear_polynomials_are_irreducible(
+        constant_term: BFieldElement,
+        #[filter(!#linear_term.is_zero())] linear_term: BFieldElement,
+    ) {
+        let poly = Polynomial::new(vec![constant_term, linear_term]);
+        prop_assert!(poly.is_irreducible());
+    }
+
+    #[test]
+    fn hardcoded_polynomials_have_expected_irreducibility() {
+        let polynomial = |cs: &[i32]| Polynomial::<BFieldElement>::from(cs);
+
+        // the defining polynomial of the extension field
+        assert!(XFieldElement::shah_polynomial().is_irreducible());
+
+        // 7 generates the multiplicative group, so it is not a square
+        assert!(polynomial(&[-7, 0, 1]).is_irreducible());
+        assert!(polynomial(&[-7, 0, 0, 1]).is_irreducible());
+
+        // 4 = 2·2 and -1 = (2^48)^2 are squares
+        assert!(!polynomial(&[-4, 0, 1]).is_irreducible());
+        assert!(!polynomial(&[1, 0, 1]).is_irreducible());
+
+        // (x^2 - 7)^2 has no roots but is still reducible
+        assert!(!polynomial(&[49, 0, -14, 0, 1]).is_irreducible());
+    }
+
+    #[proptest(cases = 50)]
+    fn product_of_non_constant_polynomials_is_not_irreducible(
+        #[filter(#a.degree() > 0)] a: Polynomial<BFieldElement>,
+        #[filter(#b.degree() > 0)] b: Polynomial<BFieldElement>,
+    ) {
+        prop_assert!(!(a * b).is_irreducible());
+    }
+
+    #[proptest(cases = 20)]
+    fn random_irreducible_polynomial_is_monic_irreducible_and_of_requested_degree(
+        #[strategy(1_usize..10)] degree: usize,
+        seed: u64,
+    ) {
+        let poly = Polynomial::random_irreducible(degree, &mut StdRng::seed_from_u64(seed));
+        prop_assert_eq!(degree as isize, poly.degree());
+        prop_assert_eq!(Some(BFieldElement::one()), poly.leading_coefficient());
+        prop_assert!(poly.is_irreducible());
+    }
+
+    #[test]
+    #[should_panic(expected = "degree at least 1")]
+    fn random_irreducible_polynomial_of_degree_zero_does_not_exist() {
+        let _ = Polynomial::random_irreducible(0, &mut rand::thread_rng());
+    }
+
+    #[proptest(cases = 20)]
+    fn square_free_part_of_zerofier_ignores_multiplicities(
+        #[strategy(vec(arb(), 1..10))] roots: Vec<BFieldElement>,
+        #[strategy(vec(0_usize..3, #roots.len()))] extra_multiplicities: Vec<usize>,
+        #[filter(!#scalar.is_zero())] scalar: BFieldElement,
+    ) {
+        let roots_with_multiplicities = roots
+            .iter()
+            .zip(extra_multiplicities)
+            .flat_map(|(&root, extra)| std::iter::repeat_n(root, 1 + extra))
+            .collect_vec();
+        let polynomial = Polynomial::zerofier(&roots_with_multiplicities).scalar_mul(scalar);
+
+        let distinct_roots = roots.into_iter().unique().collect_vec();
+        let expected = Polynomial::zerofier(&distinct_roots);
+        prop_assert_eq!(expected, polynomial.square_free_part());
+    }
+
+    #[proptest(cases = 20)]
+    fn roots_are_found_despite_multiplicities_and_irreducible_factors(
+        #[strategy(vec(arb(), 0..10))] roots: Vec<BFieldElement>,
+        #[strategy(1_usize..4)] irreducible_degree: usize,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let irreducible_factor = Polynomial::random_irreducible(irreducible_degree, &mut rng);
+        let squared_zerofier = Polynomial::zerofier(&roots).square();
+        let polynomial = squared_zerofier * irreducible_factor.clone();
+
+        let mut expected = roots.into_iter().unique().collect_vec();
+        if irreducible_degree == 1 {
+            expected.push(-irreducible_factor.coefficients[0]);
+            expected = expected.into_iter().unique().collect();
+        }
+        expected.sort_by_key(|root| root.value());
+        prop_assert_eq!(expected, polynomial.roots());
+    }
+
+    #[test]
+    fn ntt_length_for_degree_is_bounded_by_max_ntt_length() {
+        assert_eq!(Ok(1), ntt_length_for_degree(0));
+        assert_eq!(Ok(4), ntt_length_for_degree(2));
+        assert_eq!(Ok(4), ntt_length_for_degree(3));
+        assert_eq!(
+            Ok(MAX_NTT_LENGTH),
+            ntt_length_for_degree(MAX_NTT_LENGTH - 1)
+        );
+
+        assert_eq!(
+            Err(NttError::DomainTooLarge(2 * MAX_NTT_LENGTH)),
+            ntt_length_for_degree(MAX_NTT_LENGTH)
+        );
+        assert_eq!(
+            Err(NttError::DomainTooLarge(usize::MAX)),
+            ntt_length_for_degree(usize::MAX)
+        );
+    }
+
+    #[proptest]
+    fn checked_multiplication_is_multiplication(
+        a: Polynomial<BFieldElement>,
+        b: Polynomial<BFieldElement>,
+    ) {
+        prop_assert_eq!(a.multiply(&b), a.checked_multiply(&b)?);
+        prop_assert_eq!(a.square(), a.checked_square()?);
+    }
+
+    #[test]
+    fn constant_polynomial_has_no_roots() {
+        assert!(Polynomial::new(bfe_vec![42]).roots().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "has all roots")]
+    fn roots_of_zero_polynomial_cannot_be_listed() {
+        let _ = Polynomial::<BFieldElement>::zero().roots();
+    }
+
+    #[proptest]
+    fn random_polynomial_with_roots_is_divisible_by_zerofier(
+        #[strategy(vec(arb(), ..50))] roots: Vec<XFieldElement>,
+        #[strategy(0_usize..50)] extra_degree: usize,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let poly = Polynomial::random_with_roots(&roots, extra_degree, &mut rng);
+        prop_assert_eq!((roots.len() + extra_degree) as isize, poly.degree());
+        prop_assert!(poly.batch_evaluate(&roots).iter().all(|y| y.is_zero()));
+
+        let zerofier = Polynomial::zerofier(&roots);
+        let (quotient, remainder) = poly.naive_divide(&zerofier);
+        prop_assert!(remainder.is_zero());
+        prop_assert_eq!(extra_degree as isize, quotient.degree());
+    }
+
+    #[test]
+    fn random_polynomial_without_roots_or_extra_degree_is_non_zero_constant() {
+        let poly = Polynomial::<BFieldElement>::random_with_roots(&[], 0, &mut rand::thread_rng());
+        assert_eq!(0, poly.degree());
+    }
+
     #[proptest]
     fn formal_derivative_of_product_adheres_to_the_leibniz_product_rule(
         a: Polynomial<BFieldElement>,
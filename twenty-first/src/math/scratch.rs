@@ -0,0 +1,202 @@
+//! An optional, thread-local pool of scratch buffers for NTT-based polynomial arithmetic.
+//!
+//! Methods like [fast multiplication](crate::math::polynomial::Polynomial::fast_multiply) and
+//! [fast coset evaluation](crate::math::polynomial::Polynomial::fast_coset_evaluate) need vectors
+//! whose length is a power of two. Allocating and freeing them on every call puts pressure on the
+//! allocator, which is particularly noticeable if many threads do polynomial arithmetic at once.
+//!
+//! With the pool [enabled](set_enabled), buffers that are no longer needed are kept around by the
+//! thread that dropped them, at most [`MAX_BUFFERS_PER_LENGTH`] per length and element type, and
+//! handed out again to subsequent computations on that thread. Scratch space internal to this
+//! crate is returned to the pool automatically. Buffers returned to the caller, like the
+//! coefficients of a product or the values of a codeword, can be handed back using [`recycle`].
+//!
+//! The pool is disabled by default, since the kept buffers occupy memory.
+//!
+//! Buffers are pooled by element type, which must therefore be `'static`. Consequently, the
+//! NTT-based methods of [`Polynomial`](crate::math::polynomial::Polynomial) require `'static`
+//! fields.
+//!
+//! ```
+//! # use twenty_first::prelude::*;
+//! # use twenty_first::math::scratch;
+//! scratch::set_enabled(true);
+//! let polynomial = Polynomial::new(bfe_vec![1; 1000]);
+//! let square = polynomial.multiply(&polynomial);
+//!
+//! // the multiplication's scratch space is pooled
+//! assert_eq!(1, scratch::num_pooled_buffers());
+//! scratch::recycle(square.coefficients);
+//! assert_eq!(2, scratch::num_pooled_buffers());
+//!
+//! // both buffers are re-used, and the scratch space is pooled again
+//! let _ = polynomial.multiply(&polynomial);
+//! assert_eq!(1, scratch::num_pooled_buffers());
+//! # scratch::set_enabled(false);
+//! # scratch::clear();
+//! ```
+
+use std::any::Any;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+/// The maximal number of buffers of any one length and element type a thread keeps in its pool.
+pub const MAX_BUFFERS_PER_LENGTH: usize = 4;
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// For every element type and power-of-two capacity, the pooled buffers.
+    static POOL: RefCell<HashMap<(TypeId, usize), Box<dyn Buffers>>> = RefCell::new(HashMap::new());
+}
+
+/// The pooled buffers of some element type `T`, as a `Vec<Vec<T>>`.
+trait Buffers {
+    fn num_buffers(&self) -> usize;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: 'static> Buffers for Vec<Vec<T>> {
+    fn num_buffers(&self) -> usize {
+        self.len()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Enable or disable the pool on all threads. Disabling the pool does not free the buffers
+/// already pooled; use [`clear`] for that.
+pub fn set_enabled(is_enabled: bool) {
+    IS_ENABLED.store(is_enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    IS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Free all buffers pooled by the current thread.
+pub fn clear() {
+    POOL.with_borrow_mut(HashMap::clear);
+}
+
+/// The number of buffers pooled by the current thread, across all lengths and element types.
+pub fn num_pooled_buffers() -> usize {
+    POOL.with_borrow(|pool| pool.values().map(|buffers| buffers.num_buffers()).sum())
+}
+
+/// Return a `buffer` that is no longer needed to the current thread's pool. Does nothing if the
+/// pool is disabled, if the buffer's capacity is not a power of two, or if enough buffers of that
+/// capacity are pooled already.
+pub fn recycle<T: 'static>(mut buffer: Vec<T>) {
+    let capacity = buffer.capacity();
+    if !is_enabled() || !capacity.is_power_of_two() {
+        return;
+    }
+
+    buffer.clear();
+    POOL.with_borrow_mut(|pool| {
+        let buffers = pool
+            .entry((TypeId::of::<T>(), capacity))
+            .or_insert_with(|| Box::new(Vec::<Vec<T>>::new()))
+            .as_any_mut()
+            .downcast_mut::<Vec<Vec<T>>>()
+            .unwrap();
+        if buffers.len() < MAX_BUFFERS_PER_LENGTH {
+            buffers.push(buffer);
+        }
+    });
+}
+
+/// An empty vector with a capacity of at least `length`, taken from the current thread's pool if
+/// possible.
+pub(crate) fn take<T: 'static>(length: usize) -> Vec<T> {
+    if !is_enabled() || !length.is_power_of_two() {
+        return Vec::with_capacity(length);
+    }
+
+    POOL.with_borrow_mut(|pool| {
+        pool.get_mut(&(TypeId::of::<T>(), length))
+            .and_then(|buffers| {
+                let buffers = buffers.as_any_mut().downcast_mut::<Vec<Vec<T>>>().unwrap();
+                buffers.pop()
+            })
+    })
+    .unwrap_or_else(|| Vec::with_capacity(length))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::PoisonError;
+
+    use super::*;
+    use crate::config::TEST_LOCK;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::x_field_element::XFieldElement;
+
+    #[test]
+    fn recycled_buffers_are_re_used() {
+        let _guard = TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+        set_enabled(true);
+        clear();
+
+        let buffer = Vec::<BFieldElement>::with_capacity(16);
+        let pointer = buffer.as_ptr();
+        recycle(buffer);
+        let re_used_buffer = take::<BFieldElement>(16);
+        assert_eq!(pointer, re_used_buffer.as_ptr());
+        assert!(re_used_buffer.is_empty());
+
+        set_enabled(false);
+        clear();
+    }
+
+    #[test]
+    fn buffers_of_different_types_or_lengths_are_kept_apart() {
+        let _guard = TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+        set_enabled(true);
+        clear();
+
+        recycle(Vec::<BFieldElement>::with_capacity(16));
+        let _ = take::<XFieldElement>(16);
+        assert_eq!(1, num_pooled_buffers());
+        let _ = take::<BFieldElement>(32);
+        assert_eq!(1, num_pooled_buffers());
+        let _ = take::<BFieldElement>(16);
+        assert_eq!(0, num_pooled_buffers());
+
+        set_enabled(false);
+        clear();
+    }
+
+    #[test]
+    fn pool_keeps_limited_number_of_buffers() {
+        let _guard = TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+        set_enabled(true);
+        clear();
+
+        for _ in 0..2 * MAX_BUFFERS_PER_LENGTH {
+            recycle(Vec::<BFieldElement>::with_capacity(8));
+        }
+        recycle(Vec::<BFieldElement>::with_capacity(7));
+        assert_eq!(MAX_BUFFERS_PER_LENGTH, num_pooled_buffers());
+
+        set_enabled(false);
+        clear();
+    }
+
+    #[test]
+    fn disabled_pool_keeps_no_buffers() {
+        let _guard = TEST_LOCK.write().unwrap_or_else(PoisonError::into_inner);
+        set_enabled(false);
+        clear();
+
+        recycle(Vec::<BFieldElement>::with_capacity(8));
+        assert_eq!(0, num_pooled_buffers());
+    }
+}
@@ -1,14 +1,16 @@
 use arbitrary::Arbitrary;
-use bfieldcodec_derive::BFieldCodec;
 use get_size::GetSize;
 use itertools::Itertools;
 use num_traits::Zero;
+use rayon::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::config;
 use crate::math::b_field_element::BFieldElement;
 use crate::math::b_field_element::BFIELD_ONE;
 use crate::math::b_field_element::BFIELD_ZERO;
+use crate::math::bfield_codec::BFieldCodec;
 pub use crate::math::digest::Digest;
 pub use crate::math::digest::DIGEST_LENGTH;
 use crate::math::mds::generated_function;
@@ -23,6 +25,11 @@ pub const CAPACITY: usize = 6;
 pub const RATE: usize = 10;
 pub const NUM_ROUNDS: usize = 5;
 
+/// The minimal number of items hashed by one thread in [`hash_many`](AlgebraicHasher::hash_many)
+/// and [`Tip5::hash_chunks`]. Keeps the overhead of work stealing negligible when hashing many
+/// short sequences.
+const HASH_MANY_MIN_CHUNK_LENGTH: usize = 64;
+
 /// The lookup table with a high algebraic degree used in the TIP-5 permutation. To verify its
 /// correctness, see the test “lookup_table_is_correct.”
 pub const LOOKUP_TABLE: [u8; 256] = [
@@ -148,15 +155,18 @@ impl Tip5 {
 
         let mut state = [BFIELD_ZERO; STATE_SIZE];
 
-        match domain {
-            VariableLength => (),
-            FixedLength => {
-                let mut i = RATE;
-                while i < STATE_SIZE {
-                    state[i] = BFIELD_ONE;
-                    i += 1;
-                }
-            }
+        let capacity_element = match domain {
+            VariableLength => BFIELD_ZERO,
+            FixedLength => BFIELD_ONE,
+            Commitment => BFieldElement::new(2),
+            MessageAuthentication => BFieldElement::new(3),
+            ExtendableOutput => BFieldElement::new(4),
+            KeyedHash => BFieldElement::new(5),
+        };
+        let mut i = RATE;
+        while i < STATE_SIZE {
+            state[i] = capacity_element;
+            i += 1;
         }
 
         Self { state }
@@ -574,6 +584,101 @@ impl Tip5 {
         // squeeze once
         sponge.state[..DIGEST_LENGTH].try_into().unwrap()
     }
+
+    /// Like [`hash_varlen`](AlgebraicHasher::hash_varlen), but re-using `self` instead of a fresh
+    /// sponge. Absorbs the `input` block by block, without the intermediate allocations of
+    /// [`pad_and_absorb_all`](Sponge::pad_and_absorb_all).
+    fn reset_and_hash_varlen(&mut self, input: &[BFieldElement]) -> Digest {
+        *self = Self::new(Domain::VariableLength);
+        let mut blocks = input.chunks_exact(RATE);
+        for block in blocks.by_ref() {
+            self.absorb(block.try_into().unwrap());
+        }
+
+        // pad with [1, 0, 0, …], like `pad_and_absorb_all`
+        let remainder = blocks.remainder();
+        let mut last_block = [BFIELD_ZERO; RATE];
+        last_block[..remainder.len()].copy_from_slice(remainder);
+        last_block[remainder.len()] = BFIELD_ONE;
+        self.absorb(last_block);
+
+        let produce = self.squeeze();
+        Digest::new(produce[..DIGEST_LENGTH].try_into().unwrap())
+    }
+
+    /// Split the `sequence` into chunks of length `chunk_length` and
+    /// [hash](AlgebraicHasher::hash_varlen) every chunk, in parallel. If the length of the
+    /// `sequence` is not a multiple of `chunk_length`, the last chunk is shorter.
+    ///
+    /// This is the typical way of computing the leaves of a Merkle tree committing to a large
+    /// codeword, or to multiple codewords stored row-major in one contiguous buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let codeword = bfe_vec![1, 2, 3, 4, 5, 6];
+    /// let digests = Tip5::hash_chunks(&codeword, 2);
+    /// assert_eq!(3, digests.len());
+    /// assert_eq!(Tip5::hash_varlen(&bfe_vec![3, 4]), digests[1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_length` is 0.
+    pub fn hash_chunks(sequence: &[BFieldElement], chunk_length: usize) -> Vec<Digest> {
+        assert_ne!(0, chunk_length, "chunk length must be positive");
+        config::install(|| {
+            sequence
+                .par_chunks(chunk_length)
+                .with_min_len(HASH_MANY_MIN_CHUNK_LENGTH)
+                .map_init(Self::init, Self::reset_and_hash_varlen)
+                .collect()
+        })
+    }
+
+    /// Deterministically expand the `seed` into `length` many pseudo-random [`BFieldElement`]s.
+    ///
+    /// The sponge is used in counter mode: the `i`-th block of [`RATE`] elements is the
+    /// [squeeze](Sponge::squeeze) after [absorbing](Sponge::pad_and_absorb_all) the seed followed
+    /// by counter `i`, computed in the [extendable-output domain](Domain::ExtendableOutput). Hence,
+    /// no part of the expansion coincides with a hash. Since the blocks are independent, they are
+    /// computed in parallel. The expansion for some length is a prefix of the expansion for any
+    /// greater length.
+    ///
+    /// Useful for sampling long vectors, like random codewords or batching weights, from a short
+    /// seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let seed = Tip5::hash_varlen(&bfe_vec![42]);
+    /// let weights = Tip5::expand_seed(seed, 1000);
+    /// assert_eq!(1000, weights.len());
+    /// assert_eq!(weights[..10], Tip5::expand_seed(seed, 10));
+    /// ```
+    pub fn expand_seed(seed: Digest, length: usize) -> Vec<BFieldElement> {
+        let num_blocks = length.div_ceil(RATE);
+        let blocks: Vec<_> = config::install(|| {
+            (0..num_blocks)
+                .into_par_iter()
+                .with_min_len(HASH_MANY_MIN_CHUNK_LENGTH)
+                .map(|counter| {
+                    let mut sponge = Self::new(Domain::ExtendableOutput);
+                    let input = [
+                        seed.values().as_slice(),
+                        &[BFieldElement::new(counter as u64)],
+                    ]
+                    .concat();
+                    sponge.pad_and_absorb_all(&input);
+                    sponge.squeeze()
+                })
+                .collect()
+        });
+
+        blocks.into_iter().flatten().take(length).collect()
+    }
 }
 
 impl AlgebraicHasher for Tip5 {
@@ -587,6 +692,30 @@ impl AlgebraicHasher for Tip5 {
         let digest_values = sponge.state[..DIGEST_LENGTH].try_into().unwrap();
         Digest::new(digest_values)
     }
+
+    /// [Hash](AlgebraicHasher::hash) every one of the given `values`, in parallel. Every rayon
+    /// task re-uses one sponge for all the values it hashes. Each value is still
+    /// [encoded](BFieldCodec::encode) into a new vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let leaves = [bfe_vec![1, 2, 3], bfe_vec![4, 5], bfe_vec![]];
+    /// let digests = Tip5::hash_many(&leaves);
+    /// assert_eq!(Tip5::hash(&leaves[1]), digests[1]);
+    /// ```
+    fn hash_many<T: BFieldCodec + Sync>(values: &[T]) -> Vec<Digest> {
+        config::install(|| {
+            values
+                .par_iter()
+                .with_min_len(HASH_MANY_MIN_CHUNK_LENGTH)
+                .map_init(Self::init, |sponge, value| {
+                    sponge.reset_and_hash_varlen(&value.encode())
+                })
+                .collect()
+        })
+    }
 }
 
 impl Sponge for Tip5 {
@@ -596,6 +725,10 @@ impl Sponge for Tip5 {
         Self::new(Domain::VariableLength)
     }
 
+    fn init_in_domain(domain: Domain) -> Self {
+        Self::new(domain)
+    }
+
     fn absorb(&mut self, input: [BFieldElement; RATE]) {
         self.state[..RATE]
             .iter_mut()
@@ -627,6 +760,8 @@ pub(crate) mod tip5_tests {
     use rayon::prelude::ParallelIterator;
     use test_strategy::proptest;
 
+    use crate::bfe_array;
+    use crate::bfe_vec;
     use crate::math::other::random_elements;
     use crate::math::x_field_element::XFieldElement;
 
@@ -889,6 +1024,96 @@ pub(crate) mod tip5_tests {
         prop_assert_eq!(digest_through_pad_squeeze_absorb, hash_varlen_digest);
     }
 
+    #[proptest(cases = 20)]
+    fn hash_many_is_equivalent_to_hashing_individually(
+        #[strategy(arb())] values: Vec<Vec<BFieldElement>>,
+    ) {
+        let individual_digests = values.iter().map(Tip5::hash).collect_vec();
+        prop_assert_eq!(individual_digests, Tip5::hash_many(&values));
+    }
+
+    #[proptest(cases = 20)]
+    fn hash_chunks_is_equivalent_to_hashing_chunks_individually(
+        #[strategy(arb())] sequence: Vec<BFieldElement>,
+        #[strategy(1_usize..30)] chunk_length: usize,
+    ) {
+        let individual_digests = sequence
+            .chunks(chunk_length)
+            .map(Tip5::hash_varlen)
+            .collect_vec();
+        prop_assert_eq!(
+            individual_digests,
+            Tip5::hash_chunks(&sequence, chunk_length)
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn expanding_seed_is_deterministic_and_length_consistent(
+        #[strategy(arb())] seed: Digest,
+        #[strategy(0_usize..3000)] length: usize,
+        #[strategy(0_usize..=#length)] shorter_length: usize,
+    ) {
+        let expansion = Tip5::expand_seed(seed, length);
+        prop_assert_eq!(length, expansion.len());
+        prop_assert_eq!(&expansion, &Tip5::expand_seed(seed, length));
+        prop_assert_eq!(
+            &expansion[..shorter_length],
+            &Tip5::expand_seed(seed, shorter_length)
+        );
+    }
+
+    #[test]
+    fn expanding_seed_uses_sponge_in_counter_mode() {
+        let seed = Tip5::hash_varlen(&bfe_array![1, 2, 3]);
+        let expansion = Tip5::expand_seed(seed, 25);
+
+        let expected_expansion = (0..3)
+            .flat_map(|counter| {
+                let mut sponge = Tip5::init_in_domain(Domain::ExtendableOutput);
+                sponge.pad_and_absorb_all(&[seed.values().to_vec(), bfe_vec![counter]].concat());
+                sponge.squeeze()
+            })
+            .take(25)
+            .collect_vec();
+        assert_eq!(expected_expansion, expansion);
+    }
+
+    #[test]
+    fn expansion_of_seed_is_independent_of_hashes() {
+        let seed = Tip5::hash_varlen(&bfe_array![1, 2, 3]);
+        let expansion = Tip5::expand_seed(seed, 30);
+        for (counter, block) in expansion.chunks(RATE).enumerate() {
+            let input = [seed.values().to_vec(), bfe_vec![counter as u64]].concat();
+            let hash = Tip5::hash_varlen(&input);
+            assert_ne!(hash.values(), block[..DIGEST_LENGTH]);
+        }
+    }
+
+    #[test]
+    fn expanding_different_seeds_gives_different_results() {
+        let seed = Tip5::hash_varlen(&bfe_array![1]);
+        let other_seed = Tip5::hash_varlen(&bfe_array![2]);
+        assert_ne!(
+            Tip5::expand_seed(seed, 10),
+            Tip5::expand_seed(other_seed, 10)
+        );
+    }
+
+    #[test]
+    fn hash_many_of_many_values_is_equivalent_to_hashing_individually() {
+        let values = (0..1000_u64)
+            .map(|i| bfe_vec![i; (i % 13) as usize])
+            .collect_vec();
+        let individual_digests = values.iter().map(Tip5::hash).collect_vec();
+        assert_eq!(individual_digests, Tip5::hash_many(&values));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk length must be positive")]
+    fn hashing_chunks_of_length_zero_panics() {
+        Tip5::hash_chunks(&bfe_array![1, 2, 3], 0);
+    }
+
     #[test]
     fn test_linearity_of_mds() {
         type SpongeState = [BFieldElement; STATE_SIZE];
@@ -3,6 +3,7 @@ use std::iter::Sum;
 use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Div;
+use std::ops::DivAssign;
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Neg;
@@ -11,6 +12,7 @@ use std::ops::SubAssign;
 
 use arbitrary::Arbitrary;
 use bfieldcodec_derive::BFieldCodec;
+use itertools::Itertools;
 use num_traits::One;
 use num_traits::Zero;
 use rand::Rng;
@@ -22,6 +24,7 @@ use serde::Serialize;
 use crate::bfe_vec;
 use crate::error::TryFromXFieldElementError;
 use crate::math::b_field_element::BFieldElement;
+use crate::math::b_field_element::InvertedConstant;
 use crate::math::b_field_element::BFIELD_ZERO;
 use crate::math::polynomial::Polynomial;
 use crate::math::traits::CyclicGroupGenerator;
@@ -297,6 +300,53 @@ impl XFieldElement {
         }
     }
 
+    /// The adjugate of `self`, _i.e._, the element `adj(x)` such that `x·adj(x)` equals the
+    /// [norm](Self::norm) of `x`. It is the product of the two Galois conjugates of `x` other than
+    /// `x` itself, computed through the adjugate of the matrix representing multiplication by `x`.
+    fn adjugate(&self) -> Self {
+        let [a, b, c] = self.coefficients;
+
+        // Multiplication by x = a + b·α + c·α², where α³ = α - 1, corresponds to the matrix
+        //
+        //  ⎛ a   -c    -b  ⎞
+        //  ⎜ b   a+c   b-c ⎟
+        //  ⎝ c    b    a+c ⎠
+        //
+        // The adjugate element is the first column of the adjugate of this matrix.
+        let a_plus_c = a + c;
+        Self::new([
+            a_plus_c * a_plus_c - (b - c) * b,
+            (b - c) * c - b * a_plus_c,
+            b * b - a_plus_c * c,
+        ])
+    }
+
+    /// Given the [adjugate](Self::adjugate) of `x`, the norm of `x` is the determinant of its
+    /// multiplication matrix, obtained by expansion along the first row.
+    fn norm_from_adjugate(x: &Self, adjugate: &Self) -> BFieldElement {
+        let [a, b, c] = x.coefficients;
+        let [adj_0, adj_1, adj_2] = adjugate.coefficients;
+        a * adj_0 - c * adj_1 - b * adj_2
+    }
+
+    /// The field norm of `self`, _i.e._, the product of all its Galois conjugates
+    /// `x·x^p·x^(p²)`, which is always an element of the base field. The norm is zero if and only
+    /// if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::math::traits::ModPowU64;
+    /// let frobenius = |y: XFieldElement| y.mod_pow_u64(BFieldElement::P);
+    /// let x = xfe!([1, 2, 3]);
+    /// let conjugate_product = x * frobenius(x) * frobenius(frobenius(x));
+    /// assert_eq!(XFieldElement::new_const(x.norm()), conjugate_product);
+    /// ```
+    pub fn norm(&self) -> BFieldElement {
+        Self::norm_from_adjugate(self, &self.adjugate())
+    }
+
     // `increment` and `decrement` are mainly used for testing purposes
     pub fn increment(&mut self, index: usize) {
         self.coefficients[index].increment();
@@ -308,8 +358,8 @@ impl XFieldElement {
 }
 
 impl Inverse for XFieldElement {
-    #[must_use]
     fn inverse(&self) -> Self {
+        count_op!(xfe_inversions);
         assert!(
             !self.is_zero(),
             "Cannot invert the zero element in the extension field."
@@ -389,13 +439,38 @@ impl One for XFieldElement {
     }
 }
 
-impl FiniteField for XFieldElement {}
+impl FiniteField for XFieldElement {
+    /// Batch inversion via the [norm map](XFieldElement::norm) into the base field.
+    ///
+    /// For every element `x`, `x·adj(x) = N(x)` is a base field element. Hence, `x⁻¹ = adj(x) /
+    /// N(x)`, and only the norms need to be inverted. This is done using
+    /// [base field batch inversion][bfe_batch_inv], which is considerably cheaper than the generic
+    /// Montgomery batch inversion over the extension field.
+    ///
+    /// [bfe_batch_inv]: BFieldElement::batch_inversion
+    fn batch_inversion(input: Vec<Self>) -> Vec<Self> {
+        let adjugates = input.iter().map(|x| x.adjugate()).collect_vec();
+        let norms = input
+            .iter()
+            .zip(&adjugates)
+            .map(|(x, adj)| Self::norm_from_adjugate(x, adj))
+            .collect();
+        let norm_inverses = BFieldElement::batch_inversion(norms);
+
+        adjugates
+            .into_iter()
+            .zip(norm_inverses)
+            .map(|(adj, norm_inv)| adj * norm_inv)
+            .collect()
+    }
+}
 
 impl Add<XFieldElement> for XFieldElement {
     type Output = Self;
 
     #[inline]
     fn add(self, other: Self) -> Self {
+        count_op!(xfe_additions);
         let [s0, s1, s2] = self.coefficients;
         let [o0, o1, o2] = other.coefficients;
         let coefficients = [s0 + o0, s1 + o1, s2 + o2];
@@ -429,6 +504,7 @@ impl Mul<XFieldElement> for XFieldElement {
 
     #[inline]
     fn mul(self, other: Self) -> Self {
+        count_op!(xfe_multiplications);
         // XField * XField means:
         //
         // (ax^2 + bx + c) * (dx^2 + ex + f)   (mod x^3 - x + 1)
@@ -512,6 +588,7 @@ impl Sub<XFieldElement> for BFieldElement {
 impl AddAssign<XFieldElement> for XFieldElement {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
+        count_op!(xfe_additions);
         self.coefficients[0] += rhs.coefficients[0];
         self.coefficients[1] += rhs.coefficients[1];
         self.coefficients[2] += rhs.coefficients[2];
@@ -542,6 +619,7 @@ impl MulAssign<BFieldElement> for XFieldElement {
 impl SubAssign<XFieldElement> for XFieldElement {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
+        count_op!(xfe_additions);
         self.coefficients[0] -= rhs.coefficients[0];
         self.coefficients[1] -= rhs.coefficients[1];
         self.coefficients[2] -= rhs.coefficients[2];
@@ -564,6 +642,22 @@ impl Div for XFieldElement {
     }
 }
 
+impl Div<InvertedConstant> for XFieldElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, divisor: InvertedConstant) -> Self {
+        self * divisor.inverse()
+    }
+}
+
+impl DivAssign<InvertedConstant> for XFieldElement {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, divisor: InvertedConstant) {
+        *self *= divisor.inverse();
+    }
+}
+
 impl ModPowU64 for XFieldElement {
     #[inline]
     fn mod_pow_u64(&self, exponent: u64) -> Self {
@@ -598,6 +692,7 @@ mod tests {
     use proptest::collection::vec;
     use proptest::prelude::*;
     use proptest_arbitrary_interop::arb;
+    use rand::Rng;
     use test_strategy::proptest;
 
     use crate::bfe;
@@ -1019,6 +1114,56 @@ mod tests {
         }
     }
 
+    #[proptest]
+    fn batch_inversion_agrees_with_individual_inversion(
+        #[filter(!#xs.iter().any(|x| x.is_zero()))] xs: Vec<XFieldElement>,
+    ) {
+        let individual_inverses = xs.iter().map(|x| x.inverse()).collect_vec();
+        prop_assert_eq!(individual_inverses, XFieldElement::batch_inversion(xs));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot do batch inversion on zero")]
+    fn batch_inversion_of_zero_panics() {
+        let _ = XFieldElement::batch_inversion(xfe_vec![1, 0, 2]);
+    }
+
+    #[proptest]
+    fn division_by_inverted_constant_is_division_by_constant(
+        dividend: XFieldElement,
+        #[filter(!#divisor.is_zero())] divisor: BFieldElement,
+    ) {
+        let inverted_divisor = InvertedConstant::new(divisor);
+        let expected_quotient = dividend / XFieldElement::new_const(divisor);
+        prop_assert_eq!(expected_quotient, dividend / inverted_divisor);
+
+        let mut quotient = dividend;
+        quotient /= inverted_divisor;
+        prop_assert_eq!(expected_quotient, quotient);
+    }
+
+    #[proptest]
+    fn norm_is_product_of_galois_conjugates(x: XFieldElement) {
+        let frobenius = |y: XFieldElement| y.mod_pow_u64(BFieldElement::P);
+        let conjugate_product = x * frobenius(x) * frobenius(frobenius(x));
+        prop_assert_eq!(XFieldElement::new_const(x.norm()), conjugate_product);
+    }
+
+    #[proptest]
+    fn norm_is_multiplicative(x: XFieldElement, y: XFieldElement) {
+        prop_assert_eq!(x.norm() * y.norm(), (x * y).norm());
+    }
+
+    #[proptest]
+    fn norm_of_base_field_element_is_its_cube(b: BFieldElement) {
+        prop_assert_eq!(b * b * b, XFieldElement::new_const(b).norm());
+    }
+
+    #[proptest]
+    fn product_of_element_and_its_adjugate_is_norm(x: XFieldElement) {
+        prop_assert_eq!(XFieldElement::new_const(x.norm()), x * x.adjugate());
+    }
+
     #[test]
     fn mul_xfe_with_bfe_pbt() {
         let test_iterations = 100;
@@ -2,5 +2,7 @@ pub mod algebraic_hasher;
 pub mod merkle_tree;
 pub mod merkle_tree_maker;
 pub mod mmr;
+pub mod salted_merkle_tree;
+pub mod sampling;
 pub mod shared;
 pub mod tree_m_ary;
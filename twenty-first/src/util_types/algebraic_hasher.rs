@@ -2,7 +2,9 @@ use std::fmt::Debug;
 use std::iter;
 
 use itertools::Itertools;
+use rayon::prelude::*;
 
+use crate::config;
 use crate::math::b_field_element::BFieldElement;
 use crate::math::b_field_element::BFIELD_ONE;
 use crate::math::b_field_element::BFIELD_ZERO;
@@ -11,6 +13,7 @@ use crate::math::digest::Digest;
 use crate::math::digest::DIGEST_LENGTH;
 use crate::math::x_field_element::XFieldElement;
 use crate::math::x_field_element::EXTENSION_DEGREE;
+use crate::util_types::sampling;
 
 pub const RATE: usize = 10;
 
@@ -20,6 +23,7 @@ pub const RATE: usize = 10;
 /// hashing by introducing defining differences in the way the hash function's internal state
 /// (e.g. a sponge state's capacity) is initialized.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Domain {
     /// The `VariableLength` domain is used for hashing objects that potentially serialize to more
     /// than [`RATE`] number of field elements.
@@ -28,6 +32,21 @@ pub enum Domain {
     /// The `FixedLength` domain is used for hashing objects that always fit within [RATE] number
     /// of fields elements, e.g. a pair of [Digest].
     FixedLength,
+
+    /// The `Commitment` domain is used for [commitments](AlgebraicHasher::commit).
+    Commitment,
+
+    /// The `MessageAuthentication` domain is used for
+    /// [message authentication codes](AlgebraicHasher::mac).
+    MessageAuthentication,
+
+    /// The `ExtendableOutput` domain is used for
+    /// [expanding seeds](crate::math::tip5::Tip5::expand_seed) into arbitrarily many pseudo-random
+    /// elements.
+    ExtendableOutput,
+
+    /// The `KeyedHash` domain is used for [keyed hashes](Digest::keyed_hash) of digests.
+    KeyedHash,
 }
 
 /// A [cryptographic sponge][sponge]. Should only be based on a cryptographic permutation, e.g.,
@@ -40,6 +59,19 @@ pub trait Sponge: Clone + Debug + Default + Send + Sync {
 
     fn init() -> Self;
 
+    /// Like [`init`](Self::init), but with the sponge's capacity initialized to a value specific
+    /// to the given [`Domain`]. Since the capacity can neither be read nor written through the
+    /// sponge's rate, outputs of different domains are independent.
+    ///
+    /// The default implementation ignores the `domain` and is equivalent to [`init`](Self::init).
+    /// Sponges should override it; otherwise, [commitments](AlgebraicHasher::commit),
+    /// [MACs](AlgebraicHasher::mac), and [keyed hashes](Digest::keyed_hash) are not separated
+    /// from hashes.
+    fn init_in_domain(domain: Domain) -> Self {
+        let _ = domain;
+        Self::init()
+    }
+
     fn absorb(&mut self, input: [BFieldElement; RATE]);
 
     fn squeeze(&mut self) -> [BFieldElement; RATE];
@@ -67,6 +99,12 @@ pub trait AlgebraicHasher: Sponge {
         Self::hash_varlen(&value.encode())
     }
 
+    /// [Hash](Self::hash) every one of the given `values`, in parallel, according to the
+    /// [parallelism configuration](crate::config).
+    fn hash_many<T: BFieldCodec + Sync>(values: &[T]) -> Vec<Digest> {
+        config::install(|| values.par_iter().map(Self::hash).collect())
+    }
+
     /// Hash a variable-length sequence of [`BFieldElement`].
     ///
     /// - Apply the correct padding
@@ -80,27 +118,62 @@ pub trait AlgebraicHasher: Sponge {
         Digest::new((&produce[..DIGEST_LENGTH]).try_into().unwrap())
     }
 
-    /// Produce `num_indices` random integer values in the range `[0, upper_bound)`. The
-    /// `upper_bound` must be a power of 2.
+    /// Commit to `value` using the given `randomness`.
+    ///
+    /// The commitment is binding because the hash function is collision resistant. It is hiding
+    /// if the `randomness` is sampled uniformly at random and kept secret until the commitment is
+    /// opened. Opening the commitment means revealing both `value` and `randomness`; the verifier
+    /// recomputes the commitment and compares.
+    ///
+    /// Commitments are computed in their own [`Domain`]. Hence, no commitment coincides with a
+    /// hash, a [MAC](Self::mac), or any other output of the sponge, except by chance.
+    ///
+    /// Never re-use the `randomness` for different commitments.
+    fn commit(value: &[BFieldElement], randomness: Digest) -> Digest {
+        let mut sponge = Self::init_in_domain(Domain::Commitment);
+        sponge.pad_and_absorb_all(&[randomness.values().as_slice(), value].concat());
+        let produce = sponge.squeeze();
+
+        Digest::new((&produce[..DIGEST_LENGTH]).try_into().unwrap())
+    }
+
+    /// A message authentication code (MAC) for the `message` under the secret `key`.
+    ///
+    /// Without knowledge of the `key`, producing the MAC for any message, including messages for
+    /// which MACs are known, is infeasible. Since the capacity of the sponge is never revealed,
+    /// prepending the key is sufficient; there is no length extension. MACs are computed in their
+    /// own [`Domain`], separating them from hashes, [commitments](Self::commit), and any other
+    /// output of the sponge.
+    ///
+    /// For messages consisting of a single [`Digest`], [`Digest::keyed_hash`] is cheaper. The two
+    /// produce different outputs.
+    fn mac(key: Digest, message: &[BFieldElement]) -> Digest {
+        let mut sponge = Self::init_in_domain(Domain::MessageAuthentication);
+        sponge.pad_and_absorb_all(&[key.values().as_slice(), message].concat());
+        let produce = sponge.squeeze();
+
+        Digest::new((&produce[..DIGEST_LENGTH]).try_into().unwrap())
+    }
+
+    /// Produce `num_indices` random integer values in the range `[0, upper_bound)`.
+    ///
+    /// This method uses von Neumann rejection sampling: every squeezed element is mapped to an
+    /// index using [`sampling::index_from_element`], which drops elements that would lead to
+    /// biased indices. This method invokes squeeze until enough indices have been sampled.
     ///
-    /// This method uses von Neumann rejection sampling.
-    /// Specifically, if the top 32 bits of a BFieldElement are all ones, then the bottom 32 bits
-    /// are not uniformly distributed, and so they are dropped. This method invokes squeeze until
-    /// enough uniform u32s have been sampled.
+    /// For powers of 2, no additional elements are dropped. Hence, the sampled indices are the same
+    /// as those of previous versions, which only supported powers of 2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `upper_bound` is 0.
     fn sample_indices(&mut self, upper_bound: u32, num_indices: usize) -> Vec<u32> {
-        debug_assert!(upper_bound.is_power_of_two());
-        let mut indices = vec![];
-        let mut squeezed_elements = vec![];
-        while indices.len() != num_indices {
-            if squeezed_elements.is_empty() {
-                squeezed_elements = self.squeeze().into_iter().rev().collect_vec();
-            }
-            let element = squeezed_elements.pop().unwrap();
-            if element != BFieldElement::new(BFieldElement::MAX) {
-                indices.push(element.value() as u32 % upper_bound);
-            }
-        }
-        indices
+        assert_ne!(0, upper_bound, "upper bound must be positive");
+        iter::repeat_with(|| self.squeeze())
+            .flatten()
+            .filter_map(|element| sampling::index_from_element(element, upper_bound))
+            .take(num_indices)
+            .collect()
     }
 
     /// Produce `num_elements` random [`XFieldElement`] values.
@@ -110,7 +183,7 @@ pub trait AlgebraicHasher: Sponge {
     ///
     /// [rate]: Sponge::RATE
     fn sample_scalars(&mut self, num_elements: usize) -> Vec<XFieldElement> {
-        let num_squeezes = (num_elements * EXTENSION_DEGREE + Self::RATE - 1) / Self::RATE;
+        let num_squeezes = (num_elements * EXTENSION_DEGREE).div_ceil(Self::RATE);
         debug_assert!(
             num_elements * EXTENSION_DEGREE <= num_squeezes * Self::RATE,
             "need {} elements but getting {}",
@@ -199,6 +272,108 @@ mod algebraic_hasher_tests {
         assert!(indices.into_iter().all(|index| index < max));
     }
 
+    #[test]
+    fn sample_indices_for_non_powers_of_two_test() {
+        let cases = [(1, 5), (3, 9), (10, 10), (1000, 65), (u32::MAX, 21)];
+        for (upper_bound, num_indices) in cases {
+            sample_indices_prop(upper_bound, num_indices);
+        }
+    }
+
+    #[test]
+    fn sampling_indices_for_powers_of_two_is_unchanged() {
+        let mut sponge = Tip5::init();
+        sponge.absorb([BFIELD_ONE; RATE]);
+        let mut sponge_clone = sponge.clone();
+
+        let indices = sponge.sample_indices(1 << 20, 25);
+        let expected_indices = (0..3)
+            .flat_map(|_| sponge_clone.squeeze())
+            .filter(|&element| element != BFieldElement::new(BFieldElement::MAX))
+            .map(|element| element.value() as u32 % (1 << 20))
+            .take(25)
+            .collect_vec();
+        assert_eq!(expected_indices, indices);
+    }
+
+    #[test]
+    fn sampled_indices_for_non_powers_of_two_are_roughly_uniform() {
+        let upper_bound = 3;
+        let mut sponge = Tip5::randomly_seeded();
+        let indices = sponge.sample_indices(upper_bound, 3000);
+        for index in 0..upper_bound {
+            let count = indices.iter().filter(|&&i| i == index).count();
+            assert!(
+                (800..1200).contains(&count),
+                "index {index} sampled {count} times"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "upper bound must be positive")]
+    fn sampling_indices_with_upper_bound_zero_panics() {
+        Tip5::randomly_seeded().sample_indices(0, 1);
+    }
+
+    #[test]
+    fn commitment_can_be_opened() {
+        let mut rng = rand::thread_rng();
+        let value: [BFieldElement; 3] = rng.gen();
+        let randomness: Digest = rng.gen();
+        let commitment = Tip5::commit(&value, randomness);
+        assert_eq!(commitment, Tip5::commit(&value, randomness));
+
+        let other_randomness: Digest = rng.gen();
+        assert_ne!(commitment, Tip5::commit(&value, other_randomness));
+
+        let mut other_value = value;
+        other_value[0].increment();
+        assert_ne!(commitment, Tip5::commit(&other_value, randomness));
+        assert_ne!(commitment, Tip5::commit(&value[..2], randomness));
+    }
+
+    #[test]
+    fn mac_depends_on_key_and_message() {
+        let mut rng = rand::thread_rng();
+        let message: [BFieldElement; 12] = rng.gen();
+        let key: Digest = rng.gen();
+        let mac = Tip5::mac(key, &message);
+        assert_eq!(mac, Tip5::mac(key, &message));
+
+        let other_key: Digest = rng.gen();
+        assert_ne!(mac, Tip5::mac(other_key, &message));
+        assert_ne!(mac, Tip5::mac(key, &message[1..]));
+    }
+
+    #[test]
+    fn commitments_macs_and_hashes_are_domain_separated() {
+        let mut rng = rand::thread_rng();
+        let value: [BFieldElement; 4] = rng.gen();
+        let key: Digest = rng.gen();
+
+        let commitment = Tip5::commit(&value, key);
+        let mac = Tip5::mac(key, &value);
+        let hash = Tip5::hash_varlen(&[key.values().as_slice(), &value].concat());
+        assert_ne!(commitment, mac);
+        assert_ne!(commitment, hash);
+        assert_ne!(mac, hash);
+
+        // no prefix turns a hash into a commitment or a MAC
+        for prefix in [BFieldElement::new(0), BFieldElement::new(1)] {
+            let prefixed_input = [&[prefix], key.values().as_slice(), &value].concat();
+            let prefixed_hash = Tip5::hash_varlen(&prefixed_input);
+            assert_ne!(commitment, prefixed_hash);
+            assert_ne!(mac, prefixed_hash);
+        }
+
+        let digest: Digest = rng.gen();
+        assert_ne!(
+            digest.keyed_hash::<Tip5>(key),
+            Tip5::mac(key, &digest.values())
+        );
+    }
+
     #[test]
     fn sample_indices_test() {
         let cases = [
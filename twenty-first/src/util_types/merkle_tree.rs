@@ -11,6 +11,8 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use thiserror::Error;
 
+use crate::config;
+use crate::math::bfield_codec::BFieldCodec;
 use crate::math::digest::Digest;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
 use crate::util_types::merkle_tree_maker::MerkleTreeMaker;
@@ -107,6 +109,22 @@ where
         Maker::from_digests(leafs)
     }
 
+    /// Build a Merkle tree over the [hashes](AlgebraicHasher::hash) of the given `leaves`. Both the
+    /// leaves and the internal nodes are hashed in parallel, according to the
+    /// [parallelism configuration](crate::config).
+    ///
+    /// # Errors
+    ///
+    /// - If the number of leaves is 0.
+    /// - If the number of leaves is not a power of two.
+    pub fn par_from_leaves<T: BFieldCodec + Sync>(leaves: &[T]) -> Result<Self> {
+        let digests = match config::should_parallelize(leaves.len()) {
+            true => H::hash_many(leaves),
+            false => leaves.iter().map(H::hash).collect(),
+        };
+        CpuParallel::from_digests(&digests)
+    }
+
     /// Given a list of leaf indices, return the indices of exactly those nodes that are needed to
     /// prove (or verify) that the indicated leaves are in the Merkle tree.
     // This function is not defined as a method (taking self as argument) since it's needed by the
@@ -167,12 +185,12 @@ where
     /// The authentication structure for leaves 0 and 2, _i.e._, nodes 8 and 10 respectively, is nodes [11, 9, 3].
     /// Note how:
     /// - Node 3 is included only once, even though the individual authentication paths for leaves 0 and 2 both include
-    /// node 3. This is one part of the de-duplication.
+    ///   node 3. This is one part of the de-duplication.
     /// - Node 4 is not included at all, even though the authentication path for leaf 2 requires the node. Instead,
-    /// node 4 can be computed from nodes 8 and 9;
-    /// the former is supplied explicitly during [verification][verify],
-    /// the latter is included in the authentication structure.
-    /// This is the other part of the de-duplication.
+    ///   node 4 can be computed from nodes 8 and 9;
+    ///   the former is supplied explicitly during [verification][verify],
+    ///   the latter is included in the authentication structure.
+    ///   This is the other part of the de-duplication.
     ///
     /// [verify]: MerkleTreeInclusionProof::verify
     pub fn authentication_structure(&self, leaf_indices: &[usize]) -> Result<Vec<Digest>> {
@@ -286,6 +304,120 @@ where
         computed_root == expected_root
     }
 
+    /// Verify that the given root digest is the root of a Merkle tree of the given height that
+    /// contains the indicated leaves, consuming the authentication structure from an iterator.
+    ///
+    /// In contrast to [`verify`](Self::verify), the authentication structure is never held in
+    /// memory in its entirety. Instead, its digests are pulled on the fly, in the order produced
+    /// by [`authentication_structure`](MerkleTree::authentication_structure). This makes the
+    /// method suitable for verifying the inclusion of very many leaves, for example, when the
+    /// authentication structure is read from a file or received over the network. The memory
+    /// required is linear in the number of revealed leaves, and independent of the size of the
+    /// authentication structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// # use twenty_first::util_types::merkle_tree::MerkleTreeInclusionProof;
+    /// let leaves = (0..8).map(|i| Tip5::hash_varlen(&[bfe!(i)])).collect::<Vec<_>>();
+    /// let tree = MerkleTree::<Tip5>::new::<CpuParallel>(&leaves).unwrap();
+    ///
+    /// let leaf_indices = [0, 2];
+    /// let authentication_structure = tree.authentication_structure(&leaf_indices).unwrap();
+    /// let indexed_leaves = leaf_indices.map(|i| (i, leaves[i]));
+    ///
+    /// let verdict = MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+    ///     tree.height(),
+    ///     indexed_leaves,
+    ///     authentication_structure,
+    ///     tree.root(),
+    /// );
+    /// assert!(verdict);
+    /// ```
+    pub fn verify_streamed(
+        tree_height: usize,
+        indexed_leaves: impl IntoIterator<Item = (usize, Digest)>,
+        authentication_structure: impl IntoIterator<Item = Digest>,
+        expected_root: Digest,
+    ) -> bool {
+        let computed_root =
+            Self::root_from_streamed(tree_height, indexed_leaves, authentication_structure);
+        match computed_root {
+            Ok(Some(root)) => root == expected_root,
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    /// Compute the root of the Merkle tree from the indicated leaves and the streamed
+    /// authentication structure. Returns `None` if both the leaves and the authentication
+    /// structure are empty, _i.e._, if the proof is trivial.
+    fn root_from_streamed(
+        tree_height: usize,
+        indexed_leaves: impl IntoIterator<Item = (usize, Digest)>,
+        authentication_structure: impl IntoIterator<Item = Digest>,
+    ) -> Result<Option<Digest>> {
+        if tree_height > MAX_TREE_HEIGHT {
+            return Err(MerkleTreeError::TreeTooHigh);
+        }
+        let num_leaves = 1 << tree_height;
+
+        // Nodes of the current layer, sorted by descending node index. Descending order matches
+        // the order of the digests in the authentication structure.
+        let mut layer = BTreeMap::new();
+        for (leaf_index, leaf_digest) in indexed_leaves {
+            if leaf_index >= num_leaves {
+                return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+            }
+            let node_index = leaf_index + num_leaves;
+            if let Some(&digest) = layer.get(&node_index) {
+                if digest != leaf_digest {
+                    return Err(MerkleTreeError::RepeatedLeafDigestMismatch);
+                }
+            }
+            layer.insert(node_index, leaf_digest);
+        }
+        let mut layer = layer.into_iter().rev().collect_vec();
+
+        let mut authentication_structure = authentication_structure.into_iter();
+        if layer.is_empty() {
+            return match authentication_structure.next() {
+                Some(_) => Err(MerkleTreeError::RootNotFound),
+                None => Ok(None),
+            };
+        }
+
+        for _ in 0..tree_height {
+            let mut parent_layer = Vec::with_capacity(layer.len().div_ceil(2));
+            let mut nodes = layer.into_iter().peekable();
+            while let Some((node_index, digest)) = nodes.next() {
+                let sibling_index = node_index ^ 1;
+                let sibling_digest = match nodes.next_if(|&(i, _)| i == sibling_index) {
+                    Some((_, sibling_digest)) => sibling_digest,
+                    None => authentication_structure
+                        .next()
+                        .ok_or(MerkleTreeError::AuthenticationStructureLengthMismatch)?,
+                };
+
+                let (left_child, right_child) = match node_index % 2 {
+                    0 => (digest, sibling_digest),
+                    _ => (sibling_digest, digest),
+                };
+                parent_layer.push((node_index / 2, H::hash_pair(left_child, right_child)));
+            }
+            layer = parent_layer;
+        }
+
+        if authentication_structure.next().is_some() {
+            return Err(MerkleTreeError::AuthenticationStructureLengthMismatch);
+        }
+
+        debug_assert_eq!(1, layer.len());
+        debug_assert_eq!(ROOT_INDEX, layer[0].0);
+        Ok(Some(layer[0].1))
+    }
+
     /// Transform the inclusion proof into a list of authentication paths.
     ///
     /// This corresponds to a decompression of the authentication structure.
@@ -495,25 +627,23 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
         nodes[leaves_count..(leaves_count + leaves_count)]
             .clone_from_slice(&digests[..leaves_count]);
 
-        // Parallel digest calculations
+        // Parallel digest calculations. The nodes of each level are computed in place: on the level
+        // with `n` nodes, those are located at indices `n..2n`, their children at `2n..4n`.
+        let parallelization_cutoff =
+            (*PARALLELIZATION_CUTOFF).max(config::parallelism().min_parallel_len);
         let mut node_count_on_this_level: usize = leaves_count / 2;
         let mut count_acc: usize = 0;
-        while node_count_on_this_level >= *PARALLELIZATION_CUTOFF {
-            let mut local_digests: Vec<Digest> = Vec::with_capacity(node_count_on_this_level);
-            (0..node_count_on_this_level)
-                .into_par_iter()
-                .map(|i| {
-                    let j = node_count_on_this_level + i;
-                    let left_child = nodes[j * 2];
-                    let right_child = nodes[j * 2 + 1];
-                    H::hash_pair(left_child, right_child)
-                })
-                .collect_into_vec(&mut local_digests);
-            nodes[node_count_on_this_level..(node_count_on_this_level + node_count_on_this_level)]
-                .clone_from_slice(&local_digests[..node_count_on_this_level]);
-            count_acc += node_count_on_this_level;
-            node_count_on_this_level /= 2;
-        }
+        config::install(|| {
+            while node_count_on_this_level >= parallelization_cutoff {
+                let (upper_levels, lower_levels) = nodes.split_at_mut(2 * node_count_on_this_level);
+                upper_levels[node_count_on_this_level..]
+                    .par_iter_mut()
+                    .zip(lower_levels[..2 * node_count_on_this_level].par_chunks_exact(2))
+                    .for_each(|(node, children)| *node = H::hash_pair(children[0], children[1]));
+                count_acc += node_count_on_this_level;
+                node_count_on_this_level /= 2;
+            }
+        });
 
         // Sequential digest calculations
         for i in (1..(digests.len() - count_acc)).rev() {
@@ -529,6 +659,7 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum MerkleTreeError {
     #[error("All leaf indices must be valid, i.e., less than {num_leaves}.")]
     LeafIndexInvalid { num_leaves: usize },
@@ -556,6 +687,9 @@ pub enum MerkleTreeError {
 
     #[error("Tree height must not exceed {MAX_TREE_HEIGHT}.")]
     TreeTooHigh,
+
+    #[error("The number of salts must match the number of leaves.")]
+    SaltCountMismatch,
 }
 
 #[cfg(test)]
@@ -566,6 +700,7 @@ pub mod merkle_tree_test {
     use proptest_arbitrary_interop::arb;
     use test_strategy::proptest;
 
+    use crate::bfe_array;
     use crate::math::b_field_element::BFieldElement;
     use crate::math::digest::digest_tests::DigestCorruptor;
     use crate::math::tip5::Tip5;
@@ -650,6 +785,44 @@ pub mod merkle_tree_test {
         assert_eq!(MerkleTreeError::IncorrectNumberOfLeaves, err);
     }
 
+    #[proptest(cases = 10)]
+    fn parallel_and_sequential_node_computations_agree(
+        #[strategy(0_usize..=10)] _tree_height: usize,
+        #[strategy(vec(arb(), 1 << #_tree_height))] leaf_digests: Vec<Digest>,
+    ) {
+        let num_leaves = leaf_digests.len();
+        let mut nodes = vec![Digest::default(); num_leaves];
+        nodes.extend(&leaf_digests);
+        for i in (1..num_leaves).rev() {
+            nodes[i] = Tip5::hash_pair(nodes[2 * i], nodes[2 * i + 1]);
+        }
+
+        let tree = MerkleTree::<Tip5>::new::<CpuParallel>(&leaf_digests)?;
+        prop_assert_eq!(&nodes[1..], &tree.nodes()[1..]);
+    }
+
+    #[proptest(cases = 10)]
+    fn building_merkle_tree_from_leaves_hashes_them(
+        #[strategy(0_usize..=6)] _tree_height: usize,
+        #[strategy(vec(arb(), 1 << #_tree_height))] leaves: Vec<BFieldElement>,
+    ) {
+        let leaf_digests = leaves.iter().map(Tip5::hash).collect_vec();
+        let tree = MerkleTree::<Tip5>::new::<CpuParallel>(&leaf_digests)?;
+        prop_assert_eq!(tree, MerkleTree::par_from_leaves(&leaves)?);
+    }
+
+    #[test]
+    fn building_merkle_tree_from_wrong_number_of_leaves_fails() {
+        let three_leaves = MerkleTree::<Tip5>::par_from_leaves(&bfe_array![1, 2, 3]);
+        assert_eq!(
+            MerkleTreeError::IncorrectNumberOfLeaves,
+            three_leaves.unwrap_err()
+        );
+
+        let no_leaves = MerkleTree::<Tip5>::par_from_leaves::<BFieldElement>(&[]);
+        assert_eq!(MerkleTreeError::TooFewLeaves, no_leaves.unwrap_err());
+    }
+
     #[proptest(cases = 100)]
     fn accessing_number_of_leaves_and_height_never_panics(
         #[strategy(arb())] merkle_tree: MerkleTree<Tip5>,
@@ -673,6 +846,78 @@ pub mod merkle_tree_test {
         prop_assert!(verdict);
     }
 
+    #[proptest(cases = 40)]
+    fn streamed_verification_agrees_with_regular_verification(test_tree: MerkleTreeToTest) {
+        let proof = test_tree.proof();
+        let root = test_tree.tree.root();
+        let streamed_verdict = MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+            proof.tree_height,
+            proof.indexed_leaves.clone(),
+            proof.authentication_structure.clone(),
+            root,
+        );
+        prop_assert!(streamed_verdict);
+        prop_assert_eq!(proof.verify(root), streamed_verdict);
+    }
+
+    #[proptest(cases = 30)]
+    fn streamed_verification_of_corrupt_proof_fails(
+        #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
+        corruptor: DigestCorruptor,
+        #[strategy(0..#test_tree.selected_indices.len())] leaf_to_corrupt: usize,
+    ) {
+        let proof = test_tree.proof();
+        let root = test_tree.tree.root();
+        let (leaf_index, leaf_digest) = proof.indexed_leaves[leaf_to_corrupt];
+        let bad_leaf = vec![(leaf_index, corruptor.corrupt_digest(leaf_digest)?)];
+        let indexed_leaves = proof.indexed_leaves.clone().into_iter().chain(bad_leaf);
+
+        let bad_root = corruptor.corrupt_digest(root)?;
+        prop_assert!(!MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+            proof.tree_height,
+            proof.indexed_leaves,
+            proof.authentication_structure.clone(),
+            bad_root,
+        ));
+        prop_assert!(!MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+            proof.tree_height,
+            indexed_leaves,
+            proof.authentication_structure,
+            root,
+        ));
+    }
+
+    #[proptest(cases = 30)]
+    fn streamed_verification_with_wrong_authentication_structure_length_fails(
+        #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
+        additional_digest: Digest,
+    ) {
+        let proof = test_tree.proof();
+        let root = test_tree.tree.root();
+        let too_long = proof
+            .authentication_structure
+            .iter()
+            .copied()
+            .chain([additional_digest]);
+        let too_long_verdict = MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+            proof.tree_height,
+            proof.indexed_leaves.clone(),
+            too_long,
+            root,
+        );
+        prop_assert!(!too_long_verdict);
+
+        if let Some((_, too_short)) = proof.authentication_structure.split_last() {
+            let too_short_verdict = MerkleTreeInclusionProof::<Tip5>::verify_streamed(
+                proof.tree_height,
+                proof.indexed_leaves,
+                too_short.to_vec(),
+                root,
+            );
+            prop_assert!(!too_short_verdict);
+        }
+    }
+
     #[proptest(cases = 30)]
     fn corrupt_root_leads_to_verification_failure(
         #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
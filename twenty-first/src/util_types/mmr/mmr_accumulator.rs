@@ -254,7 +254,7 @@ impl<H: AlgebraicHasher> Mmr<H> for MmrAccumulator<H> {
             for (digest, authentication_path_indices) in membership_proof
                 .authentication_path
                 .iter_mut()
-                .zip(ap_indices.into_iter())
+                .zip(ap_indices)
             {
                 // Any number of hashes can be updated in the authentication path, since
                 // we're modifying multiple leaves in the MMR
@@ -406,7 +406,7 @@ pub mod util {
             // Update all_ap_elements
             for (node_index, ap_element) in new_node_indices
                 .into_iter()
-                .zip_eq(new_mp.authentication_path.clone().into_iter())
+                .zip_eq(new_mp.authentication_path.clone())
             {
                 all_ap_elements.insert(node_index, ap_element);
             }
@@ -640,10 +640,8 @@ mod accumulator_mmr_tests {
             let mutated_leaf_mps = mutated_leaf_indices
                 .iter()
                 .map(|i| ammr.prove_membership(*i).0);
-            let mutation_data: Vec<(MmrMembershipProof<H>, Digest)> = mutated_leaf_mps
-                .into_iter()
-                .zip(new_leafs.into_iter())
-                .collect();
+            let mutation_data: Vec<(MmrMembershipProof<H>, Digest)> =
+                mutated_leaf_mps.into_iter().zip(new_leafs).collect();
 
             assert_eq!(mutated_leaf_count, mutation_data.len());
 
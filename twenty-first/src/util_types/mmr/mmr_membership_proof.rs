@@ -369,10 +369,7 @@ impl<H: AlgebraicHasher> MmrMembershipProof<H> {
         // `own_node_indices` and check if the element is contained `deducible_hashes`.
         // If it is, then the appropriate element in `self.authentication_path` needs to
         // be replaced with an element from `deducible_hashes`.
-        for (digest, own_node_index) in self
-            .authentication_path
-            .iter_mut()
-            .zip(own_node_ap_indices.into_iter())
+        for (digest, own_node_index) in self.authentication_path.iter_mut().zip(own_node_ap_indices)
         {
             if !deducible_hashes.contains_key(&own_node_index) {
                 continue;
@@ -446,7 +443,7 @@ impl<H: AlgebraicHasher> MmrMembershipProof<H> {
             for (digest, authentication_path_indices) in membership_proof
                 .authentication_path
                 .iter_mut()
-                .zip(ap_indices.into_iter())
+                .zip(ap_indices)
             {
                 // Maximum 1 digest can be updated in each authentication path
                 // so if that is encountered, we might as well break and go to
@@ -477,6 +474,7 @@ impl<H: AlgebraicHasher> MmrMembershipProof<H> {
     ///  - membership_proofs -- own membership proofs, to be updated
     ///  - authentication_paths_and_leafs -- membership proofs of the mutated
     ///    leafs, and the new leaf values
+    ///
     /// Returns those indices into the slice of membership proofs that were updated.
     pub fn batch_update_from_batch_leaf_mutation(
         membership_proofs: &mut [&mut Self],
@@ -548,7 +546,7 @@ impl<H: AlgebraicHasher> MmrMembershipProof<H> {
             for (digest, authentication_path_indices) in membership_proof
                 .authentication_path
                 .iter_mut()
-                .zip(ap_indices.into_iter())
+                .zip(ap_indices)
             {
                 // Any number of hashes can be updated in the authentication path, since
                 // we're modifying multiple leaves in the MMR
@@ -652,7 +650,7 @@ mod mmr_membership_proof_test {
         let mut expected_peak_indices_and_heights: Vec<(u64, u32)> =
             vec![(7, 2), (7, 2), (7, 2), (7, 2), (10, 1), (10, 1), (11, 0)];
         for (leaf_index, expected_peak_index) in
-            (0..mmr_size as u64).zip(expected_peak_indices_and_heights.into_iter())
+            (0..mmr_size as u64).zip(expected_peak_indices_and_heights)
         {
             let (membership_proof, _peaks): (MmrMembershipProof<H>, Vec<Digest>) =
                 archival_mmr.prove_membership(leaf_index);
@@ -668,7 +666,7 @@ mod mmr_membership_proof_test {
         archival_mmr.append(leaf_hash);
         expected_peak_indices_and_heights = vec![(15, 3); mmr_size];
         for (leaf_index, expected_peak_index) in
-            (0..mmr_size as u64).zip(expected_peak_indices_and_heights.into_iter())
+            (0..mmr_size as u64).zip(expected_peak_indices_and_heights)
         {
             let (membership_proof, _peaks): (MmrMembershipProof<H>, Vec<Digest>) =
                 archival_mmr.prove_membership(leaf_index);
@@ -694,7 +692,7 @@ mod mmr_membership_proof_test {
             (16, 0),
         ];
         for (leaf_index, expected_peak_index) in
-            (0..mmr_size as u64).zip(expected_peak_indices_and_heights.into_iter())
+            (0..mmr_size as u64).zip(expected_peak_indices_and_heights)
         {
             let (membership_proof, _peaks): (MmrMembershipProof<H>, Vec<Digest>) =
                 archival_mmr.prove_membership(leaf_index);
@@ -817,7 +815,7 @@ mod mmr_membership_proof_test {
             let mutation_argument: Vec<(MmrMembershipProof<H>, Digest)> = authentication_paths
                 .clone()
                 .into_iter()
-                .zip(new_leafs.clone().into_iter())
+                .zip(new_leafs.clone())
                 .collect();
             let updated_mp_indices_0 = MmrMembershipProof::batch_update_from_batch_leaf_mutation(
                 &mut own_membership_proofs.iter_mut().collect::<Vec<_>>(),
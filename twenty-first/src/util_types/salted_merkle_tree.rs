@@ -0,0 +1,335 @@
+//! Merkle trees over salted leaves, for zero-knowledge commitments.
+//!
+//! A [`MerkleTree`] commits to its leaf digests directly. Revealing the authentication structure
+//! for some leaves reveals the digests of sibling leaves, which in turn can leak information about
+//! the values they were computed from, especially if those values are of low entropy. A
+//! [`SaltedMerkleTree`] instead commits to [salted leaves](SaltedMerkleTree::salt_leaf), each of
+//! which is a [commitment](AlgebraicHasher::commit) to a leaf under a random salt. Without the
+//! salts, the digests in an authentication structure are independent of the unrevealed leaves.
+//!
+//! Opening a leaf of a salted tree reveals the leaf as well as its salt, which the verifier needs
+//! to recompute the salted leaf. See [`SaltedMerkleTreeInclusionProof`].
+
+use std::marker::PhantomData;
+use std::result;
+
+use itertools::Itertools;
+use rand::Rng;
+
+use crate::math::digest::Digest;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::merkle_tree::MerkleTree;
+use crate::util_types::merkle_tree::MerkleTreeError;
+use crate::util_types::merkle_tree::MerkleTreeInclusionProof;
+use crate::util_types::merkle_tree_maker::MerkleTreeMaker;
+
+type Result<T> = result::Result<T, MerkleTreeError>;
+
+/// A [Merkle tree](MerkleTree) whose leaves are [salted](Self::salt_leaf) before being committed
+/// to.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::util_types::salted_merkle_tree::SaltedMerkleTree;
+/// let leaves = (0..8).map(|i| Tip5::hash_varlen(&[bfe!(i)])).collect::<Vec<_>>();
+/// let mut rng = rand::thread_rng();
+/// let tree = SaltedMerkleTree::<Tip5>::with_random_salts::<CpuParallel, _>(&leaves, &mut rng)
+///     .unwrap();
+///
+/// let proof = tree.inclusion_proof_for_leaf_indices(&[1, 6]).unwrap();
+/// assert_eq!(vec![(1, leaves[1]), (6, leaves[6])], proof.indexed_leaves);
+/// assert!(proof.verify(tree.root()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaltedMerkleTree<H>
+where
+    H: AlgebraicHasher,
+{
+    tree: MerkleTree<H>,
+    leaves: Vec<Digest>,
+    salts: Vec<Digest>,
+}
+
+/// A full inclusion proof for the leaves at the supplied indices of some
+/// [salted Merkle tree](SaltedMerkleTree), including the leaves themselves and their salts.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SaltedMerkleTreeInclusionProof<H>
+where
+    H: AlgebraicHasher,
+{
+    /// The stated height of the Merkle tree this proof is relative to.
+    pub tree_height: usize,
+
+    /// The leaves the proof is about, _i.e._, the revealed leaves, without salt.
+    pub indexed_leaves: Vec<(usize, Digest)>,
+
+    /// The salts of the revealed leaves, in the same order as the leaves.
+    pub salts: Vec<Digest>,
+
+    /// The proof's witness: de-duplicated authentication structure for the salted leaves this
+    /// proof is about. See [`authentication_structure`](MerkleTree::authentication_structure) for
+    /// details.
+    pub authentication_structure: Vec<Digest>,
+
+    pub _hasher: PhantomData<H>,
+}
+
+impl<H> SaltedMerkleTree<H>
+where
+    H: AlgebraicHasher,
+{
+    /// Build a Merkle tree over the given `leaves`, each [salted](Self::salt_leaf) with the salt at
+    /// the same index.
+    ///
+    /// In order for the tree to be hiding, the salts must be sampled uniformly at random. Prefer
+    /// [`with_random_salts`](Self::with_random_salts) unless the salts need to be derived
+    /// deterministically, for example from a seed.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of salts is not the number of leaves.
+    /// - If the number of leaves is 0.
+    /// - If the number of leaves is not a power of two.
+    pub fn new<Maker: MerkleTreeMaker<H>>(leaves: &[Digest], salts: Vec<Digest>) -> Result<Self> {
+        if leaves.len() != salts.len() {
+            return Err(MerkleTreeError::SaltCountMismatch);
+        }
+
+        let salted_leaves = leaves
+            .iter()
+            .zip(&salts)
+            .map(|(&leaf, &salt)| Self::salt_leaf(leaf, salt))
+            .collect_vec();
+        let tree = MerkleTree::new::<Maker>(&salted_leaves)?;
+
+        Ok(Self {
+            tree,
+            leaves: leaves.to_vec(),
+            salts,
+        })
+    }
+
+    /// Build a Merkle tree over the given `leaves`, each [salted](Self::salt_leaf) with a fresh
+    /// salt sampled from the given source of randomness.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of leaves is 0.
+    /// - If the number of leaves is not a power of two.
+    pub fn with_random_salts<Maker, R>(leaves: &[Digest], rng: &mut R) -> Result<Self>
+    where
+        Maker: MerkleTreeMaker<H>,
+        R: Rng + ?Sized,
+    {
+        let salts = (0..leaves.len()).map(|_| rng.gen()).collect();
+        Self::new::<Maker>(leaves, salts)
+    }
+
+    /// The digest committed to in place of the `leaf`: the [commitment](AlgebraicHasher::commit)
+    /// to the `leaf` with the `salt` as randomness. Since commitments live in their own sponge
+    /// domain, a salted leaf never coincides with an inner node of the tree, except by chance.
+    pub fn salt_leaf(leaf: Digest, salt: Digest) -> Digest {
+        H::commit(&leaf.values(), salt)
+    }
+
+    pub fn root(&self) -> Digest {
+        self.tree.root()
+    }
+
+    pub fn num_leafs(&self) -> usize {
+        self.tree.num_leafs()
+    }
+
+    pub fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    /// All leaves of the Merkle tree, without salt.
+    pub fn leaves(&self) -> &[Digest] {
+        &self.leaves
+    }
+
+    /// The salts of all leaves of the Merkle tree.
+    pub fn salts(&self) -> &[Digest] {
+        &self.salts
+    }
+
+    /// The underlying Merkle tree, whose leaves are the salted leaves.
+    pub fn salted_tree(&self) -> &MerkleTree<H> {
+        &self.tree
+    }
+
+    /// The de-duplicated authentication structure for the salted leaves at the given indices. See
+    /// [`MerkleTree::authentication_structure`] for details.
+    pub fn authentication_structure(&self, leaf_indices: &[usize]) -> Result<Vec<Digest>> {
+        self.tree.authentication_structure(leaf_indices)
+    }
+
+    /// A full inclusion proof for the leaves at the supplied indices, including the leaves and
+    /// their salts.
+    pub fn inclusion_proof_for_leaf_indices(
+        &self,
+        indices: &[usize],
+    ) -> Result<SaltedMerkleTreeInclusionProof<H>> {
+        // also validates the indices
+        let authentication_structure = self.authentication_structure(indices)?;
+        let proof = SaltedMerkleTreeInclusionProof {
+            tree_height: self.height(),
+            indexed_leaves: indices.iter().map(|&i| (i, self.leaves[i])).collect(),
+            salts: indices.iter().map(|&i| self.salts[i]).collect(),
+            authentication_structure,
+            _hasher: PhantomData,
+        };
+        Ok(proof)
+    }
+}
+
+impl<H> SaltedMerkleTreeInclusionProof<H>
+where
+    H: AlgebraicHasher,
+{
+    /// Verify that the given root digest is the root of a salted Merkle tree that contains the
+    /// indicated leaves with the indicated salts.
+    pub fn verify(self, expected_root: Digest) -> bool {
+        if self.indexed_leaves.len() != self.salts.len() {
+            return false;
+        }
+
+        let indexed_salted_leaves = self
+            .indexed_leaves
+            .into_iter()
+            .zip(self.salts)
+            .map(|((index, leaf), salt)| (index, SaltedMerkleTree::<H>::salt_leaf(leaf, salt)))
+            .collect();
+        let proof = MerkleTreeInclusionProof::<H> {
+            tree_height: self.tree_height,
+            indexed_leaves: indexed_salted_leaves,
+            authentication_structure: self.authentication_structure,
+            _hasher: PhantomData,
+        };
+        proof.verify(expected_root)
+    }
+}
+
+#[cfg(test)]
+mod salted_merkle_tree_test {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use test_strategy::proptest;
+
+    use crate::math::tip5::Tip5;
+    use crate::util_types::merkle_tree::CpuParallel;
+
+    use super::*;
+
+    type SaltedTree = SaltedMerkleTree<Tip5>;
+
+    #[proptest(cases = 50)]
+    fn inclusion_proof_of_salted_tree_verifies(
+        #[strategy(0_usize..8)] _tree_height: usize,
+        #[strategy(vec(arb(), 1 << #_tree_height))] leaves: Vec<Digest>,
+        #[strategy(vec(arb(), 1 << #_tree_height))] salts: Vec<Digest>,
+        #[strategy(vec(0..1_usize << #_tree_height, 0..10))] leaf_indices: Vec<usize>,
+    ) {
+        let tree = SaltedTree::new::<CpuParallel>(&leaves, salts).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(proof.verify(tree.root()));
+    }
+
+    #[proptest(cases = 50)]
+    fn inclusion_proof_with_wrong_salt_does_not_verify(
+        #[strategy(1_usize..8)] _tree_height: usize,
+        #[strategy(vec(arb(), 1 << #_tree_height))] leaves: Vec<Digest>,
+        #[strategy(vec(arb(), 1 << #_tree_height))] salts: Vec<Digest>,
+        #[strategy(0..1_usize << #_tree_height)] leaf_index: usize,
+        #[filter(#salts[#leaf_index] != #wrong_salt)] wrong_salt: Digest,
+    ) {
+        let tree = SaltedTree::new::<CpuParallel>(&leaves, salts).unwrap();
+        let mut proof = tree
+            .inclusion_proof_for_leaf_indices(&[leaf_index])
+            .unwrap();
+        proof.salts[0] = wrong_salt;
+        prop_assert!(!proof.verify(tree.root()));
+    }
+
+    #[proptest(cases = 50)]
+    fn inclusion_proof_with_wrong_leaf_does_not_verify(
+        #[strategy(1_usize..8)] _tree_height: usize,
+        #[strategy(vec(arb(), 1 << #_tree_height))] leaves: Vec<Digest>,
+        #[strategy(vec(arb(), 1 << #_tree_height))] salts: Vec<Digest>,
+        #[strategy(0..1_usize << #_tree_height)] leaf_index: usize,
+        #[filter(#leaves[#leaf_index] != #wrong_leaf)] wrong_leaf: Digest,
+    ) {
+        let tree = SaltedTree::new::<CpuParallel>(&leaves, salts).unwrap();
+        let mut proof = tree
+            .inclusion_proof_for_leaf_indices(&[leaf_index])
+            .unwrap();
+        proof.indexed_leaves[0].1 = wrong_leaf;
+        prop_assert!(!proof.verify(tree.root()));
+    }
+
+    #[test]
+    fn inclusion_proof_with_missing_salt_does_not_verify() {
+        let leaves = [Digest::default(); 4];
+        let tree =
+            SaltedTree::with_random_salts::<CpuParallel, _>(&leaves, &mut rand::thread_rng())
+                .unwrap();
+        let mut proof = tree.inclusion_proof_for_leaf_indices(&[1, 2]).unwrap();
+        proof.salts.pop();
+        assert!(!proof.verify(tree.root()));
+    }
+
+    #[test]
+    fn salted_tree_does_not_commit_to_unsalted_leaves() {
+        let leaves = [Digest::default(); 4];
+        let unsalted_tree = MerkleTree::<Tip5>::new::<CpuParallel>(&leaves).unwrap();
+        let salted_tree =
+            SaltedTree::with_random_salts::<CpuParallel, _>(&leaves, &mut rand::thread_rng())
+                .unwrap();
+        assert_ne!(unsalted_tree.root(), salted_tree.root());
+        assert_ne!(
+            unsalted_tree.nodes()[2..4],
+            salted_tree.salted_tree().nodes()[2..4]
+        );
+    }
+
+    #[proptest]
+    fn salted_leaves_are_domain_separated_from_inner_nodes(leaf: Digest, salt: Digest) {
+        let salted_leaf = SaltedTree::salt_leaf(leaf, salt);
+        prop_assert_eq!(Tip5::commit(&leaf.values(), salt), salted_leaf);
+        prop_assert_ne!(Tip5::hash_pair(leaf, salt), salted_leaf);
+    }
+
+    #[test]
+    fn salted_trees_over_same_leaves_with_random_salts_have_different_roots() {
+        let leaves = [Digest::default(); 4];
+        let mut rng = StdRng::seed_from_u64(0x5a17);
+        let tree_0 = SaltedTree::with_random_salts::<CpuParallel, _>(&leaves, &mut rng).unwrap();
+        let tree_1 = SaltedTree::with_random_salts::<CpuParallel, _>(&leaves, &mut rng).unwrap();
+        assert_ne!(tree_0.root(), tree_1.root());
+    }
+
+    #[test]
+    fn building_salted_tree_with_wrong_number_of_salts_fails() {
+        let leaves = [Digest::default(); 4];
+        let salts = vec![Digest::default(); 3];
+        let err = SaltedTree::new::<CpuParallel>(&leaves, salts).unwrap_err();
+        assert_eq!(MerkleTreeError::SaltCountMismatch, err);
+    }
+
+    #[test]
+    fn inclusion_proof_for_invalid_leaf_index_cannot_be_generated() {
+        let leaves = [Digest::default(); 4];
+        let salts = vec![Digest::default(); 4];
+        let tree = SaltedTree::new::<CpuParallel>(&leaves, salts).unwrap();
+        let err = tree.inclusion_proof_for_leaf_indices(&[4]).unwrap_err();
+        assert_eq!(MerkleTreeError::LeafIndexInvalid { num_leaves: 4 }, err);
+    }
+}
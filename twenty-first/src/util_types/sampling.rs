@@ -0,0 +1,155 @@
+//! Deterministic, unbiased mapping of pseudorandom field elements to indices.
+//!
+//! The mapping is used by [`AlgebraicHasher::sample_indices`] and can be used directly to derive
+//! indices, for example into an evaluation domain or the leaves of a Merkle tree, from a
+//! [`Digest`].
+//!
+//! # Bias
+//!
+//! Reducing a pseudorandom value modulo the `upper_bound` is biased unless the number of possible
+//! values is a multiple of the `upper_bound`. Hence, indices are derived as follows.
+//!
+//! 1. Of the `p = 2^64 - 2^32 + 1` possible values of a [`BFieldElement`], the largest one is
+//!    dropped. The remaining `2^32·(2^32 - 1)` values have uniformly distributed lower 32 bits.
+//! 1. If the lower 32 bits are at least the largest multiple of the `upper_bound` not exceeding
+//!    `2^32`, they are dropped. The remaining values are a multiple of the `upper_bound`.
+//! 1. The remaining lower 32 bits are reduced modulo the `upper_bound`.
+//!
+//! For uniformly distributed field elements, the resulting indices are thus uniformly distributed.
+//! For powers of two, the second step never drops anything. For other `upper_bound`s, fewer than
+//! half of all elements are dropped.
+//!
+//! [`AlgebraicHasher::sample_indices`]: crate::util_types::algebraic_hasher::AlgebraicHasher::sample_indices
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::digest::Digest;
+
+/// Map the given `element` to an index in the range `[0, upper_bound)`, or `None` if the `element`
+/// is dropped to avoid bias. See the [module-level documentation](self) for details.
+///
+/// # Panics
+///
+/// Panics if the `upper_bound` is 0.
+pub fn index_from_element(element: BFieldElement, upper_bound: u32) -> Option<u32> {
+    assert_ne!(0, upper_bound, "upper bound must be positive");
+    let num_biased_u32s = (1_u64 << 32) % u64::from(upper_bound);
+    let rejection_threshold = (1_u64 << 32) - num_biased_u32s;
+
+    let candidate = element.value() as u32;
+    let is_uniform = element != BFieldElement::new(BFieldElement::MAX);
+    let is_unbiased = u64::from(candidate) < rejection_threshold;
+    (is_uniform && is_unbiased).then_some(candidate % upper_bound)
+}
+
+/// Map the given `digest` to an index in the range `[0, upper_bound)`.
+///
+/// The index is derived from the first element of the `digest` that is not dropped by
+/// [`index_from_element`]. If all elements are dropped, `None` is returned. For a uniformly
+/// distributed `digest`, this happens with probability less than 2^-5 for any `upper_bound`, and
+/// with probability about 2^-320 if the `upper_bound` is a power of two. Callers requiring an index
+/// can, for example, hash the `digest` and try again.
+///
+/// # Panics
+///
+/// Panics if the `upper_bound` is 0.
+pub fn index_from_digest(digest: Digest, upper_bound: u32) -> Option<u32> {
+    digest
+        .values()
+        .into_iter()
+        .find_map(|element| index_from_element(element, upper_bound))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::digest::DIGEST_LENGTH;
+    use crate::math::tip5::Tip5;
+    use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+    #[proptest]
+    fn index_from_element_is_below_upper_bound(
+        #[strategy(arb())] element: BFieldElement,
+        #[strategy(1_u32..)] upper_bound: u32,
+    ) {
+        if let Some(index) = index_from_element(element, upper_bound) {
+            prop_assert!(index < upper_bound);
+        }
+    }
+
+    #[proptest]
+    fn index_from_element_for_powers_of_two_is_reduced_lower_half(
+        #[strategy(arb())] element: BFieldElement,
+        #[strategy(0_u32..32)] log_2_upper_bound: u32,
+    ) {
+        prop_assume!(element != BFieldElement::new(BFieldElement::MAX));
+        let upper_bound = 1 << log_2_upper_bound;
+        let expected_index = element.value() as u32 % upper_bound;
+        prop_assert_eq!(
+            Some(expected_index),
+            index_from_element(element, upper_bound)
+        );
+    }
+
+    #[test]
+    fn biased_elements_are_dropped() {
+        let max = BFieldElement::new(BFieldElement::MAX);
+        assert_eq!(None, index_from_element(max, 2));
+
+        let upper_bound = 3;
+        let rejection_threshold = u32::MAX;
+        let biased = BFieldElement::new(u64::from(rejection_threshold));
+        let unbiased = BFieldElement::new(u64::from(rejection_threshold - 1));
+        assert_eq!(None, index_from_element(biased, upper_bound));
+        assert_eq!(Some(2), index_from_element(unbiased, upper_bound));
+    }
+
+    #[proptest]
+    fn index_from_digest_uses_first_unbiased_element(
+        #[strategy(arb())] digest: Digest,
+        #[strategy(1_u32..)] upper_bound: u32,
+    ) {
+        let expected_index = digest
+            .values()
+            .into_iter()
+            .filter_map(|element| index_from_element(element, upper_bound))
+            .next();
+        prop_assert_eq!(expected_index, index_from_digest(digest, upper_bound));
+    }
+
+    #[test]
+    fn index_from_fully_biased_digest_is_none() {
+        let max_digest = Digest::new([BFieldElement::new(BFieldElement::MAX); DIGEST_LENGTH]);
+        assert_eq!(None, index_from_digest(max_digest, 1 << 10));
+
+        let u32_max_digest = Digest::new([BFieldElement::new(u64::from(u32::MAX)); DIGEST_LENGTH]);
+        assert_eq!(None, index_from_digest(u32_max_digest, 3));
+        assert_eq!(Some(u32::MAX % 4), index_from_digest(u32_max_digest, 4));
+    }
+
+    #[test]
+    fn indices_from_digests_are_roughly_uniform() {
+        let upper_bound = 3;
+        let indices = (0..3000_u64)
+            .map(|i| Tip5::hash(&i))
+            .filter_map(|digest| index_from_digest(digest, upper_bound))
+            .collect_vec();
+        for index in 0..upper_bound {
+            let count = indices.iter().filter(|&&i| i == index).count();
+            assert!(
+                (800..1200).contains(&count),
+                "index {index} sampled {count} times"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "upper bound must be positive")]
+    fn index_from_digest_with_upper_bound_zero_panics() {
+        index_from_digest(Digest::default(), 0);
+    }
+}